@@ -0,0 +1,11 @@
+/// Where achievement progress is kept between sessions (a `localStorage` key on the web build)
+pub const ACHIEVEMENTS_PATH: &str = "achievements.json";
+
+pub struct AchievementConfig;
+
+impl AchievementConfig {
+    /// Lifetime coins for "Coin Collector"
+    pub const COIN_COLLECTOR_COINS: u32 = 100;
+    /// Lifetime kills for "Enemy Slayer"
+    pub const ENEMY_SLAYER_KILLS: u32 = 10;
+}
@@ -0,0 +1,2 @@
+/// Where volume and mute settings are saved between sessions (a `localStorage` key on the web build)
+pub const AUDIO_SETTINGS_PATH: &str = "audio_settings.json";
@@ -6,4 +6,22 @@ impl BoundaryConstants {
     pub const WORLD_CENTER_X: f32 = 122.5;
     pub const WORLD_CENTER_Z: f32 = 35.0;
     pub const WORLD_INSET: f32 = 3.0;
+    /// Gap kept between the walkable area and the boundary walls
+    pub const WALL_MARGIN: f32 = 1.5;
+}
+
+/// Getting the player back after they fall out of the world
+pub struct RecoveryConfig;
+
+impl RecoveryConfig {
+    /// Anything below this has fallen through the environment; the ground sits around -1.5
+    pub const KILL_PLANE_Y: f32 = -20.0;
+    /// How far past the walkable area the player can get before being pulled back
+    pub const BOUNDARY_ESCAPE_MARGIN: f32 = 5.0;
+    /// Health taken for each recovery
+    pub const DAMAGE: u32 = 5;
+    /// Seconds between samples of where the player safely stood
+    pub const SAMPLE_INTERVAL_SECS: f32 = 0.5;
+    /// Safe positions kept, so repeated recoveries step further back
+    pub const HISTORY_LEN: usize = 10;
 }
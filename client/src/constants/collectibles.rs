@@ -8,4 +8,93 @@ pub const MAX_COIN_PLACEMENT_ATTEMPTS: usize = 10000;
 pub const MIN_DISTANCE_BETWEEN_COINS: f32 = 4.0;
 
 /// Coin streaming radius around the player
-pub const COIN_STREAMING_RADIUS: f32 = 100.0; 
\ No newline at end of file
+pub const COIN_STREAMING_RADIUS: f32 = 100.0; 
+
+/// Most coins a single streaming update spawns or reuses; the rest wait for the next update
+pub const COIN_STREAMING_SPAWN_BUDGET: usize = 60;
+
+/// Whether a limited spawn budget goes to coins in front of the camera first
+pub const COIN_STREAMING_PRIORITIZE_VIEW: bool = true;
+
+/// How much facing away from a coin counts against it: one directly behind the camera
+/// ranks as if it were `1 + weight` times as far away
+pub const COIN_STREAMING_VIEW_WEIGHT: f32 = 2.0;
+
+/// Extra candidate positions generated per coin, to cover candidates rejected by the physics check
+pub const COIN_CANDIDATE_OVERSAMPLE: usize = 2;
+
+/// Height the no-navigation fallback casts down from when looking for ground under a coin
+pub const FALLBACK_GROUND_PROBE_HEIGHT: f32 = 50.0;
+
+/// How far above the ground fallback coins float
+pub const FALLBACK_COIN_GROUND_OFFSET: f32 = 1.0;
+
+/// Default currency and XP each collectible type is worth when picked up
+pub struct CollectibleRewardConfig;
+
+impl CollectibleRewardConfig {
+    pub const COIN_VALUE: u32 = 1;
+    pub const COIN_XP: u32 = 0;
+    pub const BOOK_VALUE: u32 = 0;
+    pub const BOOK_XP: u32 = 25;
+    pub const HEALTH_POTION_XP: u32 = 5;
+    pub const SURVIVAL_KIT_XP: u32 = 10;
+    pub const POWER_CRYSTAL_VALUE: u32 = 10;
+    pub const POWER_CRYSTAL_XP: u32 = 15;
+}
+
+/// Pickups chained in quick succession build a combo that multiplies their rewards
+pub struct PickupComboConfig;
+
+impl PickupComboConfig {
+    /// Seconds after a pickup during which the next one extends the combo
+    pub const WINDOW_SECS: f32 = 1.5;
+    /// Extra share of the reward per pickup in the combo after the first
+    pub const STEP_BONUS: f32 = 0.1;
+    pub const MAX_MULTIPLIER: f32 = 2.0;
+}
+
+/// Default radius within which the pickup magnet starts pulling coins in
+pub const PICKUP_MAGNET_RADIUS: f32 = 4.0;
+
+/// How quickly magnetized coins close the gap to the player
+pub const PICKUP_MAGNET_SPEED: f32 = 6.0;
+
+/// Distance from the gameplay camera past which coins swap their model for a cheap impostor
+pub const COIN_LOD_DISTANCE: f32 = 40.0;
+
+/// Extra distance a coin must move past `COIN_LOD_DISTANCE` before swapping, so coins
+/// hovering at the boundary don't pop back and forth
+pub const COIN_LOD_HYSTERESIS: f32 = 5.0;
+
+/// Size of the low-poly disc shown for distant coins, in coin model units
+pub const COIN_LOD_IMPOSTOR_RADIUS: f32 = 0.5;
+pub const COIN_LOD_IMPOSTOR_THICKNESS: f32 = 0.08;
+
+/// Particle burst shown where an item is picked up
+pub struct PickupBurstConfig;
+
+impl PickupBurstConfig {
+    pub const PARTICLES: usize = 10;
+    pub const LIFETIME_SECS: f32 = 0.5;
+    pub const SPEED: f32 = 3.5;
+    /// Share of each particle's velocity pointing up, so bursts fountain rather than spread flat
+    pub const UPWARD_BIAS: f32 = 0.8;
+    pub const GRAVITY: f32 = 9.0;
+    pub const PARTICLE_RADIUS: f32 = 0.08;
+    /// Live particles above this skip new bursts, so magnet sweeps through coin lines stay cheap
+    pub const MAX_PARTICLES: usize = 120;
+}
+
+/// Default hover and spin for each collectible type; coins keep the original
+/// values, and the rest differ enough to tell apart at a glance
+pub struct CollectibleMotionDefaults;
+
+impl CollectibleMotionDefaults {
+    /// (hover amplitude, hover speed, rotation speed, clockwise)
+    pub const COIN: (f32, f32, f32, bool) = (0.2, 2.0, 1.0, true);
+    pub const BOOK: (f32, f32, f32, bool) = (0.35, 1.2, 0.5, false);
+    pub const HEALTH_POTION: (f32, f32, f32, bool) = (0.15, 2.5, 1.5, true);
+    pub const SURVIVAL_KIT: (f32, f32, f32, bool) = (0.1, 1.5, 0.6, true);
+    pub const POWER_CRYSTAL: (f32, f32, f32, bool) = (0.3, 3.0, 2.5, false);
+}
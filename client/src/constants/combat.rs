@@ -0,0 +1,39 @@
+/// Melee hit detection tuning
+pub struct CombatConfig;
+
+impl CombatConfig {
+    /// How far in front of the attacker a fight move reaches
+    pub const ATTACK_RANGE: f32 = 3.5;
+    /// Full width of the hit arc, centered on the attacker's facing
+    pub const ATTACK_ARC_DEGREES: f32 = 100.0;
+    /// Seconds into a fight move when the hit window opens and closes
+    pub const ATTACK_WINDOW_START: f32 = 0.15;
+    pub const ATTACK_WINDOW_END: f32 = 0.45;
+    /// Damage each fight move deals to whatever it hits
+    pub const FIGHT_MOVE_1_DAMAGE: u32 = 20;
+    pub const FIGHT_MOVE_2_DAMAGE: u32 = 35;
+    /// Seconds a fight move pressed mid-animation stays queued for when the character frees up
+    pub const FIGHT_MOVE_BUFFER_SECS: f32 = 0.15;
+    pub const PLAYER_MAX_HEALTH: u32 = 100;
+    /// Enemy stats on Normal; other difficulties scale these
+    pub const ENEMY_MAX_HEALTH: u32 = 60;
+    pub const ENEMY_ATTACK_DAMAGE: u32 = 10;
+    /// Seconds an enemy telegraphs a hit before it lands, the player's window to step away
+    pub const ENEMY_ATTACK_WINDUP_SECS: f32 = 0.5;
+    /// Seconds after a hit lands before the enemy can wind up the next one
+    pub const ENEMY_ATTACK_COOLDOWN_SECS: f32 = 1.2;
+    /// Seconds a defeated enemy lingers, out of the fight, before it's despawned
+    pub const ENEMY_DESPAWN_SECS: f32 = 1.0;
+    /// Seconds between the player dying and the game-over screen, left for the death animation
+    pub const DEATH_TRANSITION_SECS: f32 = 1.5;
+}
+
+/// Breakable props and what they leave behind
+pub struct DestructibleConfig;
+
+impl DestructibleConfig {
+    /// How far from the broken prop its drops land, so several don't stack
+    pub const DROP_SCATTER_RADIUS: f32 = 1.2;
+    /// Drops float at this height above the prop's origin
+    pub const DROP_HEIGHT: f32 = 1.0;
+}
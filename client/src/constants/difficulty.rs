@@ -0,0 +1,48 @@
+/// Where the chosen difficulty is saved between sessions (a `localStorage` key on the web build)
+pub const DIFFICULTY_SETTINGS_PATH: &str = "difficulty_settings.json";
+
+/// Everything a difficulty scales, as multipliers on the normal values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyModifiers {
+    pub enemy_move_speed: f32,
+    pub enemy_detection_range: f32,
+    pub enemy_damage: f32,
+    pub enemy_health: f32,
+    /// Enemies per wave
+    pub wave_enemy_count: f32,
+    pub player_stamina_regen: f32,
+    /// Currency granted by coins and other pickups
+    pub coin_value: f32,
+}
+
+impl DifficultyModifiers {
+    pub const EASY: Self = Self {
+        enemy_move_speed: 0.8,
+        enemy_detection_range: 0.75,
+        enemy_damage: 0.5,
+        enemy_health: 0.75,
+        wave_enemy_count: 0.6,
+        player_stamina_regen: 1.5,
+        coin_value: 1.5,
+    };
+
+    pub const NORMAL: Self = Self {
+        enemy_move_speed: 1.0,
+        enemy_detection_range: 1.0,
+        enemy_damage: 1.0,
+        enemy_health: 1.0,
+        wave_enemy_count: 1.0,
+        player_stamina_regen: 1.0,
+        coin_value: 1.0,
+    };
+
+    pub const HARD: Self = Self {
+        enemy_move_speed: 1.25,
+        enemy_detection_range: 1.3,
+        enemy_damage: 1.5,
+        enemy_health: 1.5,
+        wave_enemy_count: 1.5,
+        player_stamina_regen: 0.75,
+        coin_value: 0.75,
+    };
+}
@@ -1,3 +1,4 @@
+use bevy::log::warn;
 use starknet::core::types::Felt;
 use starknet::macros::selector;
 use std::env;
@@ -19,45 +20,114 @@ pub struct DojoConfig {
     pub dev_account_index: u32,
 }
 
+/// Reads a setting from the environment at runtime, falling back to the value baked in at
+/// build time. Wasm builds have no runtime environment, so only the build-time value applies.
+fn config_value(name: &str, build_time: Option<&'static str>) -> Option<String> {
+    env::var(name)
+        .ok()
+        .or_else(|| build_time.map(str::to_string))
+}
+
+/// Parses a contract address setting, warning and keeping the default when it's malformed
+fn config_address(name: &str, build_time: Option<&'static str>, default: Felt) -> Felt {
+    let Some(value) = config_value(name, build_time) else {
+        return default;
+    };
+    match Felt::from_hex(&value) {
+        Ok(address) if address != Felt::ZERO => address,
+        _ => {
+            warn!(
+                "{} `{}` is not a valid contract address, using the default",
+                name, value
+            );
+            default
+        }
+    }
+}
+
 impl Default for DojoConfig {
     fn default() -> Self {
         Self {
-            torii_url: env::var("TORII_URL").unwrap_or_else(|_| {
+            torii_url: config_value("TORII_URL", option_env!("TORII_URL")).unwrap_or_else(|| {
                 "https://api.cartridge.gg/x/elysium-descent001/torii".to_string()
             }),
-            katana_url: env::var("KATANA_URL").unwrap_or_else(|_| {
-                "https://api.cartridge.gg/x/elysium-descent001/katana".to_string()
-            }),
-            world_address: env::var("WORLD_ADDRESS")
-                .ok()
-                .and_then(|addr| Felt::from_hex(&addr).ok())
-                .unwrap_or_else(|| {
-                    // Real deployed world address from manifest_dev.json
-                    Felt::from_hex_unchecked(
-                        "0x002f3fd3e14a14bb1c98095c6f9c305b5660e41f6bbbf8f8dc8a52c6104fe5fa",
-                    )
-                }),
-            action_address: env::var("ACTION_ADDRESS")
-                .ok()
-                .and_then(|addr| Felt::from_hex(&addr).ok())
-                .unwrap_or_else(|| {
-                    // Real deployed action address from manifest_dev.json
-                    Felt::from_hex_unchecked(
-                        "0x0714c627d3dd04f47d301b29f2db8e3dd0587055e32b366ce13182f7c7e031a7",
-                    )
-                }),
-            use_dev_account: env::var("USE_DEV_ACCOUNT").unwrap_or_else(|_| "true".to_string())
-                == "true",
-            dev_account_index: env::var("DEV_ACCOUNT_INDEX")
-                .unwrap_or_else(|_| "0".to_string())
-                .parse()
+            katana_url: config_value("KATANA_URL", option_env!("KATANA_URL")).unwrap_or_else(
+                || "https://api.cartridge.gg/x/elysium-descent001/katana".to_string(),
+            ),
+            world_address: config_address(
+                "WORLD_ADDRESS",
+                option_env!("WORLD_ADDRESS"),
+                // Real deployed world address from manifest_dev.json
+                Felt::from_hex_unchecked(
+                    "0x002f3fd3e14a14bb1c98095c6f9c305b5660e41f6bbbf8f8dc8a52c6104fe5fa",
+                ),
+            ),
+            action_address: config_address(
+                "ACTION_ADDRESS",
+                option_env!("ACTION_ADDRESS"),
+                // Real deployed action address from manifest_dev.json
+                Felt::from_hex_unchecked(
+                    "0x0714c627d3dd04f47d301b29f2db8e3dd0587055e32b366ce13182f7c7e031a7",
+                ),
+            ),
+            use_dev_account: config_value("USE_DEV_ACCOUNT", option_env!("USE_DEV_ACCOUNT"))
+                .is_none_or(|value| value == "true"),
+            dev_account_index: config_value("DEV_ACCOUNT_INDEX", option_env!("DEV_ACCOUNT_INDEX"))
+                .and_then(|value| value.parse().ok())
                 .unwrap_or(0),
         }
     }
 }
 
+impl DojoConfig {
+    /// Warns about settings that are obviously wrong, e.g. a URL without a scheme
+    pub fn validate(&self) {
+        for (name, url) in [
+            ("TORII_URL", &self.torii_url),
+            ("KATANA_URL", &self.katana_url),
+        ] {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                warn!("{} `{}` is not an http(s) URL", name, url);
+            }
+        }
+    }
+}
+
 // Updated selectors for Elysium Descent contract functions
 #[allow(dead_code)]
 pub const CREATE_GAME_SELECTOR: Felt = selector!("create_game");
 #[allow(dead_code)]
 pub const PICKUP_ITEM_SELECTOR: Felt = selector!("pickup_item");
+#[allow(dead_code)]
+pub const SUBMIT_SCORE_SELECTOR: Felt = selector!("submit_score");
+
+/// Seconds a queued blockchain transaction may run before its task is aborted
+pub const DOJO_REQUEST_TIMEOUT_SECS: f32 = 30.0;
+
+/// Presses of the create-game key closer together than this are ignored
+pub const CREATE_GAME_DEBOUNCE_SECS: f32 = 2.0;
+
+/// Polling of on-chain state into `ChainState`
+pub struct ChainPollConfig;
+
+impl ChainPollConfig {
+    /// Seconds between reads while the chain is answering
+    pub const INTERVAL_SECS: f32 = 10.0;
+    /// Consecutive failed reads before the account counts as disconnected
+    pub const DISCONNECT_AFTER_ERRORS: u32 = 3;
+}
+
+/// Default `RetryPolicy` for every network call the client retries
+pub struct RetryConfig;
+
+impl RetryConfig {
+    pub const MAX_RETRIES: u32 = 3;
+    /// Delay before the first retry; doubles with every further attempt
+    pub const BASE_DELAY_SECS: f32 = 2.0;
+    pub const MAX_DELAY_SECS: f32 = 120.0;
+    /// Each delay is randomly spread by up to this fraction either way
+    pub const JITTER: f32 = 0.2;
+}
+
+#[allow(dead_code)]
+pub const UUID_SELECTOR: Felt = selector!("uuid");
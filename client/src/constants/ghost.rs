@@ -0,0 +1,13 @@
+/// Ghost replays are saved as `ghost_level_<n>.json`, one per level (native only)
+pub const GHOST_REPLAY_PATH_PREFIX: &str = "ghost_level_";
+
+pub struct GhostConfig;
+
+impl GhostConfig {
+    /// Seconds between recorded player transforms
+    pub const SAMPLE_INTERVAL_SECS: f32 = 0.1;
+    /// Recording stops growing past this, about an hour at the sample interval
+    pub const MAX_SAMPLES: usize = 36_000;
+    /// Opacity of the ghost's materials
+    pub const ALPHA: f32 = 0.35;
+}
@@ -0,0 +1,34 @@
+/// Where graphics settings are saved between sessions (a `localStorage` key on the web build)
+pub const GRAPHICS_SETTINGS_PATH: &str = "graphics_settings.json";
+
+/// Directional shadow map resolution for each quality level
+pub const SHADOW_MAP_SIZE_LOW: usize = 1024;
+pub const SHADOW_MAP_SIZE_MEDIUM: usize = 2048;
+pub const SHADOW_MAP_SIZE_HIGH: usize = 4096;
+
+/// Horizontal field of view, in degrees
+pub const FOV_DEFAULT_DEGREES: f32 = 75.0;
+pub const FOV_MIN_DEGREES: f32 = 60.0;
+pub const FOV_MAX_DEGREES: f32 = 110.0;
+pub const FOV_STEP_DEGREES: f32 = 5.0;
+
+/// Camera far clip; also caps how far coins stream and how far enemies can spot the player
+pub const RENDER_DISTANCE_DEFAULT: f32 = 1000.0;
+pub const RENDER_DISTANCE_MIN: f32 = 50.0;
+pub const RENDER_DISTANCE_MAX: f32 = 1000.0;
+pub const RENDER_DISTANCE_STEP: f32 = 50.0;
+
+/// Blob shadow drawn on the ground under characters
+pub struct GroundShadowConfig;
+
+impl GroundShadowConfig {
+    pub const RADIUS: f32 = 0.9;
+    /// Opacity while standing on the ground
+    pub const OPACITY: f32 = 0.45;
+    /// Height off the ground at which the shadow has faded out completely
+    pub const MAX_HEIGHT: f32 = 8.0;
+    /// Shrinks towards this fraction of its size as the character rises
+    pub const MIN_SCALE: f32 = 0.5;
+    /// Lift above the ground so the quad doesn't z-fight with it
+    pub const GROUND_OFFSET: f32 = 0.03;
+}
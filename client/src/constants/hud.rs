@@ -0,0 +1,61 @@
+/// Where HUD preferences are saved between sessions (a `localStorage` key on the web build)
+pub const HUD_SETTINGS_PATH: &str = "hud_settings.json";
+
+/// Multiplier on every bevy_ui size, for readability on small or high-DPI screens
+pub const UI_SCALE_DEFAULT: f32 = 1.0;
+pub const UI_SCALE_MIN: f32 = 0.75;
+pub const UI_SCALE_MAX: f32 = 1.5;
+pub const UI_SCALE_STEP: f32 = 0.05;
+
+/// Window size the HUD and modal pixel sizes were laid out for; other sizes scale
+/// the UI by whichever axis is tighter so nothing clips on ultrawide or 4:3 windows
+pub const UI_REFERENCE_WIDTH: f32 = 1920.0;
+pub const UI_REFERENCE_HEIGHT: f32 = 1080.0;
+
+/// Bounds on the window-size factor, so tiny or huge windows stay usable
+pub const UI_WINDOW_SCALE_MIN: f32 = 0.5;
+pub const UI_WINDOW_SCALE_MAX: f32 = 2.0;
+
+/// How long a toast notification stays on screen
+pub const TOAST_SECS: f32 = 4.0;
+
+/// Compass strip at the top of the gameplay HUD
+pub struct CompassConfig;
+
+impl CompassConfig {
+    pub const WIDTH: f32 = 480.0;
+    pub const HEIGHT: f32 = 36.0;
+    pub const TOP: f32 = 12.0;
+    /// Degrees of heading visible across the whole strip
+    pub const VISIBLE_DEGREES: f32 = 180.0;
+    pub const LABEL_WIDTH: f32 = 24.0;
+    pub const MARKER_SIZE: f32 = 10.0;
+    /// Location objectives shown at once; more than this just aren't marked
+    pub const MAX_OBJECTIVE_MARKERS: usize = 4;
+}
+
+/// Coin total under the compass, and the coins that fly into it on pickup
+pub struct CoinCounterConfig;
+
+impl CoinCounterConfig {
+    /// Below the compass strip
+    pub const TOP: f32 = 56.0;
+    pub const ICON_SIZE: f32 = 36.0;
+    pub const FLYING_COIN_SIZE: f32 = 32.0;
+    pub const FLIGHT_SECS: f32 = 0.4;
+    /// Coins in flight at once; pickups beyond this count up straight away
+    pub const POOL_SIZE: usize = 12;
+}
+
+/// Animation of `ProgressBar` fills toward their target
+pub struct ProgressBarConfig;
+
+impl ProgressBarConfig {
+    /// How fast fills close on their target, per second; 0 jumps straight there
+    pub const SMOOTHING: f32 = 10.0;
+    /// How long the chip layer stays put after a drop before draining
+    pub const CHIP_HOLD_SECS: f32 = 0.5;
+    pub const CHIP_SMOOTHING: f32 = 4.0;
+    /// Fills this close to their target snap to it, so settled bars stop updating
+    pub const SNAP_EPSILON: f32 = 0.001;
+}
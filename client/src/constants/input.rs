@@ -0,0 +1,9 @@
+/// Where input preferences are saved between sessions (a `localStorage` key on the web build)
+pub const INPUT_SETTINGS_PATH: &str = "input_settings.json";
+
+/// Gamepad stick deadzone, shared by the movement binding and idle detection;
+/// stick deflection below this reads as centered
+pub const STICK_DEADZONE_DEFAULT: f32 = 0.2;
+pub const STICK_DEADZONE_MIN: f32 = 0.05;
+pub const STICK_DEADZONE_MAX: f32 = 0.5;
+pub const STICK_DEADZONE_STEP: f32 = 0.05;
@@ -0,0 +1,28 @@
+use crate::systems::collectibles::CollectibleType;
+
+/// Number of slots in the gameplay inventory bar
+pub const INVENTORY_SLOT_COUNT: usize = 6;
+
+pub struct InventoryConfig;
+
+impl InventoryConfig {
+    pub const COIN_MAX_STACK: usize = 999;
+    pub const DEFAULT_MAX_STACK: usize = 99;
+
+    /// Largest count a single slot can hold for this item type
+    pub fn max_stack(item_type: CollectibleType) -> usize {
+        match item_type {
+            CollectibleType::Coin => Self::COIN_MAX_STACK,
+            _ => Self::DEFAULT_MAX_STACK,
+        }
+    }
+}
+
+/// Effects applied when a consumable is used from the inventory
+pub struct ConsumableConfig;
+
+impl ConsumableConfig {
+    pub const HEALTH_POTION_HEAL: u32 = 25;
+    pub const SURVIVAL_KIT_HEAL: u32 = 50;
+    pub const POWER_CRYSTAL_STAMINA: f32 = 50.0;
+}
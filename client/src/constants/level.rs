@@ -0,0 +1,21 @@
+/// Where the player appears in a level and how that spot is checked
+pub struct SpawnConfig;
+
+impl SpawnConfig {
+    /// Used when a level doesn't set its own spawn point; the origin, dropped in from standing height
+    pub const DEFAULT_SPAWN_POSITION: [f32; 3] = [0.0, 2.0, 0.0];
+    /// How far above the spawn point the ground cast starts, so a point buried in the floor still finds it
+    pub const GROUND_PROBE_HEIGHT: f32 = 10.0;
+    /// Gap kept between the ground and the player's feet (the player origin)
+    pub const GROUND_CLEARANCE: f32 = 0.5;
+    /// How far inside the boundary an out-of-bounds spawn is pulled
+    pub const BOUNDARY_MARGIN: f32 = 1.0;
+}
+
+/// Checkpoint zones placed through the level data
+pub struct CheckpointConfig;
+
+impl CheckpointConfig {
+    /// Horizontal distance from a checkpoint that counts as having reached it, when the level doesn't set one
+    pub const DEFAULT_RADIUS: f32 = 2.5;
+}
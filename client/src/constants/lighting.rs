@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Length of a full day/night cycle when the lighting cycle is enabled
+pub const DAY_CYCLE_PERIOD_SECS: f32 = 600.0;
+
+/// Sun strength at midday, matching the static gameplay light
+pub const SUN_ILLUMINANCE: f32 = 15_000.0;
+
+/// Ambient light at full daylight, the same warm tone set up at startup
+pub const DAY_AMBIENT_COLOR: Color = Color::srgb(0.8, 0.7, 0.6);
+pub const DAY_AMBIENT_BRIGHTNESS: f32 = 0.3;
+
+/// Ambient light at midnight, cool and dim so the level stays readable
+pub const NIGHT_AMBIENT_COLOR: Color = Color::srgb(0.25, 0.3, 0.5);
+pub const NIGHT_AMBIENT_BRIGHTNESS: f32 = 0.08;
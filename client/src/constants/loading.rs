@@ -0,0 +1,2 @@
+/// Seconds to wait for asset collections before continuing with placeholders
+pub const ASSET_LOAD_TIMEOUT_SECS: f32 = 30.0;
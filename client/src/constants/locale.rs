@@ -0,0 +1,2 @@
+/// Where the chosen language is saved between sessions (a `localStorage` key on the web build)
+pub const LOCALE_SETTINGS_PATH: &str = "locale_settings.json";
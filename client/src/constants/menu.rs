@@ -0,0 +1,30 @@
+/// Slow drift and cursor parallax of the main menu background
+pub struct MenuBackgroundConfig;
+
+impl MenuBackgroundConfig {
+    /// How much larger than the screen the background is drawn, in percent, so
+    /// moving it never shows an edge; has to cover twice the drift plus parallax
+    pub const OVERSCAN_PERCENT: f32 = 6.0;
+    /// Furthest the idle drift moves the background, in percent of the screen
+    pub const DRIFT_PERCENT: f32 = 1.5;
+    /// Time for one full drift loop
+    pub const DRIFT_PERIOD_SECS: f32 = 40.0;
+    /// Furthest the background shifts away from the cursor at the screen edge
+    pub const PARALLAX_PERCENT: f32 = 1.5;
+    /// How quickly the parallax catches up with the cursor, per second
+    pub const PARALLAX_SMOOTHING: f32 = 3.0;
+}
+
+/// Scrolling of the credits screen
+pub struct CreditsConfig;
+
+impl CreditsConfig {
+    /// Default auto-scroll speed, in UI pixels per second
+    pub const SCROLL_SPEED: f32 = 60.0;
+    /// Speed-up while Space is held
+    pub const FAST_FORWARD_MULTIPLIER: f32 = 4.0;
+    /// How long auto-scroll waits after the player scrolls by hand
+    pub const MANUAL_SCROLL_PAUSE_SECS: f32 = 2.0;
+    /// How long the last line stays up before returning to the menu
+    pub const END_HOLD_SECS: f32 = 3.0;
+}
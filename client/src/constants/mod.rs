@@ -2,3 +2,22 @@ pub mod collectibles;
 pub mod dojo;
 pub mod movement;
 pub mod boundary;
+pub mod objectives;
+pub mod loading;
+pub mod navigation;
+pub mod combat;
+pub mod inventory;
+pub mod lighting;
+pub mod graphics;
+pub mod touch_controls;
+pub mod input;
+pub mod save;
+pub mod hud;
+pub mod ghost;
+pub mod difficulty;
+pub mod locale;
+pub mod achievements;
+pub mod audio;
+pub mod statistics;
+pub mod level;
+pub mod menu;
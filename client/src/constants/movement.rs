@@ -11,10 +11,40 @@ impl CharacterMovementConfig {
     // Air resistance constant
     pub const AIR_RESISTANCE: f32 = 0.98;
 
+    /// Steepest ground the character can walk up; anything steeper is treated as a wall
+    pub const MAX_WALKABLE_SLOPE_DEGREES: f32 = 45.0;
+
+    /// Tallest ledge the character steps up onto instead of being blocked
+    pub const MAX_STEP_HEIGHT: f32 = 0.35;
+    /// Ledges lower than this are left to the collider to ride over
+    pub const MIN_STEP_HEIGHT: f32 = 0.02;
+    /// How far ahead of the collider ledges are looked for
+    pub const STEP_PROBE_DISTANCE: f32 = 0.35;
+    /// Vertical speed while stepping up, in units per second
+    pub const STEP_UP_SPEED: f32 = 4.0;
+
     // Movement threshold for stopping tiny residual movement
     pub const MIN_MOVEMENT_THRESHOLD: f32 = 0.01;
 
     pub const RUN_TRIGGER_HOLD_TIME: f32 = 3.0;
+
+    // Jumping
+    pub const JUMP_IMPULSE: f32 = 7.0;
+    /// Seconds after walking off an edge during which a jump still counts as grounded
+    pub const COYOTE_TIME: f32 = 0.12;
+    /// Upward velocity is multiplied by this when the jump key is released mid-rise
+    pub const JUMP_CUT_MULTIPLIER: f32 = 0.5;
+    /// Debounce between two jumps, counted from the last actual jump; ground contact is the real gate
+    pub const JUMP_COOLDOWN: f32 = 0.2;
+
+    // Gravity and falling
+    /// Multiplier on world gravity for characters; above 1 makes jumps snappier
+    pub const GRAVITY_SCALE: f32 = 1.0;
+    pub const FALL_DAMAGE_ENABLED: bool = true;
+    /// Landing at a downward speed up to this never hurts
+    pub const SAFE_FALL_SPEED: f32 = 12.0;
+    /// Damage per unit of impact speed above `SAFE_FALL_SPEED`
+    pub const FALL_DAMAGE_PER_SPEED: f32 = 4.0;
 }
 
 pub struct CharacterAnimationConfig;
@@ -26,3 +56,17 @@ impl CharacterAnimationConfig {
     pub const FIGHT_MOVE_1: usize = 5;
     pub const FIGHT_MOVE_2: usize = 6;
 }
+
+pub struct StaminaConfig;
+
+impl StaminaConfig {
+    pub const MAX_STAMINA: f32 = 100.0;
+    /// Drained per second while running
+    pub const RUN_DRAIN_PER_SECOND: f32 = 20.0;
+    /// Regenerated per second while idle or walking
+    pub const REGEN_PER_SECOND: f32 = 15.0;
+    /// Cost of a single fight move
+    pub const FIGHT_MOVE_COST: f32 = 25.0;
+    /// After running dry, stamina has to climb back to this before running again
+    pub const RECOVERY_THRESHOLD: f32 = 30.0;
+}
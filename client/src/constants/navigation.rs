@@ -0,0 +1,8 @@
+/// Navigation data file, read during loading and written by the recorder (native only)
+pub const NAV_DATA_PATH: &str = "nav.json";
+
+/// Seconds between player position samples while recording navigation data
+pub const NAV_RECORDING_SAMPLE_INTERVAL_SECS: f32 = 0.5;
+
+/// Optional per-level enemy wave definitions (native only)
+pub const WAVES_DATA_PATH: &str = "waves.json";
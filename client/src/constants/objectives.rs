@@ -0,0 +1,17 @@
+/// Completion radius used by location objectives that don't specify their own
+pub const DEFAULT_OBJECTIVE_COMPLETION_RADIUS: f32 = 5.0;
+
+/// The level exit; reaching it with every objective done completes the level
+pub struct LevelExitConfig;
+
+impl LevelExitConfig {
+    /// Used by levels whose data doesn't place an exit
+    pub const DEFAULT_POSITION: [f32; 3] = [60.0, 0.0, 0.0];
+    /// Horizontal distance from the portal that counts as having stepped into it
+    pub const DEFAULT_RADIUS: f32 = 3.0;
+    /// How far out the portal's prompt starts showing
+    pub const PROMPT_RANGE: f32 = 10.0;
+}
+
+/// Time allowed for the bonus power crystal challenge
+pub const TIMED_CRYSTAL_OBJECTIVE_SECS: f32 = 240.0;
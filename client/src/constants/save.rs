@@ -0,0 +1,22 @@
+/// Where the game is saved (a `localStorage` key on the web build)
+pub const SAVE_GAME_PATH: &str = "savegame.json";
+/// Where autosave preferences are saved between sessions (a `localStorage` key on the web build)
+pub const AUTOSAVE_SETTINGS_PATH: &str = "autosave_settings.json";
+
+pub struct AutosaveConfig;
+
+impl AutosaveConfig {
+    pub const DEFAULT_INTERVAL_SECS: f32 = 60.0;
+    pub const MIN_INTERVAL_SECS: f32 = 30.0;
+    pub const MAX_INTERVAL_SECS: f32 = 300.0;
+    pub const INTERVAL_STEP_SECS: f32 = 30.0;
+    /// How long the "Saving..." indicator stays on screen
+    pub const INDICATOR_SECS: f32 = 1.5;
+}
+
+pub struct QuitConfig;
+
+impl QuitConfig {
+    /// Whether quitting from a menu asks first on native builds
+    pub const CONFIRM_ON_NATIVE: bool = true;
+}
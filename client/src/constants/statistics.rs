@@ -0,0 +1,9 @@
+/// Where lifetime statistics are kept between sessions (a `localStorage` key on the web build)
+pub const STATISTICS_PATH: &str = "statistics.json";
+
+pub struct StatisticsConfig;
+
+impl StatisticsConfig {
+    /// A single frame's movement above this is a teleport (restart, respawn), not travel
+    pub const MAX_STEP_DISTANCE: f32 = 5.0;
+}
@@ -0,0 +1,12 @@
+/// On-screen joystick and action button layout, in logical pixels
+pub struct TouchControlsConfig;
+
+impl TouchControlsConfig {
+    pub const JOYSTICK_BASE_SIZE: f32 = 180.0;
+    pub const JOYSTICK_KNOB_SIZE: f32 = 72.0;
+    /// Distance the knob can travel from the center; dragging this far is full speed
+    pub const JOYSTICK_RADIUS: f32 = 72.0;
+    pub const BUTTON_SIZE: f32 = 96.0;
+    pub const BUTTON_SPACING: f32 = 20.0;
+    pub const SCREEN_MARGIN: f32 = 56.0;
+}
@@ -16,6 +16,20 @@ pub struct Health {
     pub max: u32,
 }
 
+impl Health {
+    pub fn new(max: u32) -> Self {
+        Self { current: max, max }
+    }
+
+    pub fn heal(&mut self, amount: u32) {
+        self.current = self.current.saturating_add(amount).min(self.max);
+    }
+
+    pub fn take_damage(&mut self, amount: u32) {
+        self.current = self.current.saturating_sub(amount);
+    }
+}
+
 #[derive(Component, Reflect)]
 pub struct Fruit;
 
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::window::{MonitorSelection, WindowMode};
 use bevy_enhanced_input::prelude::*;
 
+use crate::resources::input::InputSettings;
 use crate::screens::Screen;
 
 pub fn plugin(app: &mut App) {
@@ -12,16 +13,22 @@ pub fn plugin(app: &mut App) {
         .add_input_context::<DojoInput>()
         .add_observer(handle_toggle_fullscreen)
         .add_observer(handle_return_to_menu)
+        .add_observer(handle_toggle_nav_recording)
+        .add_observer(handle_toggle_nav_debug)
+        .add_observer(handle_toggle_movement_tuning)
         .add_observer(player_binding)
         .add_observer(global_binding)
         .add_observer(dojo_binding)
         .add_observer(apply_movement)
         .add_observer(jump)
+        .add_observer(jump_released)
         .add_observer(sprint_started)
         .add_observer(sprint_completed)
         .add_observer(handle_create_game)
+        .add_observer(handle_toggle_connection_status)
         .add_observer(handle_interact)
         .add_observer(handle_fight_move)
+        .add_observer(handle_second_fight_move)
         .add_observer(handle_go_to_fight_scene);
 }
 
@@ -30,7 +37,28 @@ fn spawn_system_action(mut commands: Commands) {
     commands.spawn(Actions::<DojoInput>::default());
 }
 
-fn player_binding(trigger: Trigger<Binding<Player>>, mut players: Query<&mut Actions<Player>>) {
+/// Whether the left stick is deflected past `deadzone`. The movement binding's `DeadZone`
+/// takes the same `InputSettings` value, so idle detection and movement agree.
+pub fn is_stick_held(stick: Vec2, deadzone: f32) -> bool {
+    stick.length() > deadzone
+}
+
+/// Player bindings as shown in the Controls tab: (action, keyboard, gamepad)
+pub const CONTROL_BINDINGS: [(&str, &str, &str); 7] = [
+    ("Move", "WASD / Arrow Keys", "Left Stick"),
+    ("Jump", "Space", "South (A)"),
+    ("Sprint", "Shift", "-"),
+    ("Interact", "E", "-"),
+    ("Fight Move 1", "X", "West (X)"),
+    ("Fight Move 2", "Shift + X", "North (Y)"),
+    ("Enter Fight", "Comma", "-"),
+];
+
+fn player_binding(
+    trigger: Trigger<Binding<Player>>,
+    mut players: Query<&mut Actions<Player>>,
+    input_settings: Res<InputSettings>,
+) {
     if let Ok(mut actions) = players.get_mut(trigger.target()) {
         // Movement (WASD, Arrow Keys, Gamepad Left Stick)
         actions
@@ -40,8 +68,11 @@ fn player_binding(trigger: Trigger<Binding<Player>>, mut players: Query<&mut Act
                 Axial::left_stick(),
                 Cardinal::arrow_keys(),
             ))
-            .with_modifiers(DeadZone::default());
-        // Jump (Spacebar)
+            .with_modifiers(DeadZone {
+                lower_threshold: input_settings.stick_deadzone,
+                ..default()
+            });
+        // Jump (Spacebar, Gamepad South)
         actions
             .bind::<Jump>()
             .to((KeyCode::Space, GamepadButton::South));
@@ -54,8 +85,13 @@ fn player_binding(trigger: Trigger<Binding<Player>>, mut players: Query<&mut Act
         // Interact (E key)
         actions.bind::<Interact>().to(KeyCode::KeyE);
 
-        // Fight Move (X key, with or without shift)
-        actions.bind::<FightMove>().to(KeyCode::KeyX);
+        // Fight Move (X key, with or without shift, Gamepad West)
+        actions
+            .bind::<FightMove>()
+            .to((KeyCode::KeyX, GamepadButton::West));
+
+        // Second Fight Move on its own face button, since a gamepad has no shift (Gamepad North)
+        actions.bind::<SecondFightMove>().to(GamepadButton::North);
 
         // Go to Fight Scene (Comma key)
         actions.bind::<GoToFightScene>().to(KeyCode::Comma);
@@ -78,6 +114,15 @@ fn global_binding(
             .to((KeyCode::F11, (KeyCode::AltLeft, KeyCode::Enter)));
 
         actions.bind::<ReturnToMainMenu>().to(KeyCode::Escape);
+
+        // Start/stop recording navigation data (F9)
+        actions.bind::<ToggleNavRecording>().to(KeyCode::F9);
+
+        // Show/hide navigation debug gizmos (F10)
+        actions.bind::<ToggleNavDebug>().to(KeyCode::F10);
+
+        // Show/hide the movement tuning panel (F6)
+        actions.bind::<ToggleMovementTuning>().to(KeyCode::F6);
     } else {
         error!(
             "Failed to get system actions for entity {:?}",
@@ -93,6 +138,9 @@ fn dojo_binding(
     if let Ok(mut actions) = dojo_actions.get_mut(trigger.target()) {
         // Create Game (G key)
         actions.bind::<CreateGame>().to(KeyCode::KeyG);
+
+        // Show/hide the blockchain connection badge (F8)
+        actions.bind::<ToggleConnectionStatus>().to(KeyCode::F8);
     } else {
         error!(
             "Failed to get dojo actions for entity {:?}",
@@ -107,7 +155,9 @@ fn apply_movement(
     mut movement_events: EventWriter<crate::systems::character_controller::MovementAction>,
     mut last_input: ResMut<crate::systems::character_controller::LastInputDirection>,
 ) {
-    let direction = trigger.value;
+    // Keyboard axes sum to length 1 or more (diagonals), the stick reports its tilt below 1,
+    // so clamping keeps keys digital while the stick stays analog
+    let direction = trigger.value.clamp_length_max(1.0);
     if direction != Vec2::ZERO {
         // Convert Vec2 to avian3d Vector2
         let avian_direction = avian3d::math::Vector2::new(direction.x, direction.y);
@@ -125,6 +175,14 @@ fn jump(
     movement_events.write(crate::systems::character_controller::MovementAction::Jump);
 }
 
+// Letting go early makes for a shorter jump
+fn jump_released(
+    _trigger: Trigger<Completed<Jump>>,
+    mut movement_events: EventWriter<crate::systems::character_controller::MovementAction>,
+) {
+    movement_events.write(crate::systems::character_controller::MovementAction::JumpReleased);
+}
+
 fn sprint_started(
     _trigger: Trigger<Started<Sprint>>,
     mut animation_query: Query<
@@ -174,6 +232,10 @@ pub struct Interact;
 #[input_action(output = bool)]
 pub struct FightMove;
 
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub struct SecondFightMove;
+
 #[derive(Debug, InputAction)]
 #[input_action(output = bool)]
 pub struct GoToFightScene;
@@ -192,6 +254,20 @@ struct ToggleFullScreen;
 #[input_action(output = bool)]
 struct ReturnToMainMenu;
 
+/// Action for starting/stopping the navigation recorder
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleNavRecording;
+
+/// Action for toggling the navigation debug overlay
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleNavDebug;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleMovementTuning;
+
 /// Input context for Dojo blockchain interactions
 #[derive(InputContext)]
 pub struct DojoInput;
@@ -201,6 +277,11 @@ pub struct DojoInput;
 #[input_action(output = bool)]
 struct CreateGame;
 
+/// Action for showing/hiding the connection status badge
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleConnectionStatus;
+
 fn handle_toggle_fullscreen(
     trigger: Trigger<Started<ToggleFullScreen>>,
     mut windows: Query<&mut Window>,
@@ -223,43 +304,138 @@ fn handle_toggle_fullscreen(
 
 fn handle_return_to_menu(
     trigger: Trigger<Started<ReturnToMainMenu>>,
+    mut commands: Commands,
     mut next_state: ResMut<NextState<Screen>>,
+    screen: Res<State<Screen>>,
     modal_state: Option<Res<crate::ui::modal::ModalState>>,
+    mut confirm_state: ResMut<crate::ui::modal::ConfirmDialogState>,
+    font_assets: Option<Res<crate::assets::FontAssets>>,
+    frame: Res<bevy::diagnostic::FrameCount>,
 ) {
     if trigger.value {
-        // Check if modal is open - if so, don't return to main menu
+        // Check if modal is open - if so, don't return to main menu. This observer runs
+        // in PreUpdate, before `toggle_modal_visibility`, so on the frame ESC closes the
+        // modal it is still seen as open here
         if let Some(modal_state) = modal_state {
             if modal_state.visible {
                 return; // Modal is open, let the modal handle ESC
             }
         }
-        
-        next_state.set(Screen::MainMenu);
+
+        // An open confirm dialog answers ESC itself
+        if confirm_state.is_open() {
+            return;
+        }
+
+        match screen.get() {
+            // Already there; the fight scene handles ESC itself (back to gameplay)
+            Screen::MainMenu | Screen::FightScene => {}
+            // Leaving gameplay loses progress, so ask first
+            Screen::GamePlay => {
+                if let Some(font_assets) = font_assets {
+                    crate::ui::modal::spawn_confirm_dialog(
+                        &mut commands,
+                        &font_assets,
+                        &mut confirm_state,
+                        frame.0,
+                        "Return to main menu?\nUnsaved progress may be lost.",
+                        crate::ui::modal::ConfirmAction::ReturnToMainMenu,
+                    );
+                }
+            }
+            // Cancels loading; `cancel_pregame_loading` clears what was loaded so far
+            _ => next_state.set(Screen::MainMenu),
+        }
+    }
+}
+
+fn handle_toggle_nav_recording(
+    trigger: Trigger<Started<ToggleNavRecording>>,
+    screen: Res<State<Screen>>,
+    capabilities: Res<crate::resources::platform::PlatformCapabilities>,
+    mut recorder: ResMut<crate::systems::navigation_recorder::NavigationRecorder>,
+) {
+    // Only the overworld has a navigation mesh worth recording
+    if trigger.value && *screen.get() == Screen::GamePlay {
+        if !capabilities.can_record_nav {
+            warn!("Navigation recording isn't available on this platform");
+            return;
+        }
+        recorder.toggle();
+    }
+}
+
+fn handle_toggle_nav_debug(
+    trigger: Trigger<Started<ToggleNavDebug>>,
+    mut settings: ResMut<crate::systems::navigation_debug::NavigationDebugSettings>,
+) {
+    if trigger.value {
+        settings.enabled = !settings.enabled;
+    }
+}
+
+fn handle_toggle_movement_tuning(
+    trigger: Trigger<Started<ToggleMovementTuning>>,
+    mut settings: ResMut<crate::ui::movement_tuning::MovementTuningPanelSettings>,
+) {
+    if trigger.value {
+        settings.visible = !settings.visible;
     }
 }
 
 fn handle_create_game(
     trigger: Trigger<Started<CreateGame>>,
-    mut create_game_events: EventWriter<crate::systems::dojo::CreateGameEvent>,
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    dojo_state: Option<Res<crate::systems::dojo::DojoSystemState>>,
+    mut last_sent: Local<Option<f32>>,
 ) {
-    if trigger.value {
-        create_game_events.write(crate::systems::dojo::CreateGameEvent);
+    if !trigger.value {
+        return;
+    }
+    let Some(dojo_state) = dojo_state else {
+        warn!("Dojo integration is disabled, not creating a game");
+        return;
+    };
+    if !dojo_state.account_connected {
+        warn!("No blockchain account connected, not creating a game");
+        return;
+    }
+
+    // Repeated presses would otherwise each queue a transaction
+    let now = time.elapsed_secs();
+    if last_sent.is_some_and(|sent| now - sent < crate::constants::dojo::CREATE_GAME_DEBOUNCE_SECS) {
+        return;
+    }
+    *last_sent = Some(now);
+    commands.send_event(crate::systems::dojo::CreateGameEvent);
+}
+
+fn handle_toggle_connection_status(
+    trigger: Trigger<Started<ToggleConnectionStatus>>,
+    settings: Option<ResMut<crate::ui::connection_status::ConnectionStatusSettings>>,
+) {
+    // The badge only exists while the Dojo integration is enabled
+    if let (true, Some(mut settings)) = (trigger.value, settings) {
+        settings.visible = !settings.visible;
     }
 }
 
 fn handle_interact(
     trigger: Trigger<Started<Interact>>,
     player_query: Query<&Transform, With<crate::systems::character_controller::CharacterController>>,
-    book_query: Query<&Transform, With<crate::systems::book_interaction::Book>>,
+    book_query: Query<
+        (&Transform, &crate::ui::dialog::Interactable),
+        With<crate::systems::book_interaction::Book>,
+    >,
     mut next_state: ResMut<NextState<Screen>>,
 ) {
     if trigger.value {
         // Check if player is near the book
-        if let (Ok(player_transform), Ok(book_transform)) = (player_query.single(), book_query.single()) {
+        if let (Ok(player_transform), Ok((book_transform, interactable))) = (player_query.single(), book_query.single()) {
             let distance = player_transform.translation.distance(book_transform.translation);
-            let proximity_threshold = 5.0;
 
-            if distance <= proximity_threshold {
+            if distance <= interactable.range {
                 next_state.set(Screen::FightScene);
                 return;
             }
@@ -285,6 +461,15 @@ fn handle_fight_move(
     }
 }
 
+fn handle_second_fight_move(
+    trigger: Trigger<Started<SecondFightMove>>,
+    mut movement_events: EventWriter<crate::systems::character_controller::MovementAction>,
+) {
+    if trigger.value {
+        movement_events.write(crate::systems::character_controller::MovementAction::FightMove2);
+    }
+}
+
 fn handle_go_to_fight_scene(
     trigger: Trigger<Started<GoToFightScene>>,
     mut next_state: ResMut<NextState<Screen>>,
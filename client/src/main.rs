@@ -12,6 +12,8 @@ mod rendering;
 mod resources;
 mod screens;
 mod systems;
+#[cfg(test)]
+mod test_support;
 mod ui;
 
 pub use resources::assets;
@@ -32,8 +34,11 @@ fn main() -> AppExit {
                 prevent_default_event_handling: false,
                 ..default()
             }),
+            // Closing goes through the shutdown plugin so the game saves first
+            close_when_requested: false,
             ..default()
         }))
+        .insert_resource(resources::platform::PlatformCapabilities::detect())
         .add_systems(Startup, (setup_camera, setup_global_lighting))
         .insert_resource(ClearColor(Color::srgb(0.1, 0.1, 0.1))) // Dark background initially
         .add_plugins(UiLunexPlugins)
@@ -45,6 +50,17 @@ fn main() -> AppExit {
         .add_plugins(assets::AssetsPlugin)
         .add_plugins(GameAudioPlugin)
         .add_plugins(SfxPlugin)
+        .add_plugins(resources::graphics::GraphicsSettingsPlugin)
+        .add_plugins(resources::hud::HudSettingsPlugin)
+        .add_plugins(resources::input::InputSettingsPlugin)
+        .add_plugins(ui::styles::PalettePlugin)
+        .add_plugins(ui::widgets::ProgressBarPlugin)
+        .add_plugins(ui::toast::ToastPlugin)
+        .add_plugins(ui::connection_status::ConnectionStatusPlugin)
+        .add_plugins(resources::difficulty::DifficultyPlugin)
+        .add_plugins(resources::locale::LocalePlugin)
+        .add_plugins(resources::pause::GamePausePlugin)
+        .add_plugins(resources::shutdown::ShutdownPlugin)
         .add_event::<systems::dojo::pickup_item::PickupItemEvent>()
         .add_event::<systems::dojo::pickup_item::ItemPickedUpEvent>()
         .add_event::<systems::dojo::pickup_item::ItemPickupFailedEvent>()
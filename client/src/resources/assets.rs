@@ -1,7 +1,9 @@
+use bevy::asset::AssetLoadFailedEvent;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 use bevy_kira_audio::AudioSource;
 
+use crate::constants::loading::ASSET_LOAD_TIMEOUT_SECS;
 use crate::screens::Screen;
 
 pub struct AssetsPlugin;
@@ -11,16 +13,95 @@ impl Plugin for AssetsPlugin {
         app.add_loading_state(
             LoadingState::new(Screen::Loading)
                 .continue_to_state(Screen::MainMenu)
+                .on_failure_continue_to_state(Screen::MainMenu)
                 .load_collection::<UiAssets>()
                 .load_collection::<AudioAssets>()
                 .load_collection::<FontAssets>()
                 .load_collection::<ModelAssets>(),
+        )
+        .init_resource::<AssetLoadReport>()
+        .add_systems(
+            Update,
+            (
+                record_failed_assets::<Image>,
+                record_failed_assets::<Font>,
+                record_failed_assets::<Scene>,
+                record_failed_assets::<Gltf>,
+                record_failed_assets::<AudioSource>,
+            ),
+        )
+        .add_systems(
+            Update,
+            enforce_asset_load_timeout.run_if(in_state(Screen::Loading)),
+        )
+        // Whatever failed or timed out gets a placeholder so later screens can still run
+        .add_systems(OnExit(Screen::Loading), insert_placeholder_assets);
+    }
+}
+
+/// Assets that failed to load (missing files or decode errors), for display in loading UIs
+#[derive(Resource, Default)]
+pub struct AssetLoadReport {
+    pub failed: Vec<String>,
+    pub timed_out: bool,
+}
+
+impl AssetLoadReport {
+    pub fn has_errors(&self) -> bool {
+        !self.failed.is_empty() || self.timed_out
+    }
+}
+
+fn record_failed_assets<A: Asset>(
+    mut failed_events: EventReader<AssetLoadFailedEvent<A>>,
+    mut report: ResMut<AssetLoadReport>,
+) {
+    for event in failed_events.read() {
+        error!("Failed to load asset {}: {}", event.path, event.error);
+        report.failed.push(format!("{}: {}", event.path, event.error));
+    }
+}
+
+fn enforce_asset_load_timeout(
+    time: Res<Time<Real>>,
+    mut waited: Local<f32>,
+    mut report: ResMut<AssetLoadReport>,
+    mut next_state: ResMut<NextState<Screen>>,
+) {
+    *waited += time.delta_secs();
+    if *waited >= ASSET_LOAD_TIMEOUT_SECS && !report.timed_out {
+        warn!(
+            "Assets still loading after {}s, continuing with placeholders",
+            ASSET_LOAD_TIMEOUT_SECS
         );
+        report.timed_out = true;
+        next_state.set(Screen::MainMenu);
     }
 }
 
+fn insert_placeholder_assets(mut commands: Commands, report: Res<AssetLoadReport>) {
+    if report.has_errors() {
+        warn!(
+            "Continuing with placeholder assets ({} failed to load)",
+            report.failed.len()
+        );
+    }
+
+    init_placeholder_assets(&mut commands);
+}
+
+/// Fills in any collection that never arrived with default handles. Default image
+/// and font handles still render (white texture / built-in font); models render empty.
+pub fn init_placeholder_assets(commands: &mut Commands) {
+    // init_resource leaves collections that loaded successfully untouched
+    commands.init_resource::<UiAssets>();
+    commands.init_resource::<AudioAssets>();
+    commands.init_resource::<FontAssets>();
+    commands.init_resource::<ModelAssets>();
+}
+
 // UI Assets
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct UiAssets {
     // Backgrounds
     #[asset(path = "images/ui/background.png")]
@@ -62,7 +143,7 @@ pub struct UiAssets {
 }
 
 // Audio Assets
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct AudioAssets {
     #[asset(path = "audio/main_menu.ogg")]
     pub main_menu_track: Handle<AudioSource>,
@@ -81,7 +162,7 @@ pub struct AudioAssets {
 }
 
 // Font Assets
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct FontAssets {
     #[asset(path = "fonts/GoudyTrajan-Regular.otf")]
     pub rajdhani_bold: Handle<Font>,
@@ -94,7 +175,7 @@ pub struct FontAssets {
 }
 
 // Model Assets (for future use)
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct ModelAssets {
     #[asset(path = "models/coin.glb#Scene0")]
     pub coin: Handle<Scene>,
@@ -113,6 +194,9 @@ pub struct ModelAssets {
 
     #[asset(path = "models/book.glb#Scene0")]
     pub book: Handle<Scene>,
+
+    #[asset(path = "models/mystery_box.glb#Scene0")]
+    pub mystery_box: Handle<Scene>,
 }
 
 // Movie/Video Assets
@@ -1,13 +1,37 @@
 use crate::screens::Screen;
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 use crate::assets::AudioAssets;
+use crate::constants::audio::AUDIO_SETTINGS_PATH;
+use crate::resources::platform::{PersistedSettings, save_on_change};
+
+/// Background music tracks a screen can ask for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MusicTrack {
+    MainMenu,
+    Intro,
+}
+
+impl MusicTrack {
+    /// Music each screen plays; `None` lets the current track fade out
+    pub fn for_screen(screen: &Screen) -> Option<Self> {
+        match screen {
+            Screen::Loading => None,
+            Screen::MainMenu | Screen::NewGame | Screen::Settings | Screen::Credits => {
+                Some(MusicTrack::MainMenu)
+            }
+            Screen::PreGameLoading | Screen::GamePlay | Screen::FightScene => Some(MusicTrack::Intro),
+        }
+    }
+}
 
 #[derive(Resource, Default)]
 pub struct AudioResources {
     pub current_music: Option<Handle<AudioInstance>>,
+    pub current_track: Option<MusicTrack>,
     pub main_menu_track: Option<Handle<AudioSource>>,
     pub intro_track: Option<Handle<AudioSource>>,
 }
@@ -18,11 +42,18 @@ pub struct MusicChannel;
 #[derive(Resource)]
 pub struct SfxChannel;
 
+/// Menu and HUD feedback sounds, kept apart from gameplay effects
 #[derive(Resource)]
+pub struct UiChannel;
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AudioSettings {
     pub master_volume: f64,
     pub music_volume: f64,
     pub sfx_volume: f64,
+    pub ui_volume: f64,
+    /// Mutes every channel without touching the individual volumes
     pub muted: bool,
 }
 
@@ -32,24 +63,42 @@ impl Default for AudioSettings {
             master_volume: 1.0,
             music_volume: 1.0,
             sfx_volume: 0.7,
+            ui_volume: 0.8,
             muted: false,
         }
     }
 }
 
+impl PersistedSettings for AudioSettings {
+    const PATH: &'static str = AUDIO_SETTINGS_PATH;
+
+    fn sanitize(&mut self) {
+        for volume in [
+            &mut self.master_volume,
+            &mut self.music_volume,
+            &mut self.sfx_volume,
+            &mut self.ui_volume,
+        ] {
+            *volume = volume.clamp(0.0, 1.0);
+        }
+    }
+}
+
 pub struct GameAudioPlugin;
 
 impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AudioResources>()
-            .init_resource::<AudioSettings>()
+            .insert_resource(AudioSettings::load())
             .add_audio_channel::<MusicChannel>()
             .add_audio_channel::<SfxChannel>()
+            .add_audio_channel::<UiChannel>()
             .add_systems(OnEnter(Screen::MainMenu), setup_audio)
             .add_systems(
                 Update,
                 (
-                    apply_audio_settings,
+                    apply_audio_settings.run_if(resource_changed::<AudioSettings>),
+                    save_on_change::<AudioSettings>.run_if(resource_changed::<AudioSettings>),
                     handle_screen_transitions,
                     instance_control,
                 ),
@@ -62,20 +111,52 @@ fn setup_audio(audio_assets: Res<AudioAssets>, mut audio_resources: ResMut<Audio
     audio_resources.intro_track = Some(audio_assets.intro_track.clone());
 }
 
+impl AudioResources {
+    fn track_source(&self, track: MusicTrack) -> Option<&Handle<AudioSource>> {
+        match track {
+            MusicTrack::MainMenu => self.main_menu_track.as_ref(),
+            MusicTrack::Intro => self.intro_track.as_ref(),
+        }
+    }
+}
+
+/// Pushes `AudioSettings` into the kira channels whenever they change. Channel
+/// volume scales every instance on the channel, including ones started later,
+/// so master at 0 silences everything and music/sfx stay independent.
 fn apply_audio_settings(
     settings: Res<AudioSettings>,
     music: Res<AudioChannel<MusicChannel>>,
     sfx: Res<AudioChannel<SfxChannel>>,
+    ui: Res<AudioChannel<UiChannel>>,
+    mut was_muted: Local<bool>,
 ) {
     if settings.muted {
+        // Suspend what's playing and silence anything started while muted
+        if !*was_muted {
+            music.pause();
+            sfx.pause();
+            ui.pause();
+        }
         music.set_volume(0.0);
         sfx.set_volume(0.0);
+        ui.set_volume(0.0);
+        *was_muted = true;
         return;
     }
+
+    if *was_muted {
+        music.resume();
+        sfx.resume();
+        ui.resume();
+        *was_muted = false;
+    }
     music.set_volume(settings.master_volume * settings.music_volume);
     sfx.set_volume(settings.master_volume * settings.sfx_volume);
+    ui.set_volume(settings.master_volume * settings.ui_volume);
 }
 
+/// Cross-fades to the track the new screen asks for, leaving it alone when
+/// adjacent screens share a track
 fn handle_screen_transitions(
     mut audio_resources: ResMut<AudioResources>,
     mut audio_instances: ResMut<Assets<AudioInstance>>,
@@ -89,7 +170,12 @@ fn handle_screen_transitions(
 
     *prev_state = current_state.get().clone();
 
-    // Stop current music
+    let next_track = MusicTrack::for_screen(current_state.get());
+    if next_track == audio_resources.current_track {
+        return;
+    }
+
+    // Fade out current music
     if let Some(handle) = audio_resources.current_music.take() {
         if let Some(instance) = audio_instances.get_mut(&handle) {
             instance.stop(AudioTween::new(
@@ -98,24 +184,24 @@ fn handle_screen_transitions(
             ));
         }
     }
+    audio_resources.current_track = None;
 
-    // Play new track if needed
-    if matches!(
-        current_state.get(),
-        Screen::MainMenu | Screen::NewGame | Screen::Settings
-    ) {
-        if let Some(track) = &audio_resources.main_menu_track {
-            let handle = music_channel
-                .play(track.clone())
-                .looped()
-                .fade_in(AudioTween::new(
-                    Duration::from_secs(2),
-                    AudioEasing::OutPowf(2.0),
-                ))
-                .handle();
-
-            audio_resources.current_music = Some(handle);
-        }
+    // Fade in the new track; the channel volume keeps it in line with music_volume
+    let Some(track) = next_track else {
+        return;
+    };
+    if let Some(source) = audio_resources.track_source(track).cloned() {
+        let handle = music_channel
+            .play(source)
+            .looped()
+            .fade_in(AudioTween::new(
+                Duration::from_secs(2),
+                AudioEasing::OutPowf(2.0),
+            ))
+            .handle();
+
+        audio_resources.current_music = Some(handle);
+        audio_resources.current_track = Some(track);
     }
 }
 
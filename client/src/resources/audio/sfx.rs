@@ -3,8 +3,8 @@ use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
 
 use crate::assets::AudioAssets;
-use crate::resources::audio::{SfxChannel, AudioSettings};
-use crate::systems::character_controller::{AnimationState, CharacterController};
+use crate::resources::audio::{SfxChannel, AudioSettings, UiChannel};
+use crate::systems::character_controller::{AnimationState, CharacterController, MovementInputHeld};
 use avian3d::prelude::LinearVelocity;
 
 
@@ -27,8 +27,13 @@ pub struct MovementAudioState {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SfxType {
     CoinCollect,
+    ItemUse,
     Walking,
     Running,
+    /// A menu or HUD button was pressed
+    UiClick,
+    /// A toast popped up
+    UiNotify,
 }
 
 pub struct SfxPlugin;
@@ -38,17 +43,28 @@ impl Plugin for SfxPlugin {
         app.init_resource::<MovementAudioState>()
             .add_event::<PlaySfxEvent>()
             .add_event::<StopMovementAudioEvent>()
-            .add_systems(Update, play_sfx_events)
+            .add_systems(Update, (play_button_click_sfx, play_sfx_events).chain())
             .add_systems(Update, stop_movement_audio)
             .add_systems(Update, handle_movement_sfx.run_if(in_state(crate::screens::Screen::GamePlay)))
             .add_systems(Update, handle_movement_sfx.run_if(in_state(crate::screens::Screen::FightScene)));
     }
 }
 
+/// Every bevy_ui button clicks through the UI channel, so menus don't each need to ask
+fn play_button_click_sfx(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+    if interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        sfx_events.write(PlaySfxEvent { sfx_type: SfxType::UiClick });
+    }
+}
+
 fn play_sfx_events(
     mut sfx_events: EventReader<PlaySfxEvent>,
     audio_assets: Option<Res<AudioAssets>>,
     sfx_channel: Res<AudioChannel<SfxChannel>>,
+    ui_channel: Res<AudioChannel<UiChannel>>,
     audio_settings: Res<AudioSettings>,
 ) {
     let Some(assets) = audio_assets else {
@@ -64,12 +80,20 @@ fn play_sfx_events(
             SfxType::CoinCollect => {
                 sfx_channel.play(assets.coin_sound.clone());
             }
+            SfxType::ItemUse => {
+                // No dedicated sound yet; the pickup chime reads well enough
+                sfx_channel.play(assets.coin_sound.clone());
+            }
             SfxType::Walking => {
                 sfx_channel.play(assets.walking_sound.clone()).looped();
             }
             SfxType::Running => {
                 sfx_channel.play(assets.running_sound.clone()).looped();
             }
+            // No dedicated UI sounds yet; the chime, played on the UI channel, stands in
+            SfxType::UiClick | SfxType::UiNotify => {
+                ui_channel.play(assets.coin_sound.clone());
+            }
         }
     }
 }
@@ -79,13 +103,10 @@ fn handle_movement_sfx(
     mut stop_events: EventWriter<StopMovementAudioEvent>,
     mut movement_state: ResMut<MovementAudioState>,
     character_query: Query<(&LinearVelocity, &AnimationState), With<CharacterController>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    movement_input: Res<MovementInputHeld>,
 ) {
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed([
-        KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD,
-        KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
-    ]);
+    // Check if any movement input is held
+    let is_movement_pressed = movement_input.0;
 
     // Check if character is moving (based on velocity)
     let is_moving = character_query.iter().any(|(velocity, _)| {
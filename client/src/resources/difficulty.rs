@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::difficulty::{DIFFICULTY_SETTINGS_PATH, DifficultyModifiers};
+use crate::resources::platform::{PersistedSettings, save_on_change};
+use crate::screens::Screen;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+        }
+    }
+
+    pub fn modifiers(self) -> DifficultyModifiers {
+        match self {
+            Difficulty::Easy => DifficultyModifiers::EASY,
+            Difficulty::Normal => DifficultyModifiers::NORMAL,
+            Difficulty::Hard => DifficultyModifiers::HARD,
+        }
+    }
+}
+
+/// The difficulty picked in settings; takes effect from the next level started
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DifficultySettings {
+    pub difficulty: Difficulty,
+}
+
+impl PersistedSettings for DifficultySettings {
+    const PATH: &'static str = DIFFICULTY_SETTINGS_PATH;
+}
+
+/// The difficulty the current level is played on. Copied from `DifficultySettings` when
+/// a level starts, so a change made mid-run only applies from the next level; restarting
+/// the level keeps it.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ActiveDifficulty(pub Difficulty);
+
+impl ActiveDifficulty {
+    pub fn modifiers(&self) -> DifficultyModifiers {
+        self.0.modifiers()
+    }
+}
+
+/// `value` scaled by a difficulty multiplier, rounded, never below 1 unless it was 0
+pub fn scale_u32(value: u32, multiplier: f32) -> u32 {
+    if value == 0 {
+        return 0;
+    }
+    ((value as f32 * multiplier).round() as u32).max(1)
+}
+
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = DifficultySettings::load();
+        app.insert_resource(ActiveDifficulty(settings.difficulty))
+            .insert_resource(settings)
+            // Before `reset_waves`, which sizes the waves from the active difficulty
+            .add_systems(
+                OnEnter(Screen::GamePlay),
+                apply_difficulty_for_level.before(crate::systems::waves::reset_waves),
+            )
+            .add_systems(
+                Update,
+                save_on_change::<DifficultySettings>.run_if(resource_changed::<DifficultySettings>),
+            );
+    }
+}
+
+fn apply_difficulty_for_level(settings: Res<DifficultySettings>, mut active: ResMut<ActiveDifficulty>) {
+    active.0 = settings.difficulty;
+    info!("Starting level on {} difficulty", settings.difficulty.label());
+}
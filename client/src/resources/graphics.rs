@@ -0,0 +1,169 @@
+use bevy::pbr::DirectionalLightShadowMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::collectibles::COIN_STREAMING_RADIUS;
+use crate::constants::graphics::{
+    FOV_DEFAULT_DEGREES, FOV_MAX_DEGREES, FOV_MIN_DEGREES, GRAPHICS_SETTINGS_PATH,
+    RENDER_DISTANCE_DEFAULT, RENDER_DISTANCE_MAX, RENDER_DISTANCE_MIN, SHADOW_MAP_SIZE_HIGH,
+    SHADOW_MAP_SIZE_LOW, SHADOW_MAP_SIZE_MEDIUM,
+};
+use crate::resources::platform::{IS_WEB, PersistedSettings, save_on_change};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShadowQuality {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl ShadowQuality {
+    pub fn next(self) -> Self {
+        match self {
+            ShadowQuality::Low => ShadowQuality::Medium,
+            ShadowQuality::Medium => ShadowQuality::High,
+            ShadowQuality::High => ShadowQuality::Low,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ShadowQuality::Low => "LOW",
+            ShadowQuality::Medium => "MEDIUM",
+            ShadowQuality::High => "HIGH",
+        }
+    }
+
+    /// Highest quality the platform handles well; WebGL2 struggles with 4k shadow maps
+    pub fn supported(self) -> Self {
+        if IS_WEB && self == ShadowQuality::High {
+            ShadowQuality::Medium
+        } else {
+            self
+        }
+    }
+
+    pub fn shadow_map_size(self) -> usize {
+        match self.supported() {
+            ShadowQuality::Low => SHADOW_MAP_SIZE_LOW,
+            ShadowQuality::Medium => SHADOW_MAP_SIZE_MEDIUM,
+            ShadowQuality::High => SHADOW_MAP_SIZE_HIGH,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphicsSettings {
+    pub shadows_enabled: bool,
+    /// Cheap blob shadows under characters, independent of shadow mapping
+    pub ground_shadows: bool,
+    pub shadow_quality: ShadowQuality,
+    /// Horizontal field of view in degrees
+    pub fov_degrees: f32,
+    pub render_distance: f32,
+    /// Runs the gameplay day/night lighting cycle; off by default for performance
+    pub day_night_cycle: bool,
+}
+
+impl Default for GraphicsSettings {
+    fn default() -> Self {
+        Self {
+            shadows_enabled: true,
+            ground_shadows: true,
+            shadow_quality: ShadowQuality::Medium,
+            fov_degrees: FOV_DEFAULT_DEGREES,
+            render_distance: RENDER_DISTANCE_DEFAULT,
+            day_night_cycle: false,
+        }
+    }
+}
+
+impl GraphicsSettings {
+    pub fn set_fov_degrees(&mut self, degrees: f32) {
+        self.fov_degrees = degrees.clamp(FOV_MIN_DEGREES, FOV_MAX_DEGREES);
+    }
+
+    pub fn set_render_distance(&mut self, distance: f32) {
+        self.render_distance = distance.clamp(RENDER_DISTANCE_MIN, RENDER_DISTANCE_MAX);
+    }
+
+    /// Coins never stream in beyond the render distance, so lowering it also cuts streaming work
+    pub fn coin_streaming_radius(&self) -> f32 {
+        COIN_STREAMING_RADIUS.min(self.render_distance)
+    }
+}
+
+impl PersistedSettings for GraphicsSettings {
+    const PATH: &'static str = GRAPHICS_SETTINGS_PATH;
+
+    fn sanitize(&mut self) {
+        self.set_fov_degrees(self.fov_degrees);
+        self.set_render_distance(self.render_distance);
+    }
+}
+
+/// Directional lights whose shadows follow `GraphicsSettings`
+#[derive(Component)]
+pub struct ShadowCastingLight;
+
+pub struct GraphicsSettingsPlugin;
+
+impl Plugin for GraphicsSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GraphicsSettings::load())
+            .add_systems(
+                Update,
+                (
+                    save_on_change::<GraphicsSettings>.run_if(resource_changed::<GraphicsSettings>),
+                    apply_shadow_settings,
+                    apply_camera_settings,
+                ),
+            );
+    }
+}
+
+/// Applies shadow settings to the shadow map and to newly spawned or existing lights
+fn apply_shadow_settings(
+    settings: Res<GraphicsSettings>,
+    mut shadow_map: ResMut<DirectionalLightShadowMap>,
+    mut light_query: Query<(&mut DirectionalLight, Ref<ShadowCastingLight>)>,
+) {
+    let size = settings.shadow_quality.shadow_map_size();
+    if settings.is_changed() && shadow_map.size != size {
+        shadow_map.size = size;
+    }
+
+    for (mut light, marker) in light_query.iter_mut() {
+        if (settings.is_changed() || marker.is_added())
+            && light.shadows_enabled != settings.shadows_enabled
+        {
+            light.shadows_enabled = settings.shadows_enabled;
+        }
+    }
+}
+
+/// Applies field of view and far clip to every perspective 3D camera. The horizontal
+/// FOV is converted using the camera's aspect ratio, which follows window resizes,
+/// so this compares every frame and only writes when something differs.
+fn apply_camera_settings(
+    settings: Res<GraphicsSettings>,
+    mut camera_query: Query<&mut Projection, With<Camera3d>>,
+) {
+    for mut projection in camera_query.iter_mut() {
+        let Projection::Perspective(perspective) = &*projection else {
+            continue;
+        };
+        let half_horizontal = settings.fov_degrees.to_radians() / 2.0;
+        let fov = 2.0 * (half_horizontal.tan() / perspective.aspect_ratio.max(f32::EPSILON)).atan();
+        let far = settings.render_distance;
+
+        if (perspective.fov - fov).abs() > f32::EPSILON || perspective.far != far {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                perspective.fov = fov;
+                perspective.far = far;
+            }
+        }
+    }
+}
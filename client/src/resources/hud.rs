@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::hud::{
+    HUD_SETTINGS_PATH, UI_REFERENCE_HEIGHT, UI_REFERENCE_WIDTH, UI_SCALE_DEFAULT, UI_SCALE_MAX,
+    UI_SCALE_MIN, UI_WINDOW_SCALE_MAX, UI_WINDOW_SCALE_MIN,
+};
+use crate::resources::platform::{PersistedSettings, save_on_change};
+use crate::ui::styles::UiPalette;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorblindMode {
+    #[default]
+    Off,
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    pub fn next(self) -> Self {
+        match self {
+            ColorblindMode::Off => ColorblindMode::Protanopia,
+            ColorblindMode::Protanopia => ColorblindMode::Deuteranopia,
+            ColorblindMode::Deuteranopia => ColorblindMode::Tritanopia,
+            ColorblindMode::Tritanopia => ColorblindMode::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorblindMode::Off => "DEFAULT",
+            ColorblindMode::Protanopia => "PROTANOPIA",
+            ColorblindMode::Deuteranopia => "DEUTERANOPIA",
+            ColorblindMode::Tritanopia => "TRITANOPIA",
+        }
+    }
+
+    pub fn palette(self) -> UiPalette {
+        match self {
+            ColorblindMode::Off => UiPalette::DEFAULT,
+            ColorblindMode::Protanopia => UiPalette::PROTANOPIA,
+            ColorblindMode::Deuteranopia => UiPalette::DEUTERANOPIA,
+            ColorblindMode::Tritanopia => UiPalette::TRITANOPIA,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HudSettings {
+    /// Whether finished objectives stay in the objectives panel, below the active ones
+    pub show_completed_objectives: bool,
+    /// Whether the compass strip is shown at the top of the gameplay HUD
+    pub compass_visible: bool,
+    /// Applied to Bevy's `UiScale`, so HUD, modal and objectives all grow or shrink together
+    pub ui_scale: f32,
+    /// Whether `ui_scale` is also multiplied by the window's size relative to 1920x1080
+    pub scale_with_window: bool,
+    /// Which `UiPalette` colors completion, health, xp and tab highlights
+    pub colorblind_mode: ColorblindMode,
+}
+
+impl Default for HudSettings {
+    fn default() -> Self {
+        Self {
+            show_completed_objectives: true,
+            compass_visible: true,
+            ui_scale: UI_SCALE_DEFAULT,
+            scale_with_window: true,
+            colorblind_mode: ColorblindMode::Off,
+        }
+    }
+}
+
+impl HudSettings {
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui_scale = scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    }
+}
+
+impl PersistedSettings for HudSettings {
+    const PATH: &'static str = HUD_SETTINGS_PATH;
+}
+
+/// `UiScale` for a window of `window_size` logical pixels. The window factor follows
+/// the tighter axis, so a 21:9 window scales by height and a 4:3 one by width, and
+/// pixel layouts made for 1920x1080 keep their proportions. Off the reference size
+/// this is rarely 1, so anything mixing pointer or viewport positions (logical pixels)
+/// with node layout or `Val::Px` (UI pixels) must divide by `UiScale` first.
+pub fn effective_ui_scale(user_scale: f32, window_size: Option<Vec2>, scale_with_window: bool) -> f32 {
+    // Hand-edited saves can hold anything, so clamp here as well as in the setter
+    let user_scale = user_scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    let window_factor = match window_size {
+        Some(size) if scale_with_window && size.x > 0.0 && size.y > 0.0 => (size.x / UI_REFERENCE_WIDTH)
+            .min(size.y / UI_REFERENCE_HEIGHT)
+            .clamp(UI_WINDOW_SCALE_MIN, UI_WINDOW_SCALE_MAX),
+        _ => 1.0,
+    };
+    user_scale * window_factor
+}
+
+pub struct HudSettingsPlugin;
+
+impl Plugin for HudSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = HudSettings::load();
+        app.insert_resource(settings.colorblind_mode.palette())
+            .insert_resource(settings)
+            .add_systems(
+                Update,
+                (
+                    apply_ui_scale
+                        .run_if(resource_changed::<HudSettings>.or(on_event::<WindowResized>)),
+                    (apply_color_palette, save_on_change::<HudSettings>).run_if(resource_changed::<HudSettings>),
+                ),
+            );
+    }
+}
+
+fn apply_ui_scale(
+    settings: Res<HudSettings>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let window_size = window_query.single().ok().map(|window| window.size());
+    let scale = effective_ui_scale(settings.ui_scale, window_size, settings.scale_with_window);
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
+
+fn apply_color_palette(settings: Res<HudSettings>, mut palette: ResMut<UiPalette>) {
+    // Only write on a real switch, so the palette painters don't repaint every settings change
+    palette.set_if_neq(settings.colorblind_mode.palette());
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::input::{
+    INPUT_SETTINGS_PATH, STICK_DEADZONE_DEFAULT, STICK_DEADZONE_MAX, STICK_DEADZONE_MIN,
+};
+use crate::resources::platform::{PersistedSettings, save_on_change};
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputSettings {
+    /// Read when the player's bindings are built, so a change applies from the next level
+    /// started; idle detection picks it up straight away
+    pub stick_deadzone: f32,
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self {
+            stick_deadzone: STICK_DEADZONE_DEFAULT,
+        }
+    }
+}
+
+impl InputSettings {
+    pub fn set_stick_deadzone(&mut self, deadzone: f32) {
+        self.stick_deadzone = deadzone.clamp(STICK_DEADZONE_MIN, STICK_DEADZONE_MAX);
+    }
+}
+
+impl PersistedSettings for InputSettings {
+    const PATH: &'static str = INPUT_SETTINGS_PATH;
+
+    fn sanitize(&mut self) {
+        self.set_stick_deadzone(self.stick_deadzone);
+    }
+}
+
+pub struct InputSettingsPlugin;
+
+impl Plugin for InputSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputSettings::load()).add_systems(
+            Update,
+            save_on_change::<InputSettings>.run_if(resource_changed::<InputSettings>),
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::constants::locale::LOCALE_SETTINGS_PATH;
+use crate::resources::platform::{PersistedSettings, save_on_change};
+
+// String tables are compiled in so every platform, web included, has them up front
+const ENGLISH_TABLE: &str = include_str!("../../assets/locales/en.json");
+const SPANISH_TABLE: &str = include_str!("../../assets/locales/es.json");
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub fn next(self) -> Self {
+        match self {
+            Language::English => Language::Spanish,
+            Language::Spanish => Language::English,
+        }
+    }
+
+    /// The language's own name, so it can be found without reading the current one
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::English => "ENGLISH",
+            Language::Spanish => "ESPAÑOL",
+        }
+    }
+
+    fn table(self) -> &'static str {
+        match self {
+            Language::English => ENGLISH_TABLE,
+            Language::Spanish => SPANISH_TABLE,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocaleSettings {
+    pub language: Language,
+}
+
+impl PersistedSettings for LocaleSettings {
+    const PATH: &'static str = LOCALE_SETTINGS_PATH;
+}
+
+/// UI strings for the selected language, looked up by key with `t`
+#[derive(Resource, Debug)]
+pub struct Locale {
+    pub language: Language,
+    strings: HashMap<String, String>,
+}
+
+impl Locale {
+    pub fn new(language: Language) -> Self {
+        let strings = serde_json::from_str(language.table()).unwrap_or_else(|e| {
+            error!("Failed to parse the {} string table: {}", language.label(), e);
+            HashMap::new()
+        });
+        Self { language, strings }
+    }
+
+    /// The string for `key`, or the key itself (with a warning) when the table lacks it
+    pub fn t(&self, key: &str) -> String {
+        match self.strings.get(key) {
+            Some(value) => value.clone(),
+            None => {
+                warn!("Missing {} string for `{}`", self.language.label(), key);
+                key.to_string()
+            }
+        }
+    }
+}
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        let settings = LocaleSettings::load();
+        app.insert_resource(Locale::new(settings.language))
+            .insert_resource(settings)
+            .add_systems(
+                Update,
+                (reload_locale, save_on_change::<LocaleSettings>).run_if(resource_changed::<LocaleSettings>),
+            );
+    }
+}
+
+fn reload_locale(settings: Res<LocaleSettings>, mut locale: ResMut<Locale>) {
+    if locale.language != settings.language {
+        *locale = Locale::new(settings.language);
+    }
+}
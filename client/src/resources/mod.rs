@@ -1,2 +1,10 @@
 pub mod assets;
 pub mod audio;
+pub mod difficulty;
+pub mod graphics;
+pub mod hud;
+pub mod input;
+pub mod locale;
+pub mod pause;
+pub mod platform;
+pub mod shutdown;
@@ -0,0 +1,105 @@
+//! Pausing gameplay. Gameplay systems read the default `Time`, which in
+//! `Update` is Bevy's virtual clock, so pausing that clock stops timers,
+//! movement, AI and physics together and nothing jumps ahead on resume.
+//! Anything that must keep counting while paused reads `Time<Real>` instead:
+//! toasts, the loading screen's stage gating and timeouts, input debouncing
+//! and the blockchain request timeouts.
+
+use bevy::prelude::*;
+
+use crate::screens::Screen;
+
+/// Whether gameplay is paused; set this rather than touching the clock directly
+#[derive(Resource, Default, Debug)]
+pub struct GamePause {
+    pub paused: bool,
+}
+
+pub struct GamePausePlugin;
+
+impl Plugin for GamePausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamePause>()
+            .add_systems(
+                PreUpdate,
+                apply_game_pause.run_if(resource_changed::<GamePause>),
+            )
+            // Menus run on the same clock, so it must never stay paused outside gameplay
+            .add_systems(OnExit(Screen::GamePlay), resume_game);
+    }
+}
+
+fn apply_game_pause(pause: Res<GamePause>, mut time: ResMut<Time<Virtual>>) {
+    if pause.paused {
+        time.pause();
+    } else {
+        time.unpause();
+    }
+}
+
+fn resume_game(mut pause: ResMut<GamePause>) {
+    pause.paused = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::headless_app;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn pause_app() -> App {
+        let mut app = headless_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .init_resource::<GamePause>()
+            .add_systems(
+                PreUpdate,
+                apply_game_pause.run_if(resource_changed::<GamePause>),
+            );
+        app
+    }
+
+    fn game_secs(app: &App) -> f32 {
+        app.world().resource::<Time<Virtual>>().elapsed_secs()
+    }
+
+    fn real_secs(app: &App) -> f32 {
+        app.world().resource::<Time<Real>>().elapsed_secs()
+    }
+
+    #[test]
+    fn game_clock_stands_still_while_paused() {
+        let mut app = pause_app();
+        app.update();
+        app.update();
+        assert!(game_secs(&app) > 0.0);
+
+        app.world_mut().resource_mut::<GamePause>().paused = true;
+        // The clock ticks at the start of the frame, so the pause only holds from the next one
+        app.update();
+        let (game_at_pause, real_at_pause) = (game_secs(&app), real_secs(&app));
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(game_secs(&app), game_at_pause);
+        assert!(real_secs(&app) > real_at_pause);
+    }
+
+    #[test]
+    fn resuming_picks_up_without_a_jump() {
+        let mut app = pause_app();
+        app.update();
+        app.world_mut().resource_mut::<GamePause>().paused = true;
+        app.update();
+        for _ in 0..10 {
+            app.update();
+        }
+        let game_at_resume = game_secs(&app);
+
+        app.world_mut().resource_mut::<GamePause>().paused = false;
+        app.update();
+        app.update();
+        // One frame's worth, not the second spent paused
+        assert!((game_secs(&app) - game_at_resume - 0.1).abs() < 1e-4);
+    }
+}
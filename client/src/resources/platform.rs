@@ -0,0 +1,150 @@
+//! What the current platform can do, decided in one place so systems check
+//! capabilities instead of sprinkling `cfg(target_arch = "wasm32")` around.
+//!
+//! Disabled on wasm:
+//! - filesystem access, so `nav.json`, `waves.json` and ghost replays are not read or written
+//! - navigation data, so coins use the scattered fallback placement
+//! - navigation recording (F9)
+//!
+//! Save games, settings, achievements and statistics go through `read_file` and
+//! `write_file` on every platform: files natively, `localStorage` keyed by path in
+//! the browser. `has_storage` is false only when the browser refuses storage.
+//!
+//! Settings resources implement [`PersistedSettings`] and register
+//! [`save_on_change`], so each one loads and saves the same way.
+//!
+//! Touch support can't be known up front in a browser, so `has_touch` starts
+//! false and flips on at the first touch event.
+
+use bevy::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::constants::navigation::NAV_DATA_PATH;
+
+/// True for the browser build; prefer `PlatformCapabilities` where a resource is at hand
+pub const IS_WEB: bool = cfg!(target_arch = "wasm32");
+
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct PlatformCapabilities {
+    pub has_filesystem: bool,
+    /// Whether save games and settings persist between sessions
+    pub has_storage: bool,
+    /// Whether `nav.json` was present at startup
+    pub has_nav_data: bool,
+    pub can_record_nav: bool,
+    /// Whether a touch has been seen; gates the on-screen touch controls
+    pub has_touch: bool,
+}
+
+impl PlatformCapabilities {
+    pub fn detect() -> Self {
+        let has_filesystem = !IS_WEB;
+        let has_nav_data = has_filesystem && file_exists(NAV_DATA_PATH);
+
+        let capabilities = Self {
+            has_filesystem,
+            has_storage: storage_available(),
+            has_nav_data,
+            can_record_nav: has_filesystem,
+            has_touch: false,
+        };
+        info!("Platform capabilities: {:?}", capabilities);
+        capabilities
+    }
+}
+
+/// A settings resource stored as JSON at `PATH`. Implementors use `#[serde(default)]`
+/// so missing fields fall back to defaults and saves from older versions still load.
+pub trait PersistedSettings: Resource + Default + Serialize + DeserializeOwned {
+    /// File path, or the `localStorage` key on the web build
+    const PATH: &'static str;
+
+    /// Pulls loaded values back into range; hand-edited files could hold anything
+    fn sanitize(&mut self) {}
+
+    /// Saved settings, or the defaults when there's no save or no storage
+    fn load() -> Self {
+        let mut settings: Self = read_file(Self::PATH)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        settings.sanitize();
+        settings
+    }
+
+    fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(e) = write_file(Self::PATH, &contents) {
+                    error!("Failed to write {}: {}", Self::PATH, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize {}: {}", Self::PATH, e),
+        }
+    }
+}
+
+/// Writes `T` back to storage; register with `run_if(resource_changed::<T>)`. The
+/// value just loaded isn't written again, and without storage settings last for the session.
+pub fn save_on_change<T: PersistedSettings>(settings: Res<T>, capabilities: Res<PlatformCapabilities>) {
+    if !settings.is_added() && capabilities.has_storage {
+        settings.save();
+    }
+}
+
+// The only place that touches `std::fs` or `localStorage`, so each build carries one of them
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read_file(path: &str) -> std::io::Result<String> {
+    std::fs::read_to_string(path)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write_file(path: &str, contents: &str) -> std::io::Result<()> {
+    std::fs::write(path, contents)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn file_exists(path: &str) -> bool {
+    std::path::Path::new(path).exists()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn storage_available() -> bool {
+    true
+}
+
+/// None when storage is disabled, e.g. by privacy settings or a sandboxed iframe
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn storage_available() -> bool {
+    local_storage().is_some()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn read_file(path: &str) -> std::io::Result<String> {
+    let storage = local_storage().ok_or(std::io::ErrorKind::Unsupported)?;
+    storage
+        .get_item(path)
+        .map_err(|e| std::io::Error::other(format!("{:?}", e)))?
+        .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn write_file(path: &str, contents: &str) -> std::io::Result<()> {
+    let storage = local_storage().ok_or(std::io::ErrorKind::Unsupported)?;
+    // Fails once the origin's storage quota is used up
+    storage
+        .set_item(path, contents)
+        .map_err(|e| std::io::Error::other(format!("{:?}", e)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn file_exists(_path: &str) -> bool {
+    false
+}
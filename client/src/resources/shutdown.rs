@@ -0,0 +1,194 @@
+//! Quitting the game. Every way out (the main menu's Quit, closing the window)
+//! sends a [`QuitRequested`], and the game only exits through here: once
+//! confirmed, [`ShutdownSave`] is triggered so each system with something on
+//! disk writes it synchronously, and only then is `AppExit` sent. Bevy
+//! processes that exit after the frame, so the saves always land first.
+
+use bevy::prelude::*;
+use bevy::window::WindowCloseRequested;
+
+use crate::assets::FontAssets;
+use crate::constants::save::QuitConfig;
+use crate::resources::platform::IS_WEB;
+use crate::ui::modal::{
+    ConfirmAction, ConfirmDialogAccepted, ConfirmDialogState, spawn_confirm_dialog,
+};
+
+/// Asks to quit; `confirm` shows a confirm dialog first where the platform wants one
+#[derive(Event, Debug)]
+pub struct QuitRequested {
+    pub confirm: bool,
+}
+
+/// Triggered once right before exiting; observe it to flush anything unsaved
+#[derive(Event, Debug)]
+pub struct ShutdownSave;
+
+#[derive(Resource, Default, Debug, PartialEq, Eq)]
+pub enum ShutdownState {
+    #[default]
+    Running,
+    Exiting,
+}
+
+impl ShutdownState {
+    /// Moves to `Exiting`; true only the first time, so the final save runs once
+    /// however many quit requests arrive
+    pub fn begin(&mut self) -> bool {
+        if *self == ShutdownState::Exiting {
+            return false;
+        }
+        *self = ShutdownState::Exiting;
+        true
+    }
+}
+
+/// Whether a quit request should ask first; the browser build has nothing to confirm
+/// since closing the tab doesn't go through here
+pub fn should_confirm(requested: bool) -> bool {
+    requested && QuitConfig::CONFIRM_ON_NATIVE && !IS_WEB
+}
+
+pub struct ShutdownPlugin;
+
+impl Plugin for ShutdownPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShutdownState>()
+            .add_event::<QuitRequested>()
+            .add_systems(
+                Update,
+                (request_quit_on_window_close, handle_quit_requests).chain(),
+            )
+            .add_observer(quit_confirmed);
+    }
+}
+
+/// Window closing is turned off in `main`, so the close button comes through here
+/// and still saves; it doesn't ask, since the player already chose to close
+fn request_quit_on_window_close(
+    mut close_events: EventReader<WindowCloseRequested>,
+    mut quit_events: EventWriter<QuitRequested>,
+) {
+    if close_events.read().count() > 0 {
+        quit_events.write(QuitRequested { confirm: false });
+    }
+}
+
+fn handle_quit_requests(
+    mut commands: Commands,
+    mut quit_events: EventReader<QuitRequested>,
+    mut state: ResMut<ShutdownState>,
+    mut confirm_state: ResMut<ConfirmDialogState>,
+    font_assets: Option<Res<FontAssets>>,
+    frame: Res<bevy::diagnostic::FrameCount>,
+) {
+    let Some(confirm) = quit_events
+        .read()
+        .map(|event| event.confirm)
+        .reduce(|a, b| a && b)
+    else {
+        return;
+    };
+    match font_assets {
+        Some(font_assets) if should_confirm(confirm) => spawn_confirm_dialog(
+            &mut commands,
+            &font_assets,
+            &mut confirm_state,
+            frame.0,
+            "Quit Elysium Descent?",
+            ConfirmAction::QuitGame,
+        ),
+        _ => shut_down(&mut commands, &mut state),
+    }
+}
+
+fn quit_confirmed(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut commands: Commands,
+    mut state: ResMut<ShutdownState>,
+) {
+    if trigger.0 == ConfirmAction::QuitGame {
+        shut_down(&mut commands, &mut state);
+    }
+}
+
+/// Runs the final save, then exits. The trigger's observers run when these commands
+/// are applied, before the frame ends and `AppExit` takes effect.
+fn shut_down(commands: &mut Commands, state: &mut ShutdownState) {
+    if !state.begin() {
+        return;
+    }
+    info!("Shutting down");
+    commands.trigger(ShutdownSave);
+    commands.send_event(AppExit::Success);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{event_count, headless_app};
+
+    #[derive(Resource, Default)]
+    struct SaveCount(usize);
+
+    fn shutdown_app() -> App {
+        let mut app = headless_app();
+        app.init_resource::<ConfirmDialogState>()
+            .init_resource::<SaveCount>()
+            .add_event::<WindowCloseRequested>()
+            .add_plugins(ShutdownPlugin)
+            .add_observer(|_: Trigger<ShutdownSave>, mut count: ResMut<SaveCount>| count.0 += 1);
+        app
+    }
+
+    fn save_count(app: &App) -> usize {
+        app.world().resource::<SaveCount>().0
+    }
+
+    #[test]
+    fn quitting_saves_exactly_once() {
+        let mut app = shutdown_app();
+        app.world_mut().send_event(QuitRequested { confirm: false });
+        app.world_mut().send_event(QuitRequested { confirm: false });
+        app.update();
+        assert_eq!(save_count(&app), 1);
+        assert_eq!(event_count::<AppExit>(&app), 1);
+
+        // Later requests while already exiting don't save again
+        app.world_mut().send_event(QuitRequested { confirm: false });
+        app.update();
+        assert_eq!(save_count(&app), 1);
+        assert_eq!(*app.world().resource::<ShutdownState>(), ShutdownState::Exiting);
+    }
+
+    #[test]
+    fn confirmed_quit_saves_once_the_dialog_is_accepted() {
+        let mut app = shutdown_app();
+        app.init_resource::<FontAssets>();
+        app.world_mut().send_event(QuitRequested { confirm: true });
+        app.update();
+        assert_eq!(save_count(&app), 0);
+        assert!(app.world().resource::<ConfirmDialogState>().is_open());
+
+        app.world_mut()
+            .trigger(ConfirmDialogAccepted(ConfirmAction::QuitGame));
+        app.world_mut().flush();
+        assert_eq!(save_count(&app), 1);
+    }
+
+    #[test]
+    fn other_confirmed_actions_do_not_quit() {
+        let mut app = shutdown_app();
+        app.world_mut()
+            .trigger(ConfirmDialogAccepted(ConfirmAction::RestartLevel));
+        app.world_mut().flush();
+        assert_eq!(save_count(&app), 0);
+        assert_eq!(*app.world().resource::<ShutdownState>(), ShutdownState::Running);
+    }
+
+    #[test]
+    fn closing_the_window_quits_without_asking() {
+        assert!(!should_confirm(false));
+        assert_eq!(should_confirm(true), QuitConfig::CONFIRM_ON_NATIVE && !IS_WEB);
+    }
+}
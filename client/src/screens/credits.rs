@@ -0,0 +1,262 @@
+//! Credits, scrolled up the screen over the menu background. Mouse wheel scrolling
+//! comes from the modal's `update_scroll_position`, which moves every scroll area;
+//! this screen only pauses its own auto-scroll while the player does that.
+
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+
+use super::{Screen, despawn_scene};
+use crate::assets::{FontAssets, UiAssets};
+use crate::constants::menu::CreditsConfig;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// One block of the credits: a heading and the lines under it
+pub struct CreditsSection {
+    pub heading: &'static str,
+    pub entries: &'static [&'static str],
+}
+
+/// Everything the credits list, top to bottom; add sections here
+pub const CREDITS: &[CreditsSection] = &[
+    CreditsSection {
+        heading: "DEVELOPMENT",
+        entries: &["The Elysium Descent team"],
+    },
+    CreditsSection {
+        heading: "BUILT WITH",
+        entries: &[
+            "Bevy",
+            "Avian Physics",
+            "Bevy Lunex",
+            "Bevy Kira Audio",
+            "Bevy Enhanced Input",
+            "Dojo",
+            "Starknet",
+        ],
+    },
+    CreditsSection {
+        heading: "FONTS",
+        entries: &["Goudy Trajan", "Rajdhani"],
+    },
+    CreditsSection {
+        heading: "SPECIAL THANKS",
+        entries: &[
+            "Everyone who played, tested and reported bugs",
+            "And you, for playing",
+        ],
+    },
+];
+
+/// Auto-scroll speed in UI pixels per second; Space fast-forwards on top of it
+#[derive(Resource, Debug, Clone)]
+pub struct CreditsSettings {
+    pub scroll_speed: f32,
+}
+
+impl Default for CreditsSettings {
+    fn default() -> Self {
+        Self {
+            scroll_speed: CreditsConfig::SCROLL_SPEED,
+        }
+    }
+}
+
+#[derive(Component)]
+struct CreditsScene;
+
+/// The scrolling area holding the credits
+#[derive(Component, Default)]
+struct CreditsScroll {
+    /// Offset the last auto-scroll step asked for; layout clamps it at the end
+    requested_offset: f32,
+    manual_pause_secs: f32,
+    end_hold_secs: f32,
+}
+
+/// Whether layout clamped the offset back, i.e. the last line is on screen
+pub fn reached_end(requested_offset: f32, actual_offset: f32) -> bool {
+    actual_offset + 0.5 < requested_offset
+}
+
+// ===== PLUGIN =====
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<CreditsSettings>()
+        .add_systems(OnEnter(Screen::Credits), spawn_credits)
+        .add_systems(
+            Update,
+            auto_scroll_credits.run_if(in_state(Screen::Credits)),
+        )
+        .add_systems(OnExit(Screen::Credits), despawn_scene::<CreditsScene>);
+}
+
+// ===== SYSTEMS =====
+
+fn spawn_credits(mut commands: Commands, font_assets: Res<FontAssets>, ui_assets: Res<UiAssets>) {
+    commands
+        .spawn((
+            CreditsScene,
+            Name::new("Credits"),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                ImageNode::new(ui_assets.background.clone()),
+                BackgroundColor(Color::WHITE.with_alpha(0.3)),
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    CreditsScroll::default(),
+                ))
+                .with_children(|scroll| {
+                    // A screen of space above and below, so the credits roll in from the
+                    // bottom and the last line can be read before the end
+                    scroll.spawn(Node {
+                        min_height: Val::Vh(100.0),
+                        ..default()
+                    });
+                    scroll.spawn((
+                        Node {
+                            width: Val::Px(620.0),
+                            height: Val::Px(190.0),
+                            margin: UiRect::bottom(Val::Px(80.0)),
+                            flex_shrink: 0.0,
+                            ..default()
+                        },
+                        ImageNode::new(ui_assets.title.clone()),
+                    ));
+                    for section in CREDITS {
+                        spawn_section(scroll, &font_assets, section);
+                    }
+                    scroll.spawn(Node {
+                        min_height: Val::Vh(50.0),
+                        ..default()
+                    });
+                });
+
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(24.0),
+                    ..default()
+                },
+                Text::new("ESC to return  -  hold SPACE to speed up"),
+                TextFont {
+                    font: font_assets.rajdhani_medium.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE.with_alpha(0.6)),
+                Pickable::IGNORE,
+            ));
+        });
+}
+
+fn spawn_section(
+    parent: &mut ChildSpawnerCommands,
+    font_assets: &FontAssets,
+    section: &CreditsSection,
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            flex_shrink: 0.0,
+            margin: UiRect::bottom(Val::Px(64.0)),
+            row_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|section_node| {
+            section_node.spawn((
+                Text::new(section.heading),
+                TextFont {
+                    font: font_assets.rajdhani_bold.clone(),
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::ELYSIUM_GOLD),
+                Node {
+                    margin: UiRect::bottom(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+            for entry in section.entries {
+                section_node.spawn((
+                    Text::new(*entry),
+                    TextFont {
+                        font: font_assets.rajdhani_medium.clone(),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            }
+        });
+}
+
+/// Rolls the credits up, pausing after manual scrolling, and heads back to the menu
+/// a little after the end is reached
+fn auto_scroll_credits(
+    time: Res<Time<Real>>,
+    settings: Res<CreditsSettings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut scroll_query: Query<(&mut CreditsScroll, &mut ScrollPosition)>,
+    mut next_state: ResMut<NextState<Screen>>,
+) {
+    let Ok((mut credits, mut scroll)) = scroll_query.single_mut() else {
+        return;
+    };
+    let delta = time.delta_secs();
+
+    if mouse_wheel_events.read().count() > 0 {
+        credits.manual_pause_secs = CreditsConfig::MANUAL_SCROLL_PAUSE_SECS;
+        credits.end_hold_secs = 0.0;
+    }
+    if credits.manual_pause_secs > 0.0 {
+        credits.manual_pause_secs -= delta;
+        credits.requested_offset = scroll.offset_y;
+        return;
+    }
+
+    if reached_end(credits.requested_offset, scroll.offset_y) {
+        credits.end_hold_secs += delta;
+        if credits.end_hold_secs >= CreditsConfig::END_HOLD_SECS {
+            next_state.set(Screen::MainMenu);
+        }
+        return;
+    }
+
+    let speed = if keyboard.pressed(KeyCode::Space) {
+        settings.scroll_speed * CreditsConfig::FAST_FORWARD_MULTIPLIER
+    } else {
+        settings.scroll_speed
+    };
+    scroll.offset_y += speed * delta;
+    credits.requested_offset = scroll.offset_y;
+}
@@ -1,4 +1,4 @@
-use super::{Screen, despawn_scene};
+use super::{Screen, despawn_scene, reset_scene_state};
 use crate::assets::ModelAssets;
 use crate::systems::character_controller::CharacterControllerBundle;
 use crate::systems::enemy_ai::EnemyBundle;
@@ -13,16 +13,24 @@ use bevy_gltf_animation::prelude::GltfSceneRoot;
 // ===== PLUGIN SETUP =====
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::FightScene), (spawn_fight_scene, despawn_collectibles))
-        .add_systems(OnExit(Screen::FightScene), despawn_scene::<FightScene>)
-        .add_systems(
-            Update,
-            handle_fight_input.run_if(in_state(Screen::FightScene)),
-        )
-        .add_systems(
-            Update,
-            camera_follow_fight_player.run_if(in_state(Screen::FightScene)),
-        );
+    app.add_systems(
+        OnEnter(Screen::FightScene),
+        (
+            spawn_fight_scene,
+            despawn_scene::<crate::systems::collectibles::Collectible>,
+            reset_scene_state::<crate::systems::collectibles::CoinStreamingManager>,
+        ),
+    )
+    .add_systems(OnExit(Screen::FightScene), despawn_scene::<FightScene>)
+    .add_systems(
+        Update,
+        handle_fight_input.run_if(in_state(Screen::FightScene)),
+    )
+    .add_systems(
+        Update,
+        camera_follow_fight_player.run_if(in_state(Screen::FightScene)),
+    )
+    .add_plugins(crate::systems::combat::CombatPlugin);
 }
 
 // ===== SYSTEMS =====
@@ -32,6 +40,7 @@ fn spawn_fight_scene(
     assets: Res<ModelAssets>,
     ui_assets: Res<crate::assets::UiAssets>,
     font_assets: Res<crate::assets::FontAssets>,
+    difficulty: Res<crate::resources::difficulty::ActiveDifficulty>,
 ) {
     // Set up ambient light (match gameplay)
     commands.insert_resource(AmbientLight {
@@ -68,6 +77,7 @@ fn spawn_fight_scene(
             std::f32::consts::FRAC_PI_4,
             0.0,
         )),
+        crate::resources::graphics::ShadowCastingLight,
         FightScene,
     ));
 
@@ -148,7 +158,7 @@ fn spawn_fight_scene(
             scale: Vec3::splat(4.0),
             ..default()
         },
-        EnemyBundle::default(),
+        EnemyBundle::default().with_difficulty(&difficulty.modifiers()),
         Friction::new(0.5),
         Restitution::new(0.0),
         GravityScale(1.0),
@@ -192,6 +202,9 @@ fn spawn_fight_scene(
                 font_assets.rajdhani_bold.clone(),
                 crate::ui::widgets::HudPosition::Left,
             ));
+            parent.spawn(crate::ui::widgets::stamina_bar_widget(
+                font_assets.rajdhani_bold.clone(),
+            ));
             parent.spawn(crate::ui::widgets::player_hud_widget(
                 ui_assets.enemy_avatar.clone(),
                 "Enemy",
@@ -261,11 +274,6 @@ fn camera_follow_fight_player(
     }
 }
 
-fn despawn_collectibles(mut commands: Commands, query: Query<Entity, With<crate::systems::collectibles::Collectible>>) {
-    for entity in &query {
-        commands.entity(entity).despawn();
-    }
-}
 
 // ===== SCENE MARKER =====
 
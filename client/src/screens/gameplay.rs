@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use bevy_gltf_animation::prelude::*;
 use rand::prelude::*;
 
-use super::{Screen, despawn_scene};
+use super::{Screen, despawn_scene, reset_scene_state};
 use super::pregame_loading::EnvironmentPreload;
 use crate::assets::{FontAssets, ModelAssets, UiAssets};
 use crate::constants::collectibles::{MAX_COINS, MIN_DISTANCE_BETWEEN_COINS};
@@ -13,12 +13,13 @@ use crate::systems::character_controller::{
 };
 use crate::systems::book_interaction::BookInteractionPlugin;
 use crate::systems::collectibles::{CollectiblesPlugin, NavigationBasedSpawner, CollectibleSpawner, CoinStreamingManager};
+use crate::systems::objective_timer::ObjectiveTimerPlugin;
 use crate::systems::objectives::ObjectivesPlugin;
 use crate::ui::dialog::DialogPlugin;
 use crate::ui::inventory::spawn_inventory_ui;
 use crate::ui::styles::ElysiumDescentColorPalette;
-use crate::ui::widgets::{HudPosition, player_hud_widget};
-use crate::ui::modal::despawn_modal;
+use crate::ui::widgets::{HudPosition, player_hud_widget, stamina_bar_widget};
+use crate::ui::modal::{ConfirmDialog, ConfirmDialogState, ModalState, despawn_modal};
 use bevy_enhanced_input::prelude::*;
 
 // ===== PLUGIN SETUP =====
@@ -29,7 +30,8 @@ pub(super) fn plugin(app: &mut App) {
         (
             reveal_preloaded_environment,
             debug_streaming_manager_state,
-            PlayingScene::spawn_player_and_camera,
+            PlayingScene::spawn_player_and_camera
+                .after(crate::systems::level_data::resolve_player_spawn),
             set_gameplay_clear_color,
         ),
     )
@@ -44,16 +46,51 @@ pub(super) fn plugin(app: &mut App) {
     )
     .add_systems(
         OnExit(Screen::GamePlay),
-        (despawn_scene::<PlayingScene>, despawn_gameplay_hud, cleanup_preloaded_environment, despawn_modal, despawn_collectibles),
+        (
+            despawn_scene::<PlayingScene>,
+            despawn_gameplay_hud,
+            cleanup_preloaded_environment,
+            despawn_modal,
+            reset_scene_state::<ModalState>,
+            despawn_scene::<ConfirmDialog>,
+            reset_scene_state::<ConfirmDialogState>,
+            despawn_scene::<crate::systems::collectibles::Collectible>,
+            reset_scene_state::<CoinStreamingManager>,
+            crate::ui::inventory::cancel_item_drag,
+        ),
     )
+    .add_observer(restart_level)
     .add_plugins(PhysicsPlugins::default())
     .add_plugins(CharacterControllerPlugin)
     .add_plugins(GltfAnimationPlugin)
     .add_plugins(CollectiblesPlugin)
+    .add_plugins(crate::systems::pickup_effects::PickupEffectsPlugin)
     .add_plugins(ObjectivesPlugin)
+    .add_plugins(ObjectiveTimerPlugin)
     .add_plugins(DialogPlugin)
     .add_plugins(BookInteractionPlugin)
-    .add_plugins(crate::systems::boundary::BoundaryPlugin);
+    .add_plugins(crate::systems::boundary::BoundaryPlugin)
+    .add_plugins(crate::systems::navigation_recorder::NavigationRecorderPlugin)
+    .add_plugins(crate::systems::navigation_debug::NavigationDebugPlugin)
+    .add_plugins(crate::systems::enemy_ai::EnemyAIPlugin)
+    .add_plugins(crate::systems::waves::WavePlugin)
+    .add_plugins(crate::systems::lighting::LightingCyclePlugin)
+    .add_plugins(crate::systems::ground_shadow::GroundShadowPlugin)
+    .add_plugins(crate::systems::ghost::GhostPlugin)
+    .add_plugins(crate::systems::health::HealthPlugin)
+    .add_plugins(crate::systems::level_exit::LevelExitPlugin)
+    .add_plugins(crate::systems::level_data::LevelDataPlugin)
+    .add_plugins(crate::systems::checkpoints::CheckpointsPlugin)
+    .add_plugins(crate::systems::fall_recovery::FallRecoveryPlugin)
+    .add_plugins(crate::systems::destructibles::DestructiblesPlugin)
+    .add_plugins(crate::ui::game_over::GameOverPlugin)
+    .add_plugins(crate::ui::touch_controls::TouchControlsPlugin)
+    .add_plugins(crate::ui::compass::CompassPlugin)
+    .add_plugins(crate::ui::coin_counter::CoinCounterPlugin)
+    .add_plugins(crate::ui::movement_tuning::MovementTuningPanelPlugin)
+    .add_plugins(crate::systems::save::SavePlugin)
+    .add_plugins(crate::systems::achievements::AchievementsPlugin)
+    .add_plugins(crate::systems::statistics::StatisticsPlugin);
 }
 
 // ===== SYSTEMS =====
@@ -114,7 +151,7 @@ pub struct PlayingScene;
 #[derive(Component)]
 struct EnvironmentMarker;
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 struct GameplayHud;
 
 fn spawn_player_hud(
@@ -131,15 +168,20 @@ fn spawn_player_hud(
     let font = font_assets.rajdhani_bold.clone();
 
     commands.spawn((
-        player_hud_widget(avatar, name, level, health, xp, font, HudPosition::Left),
+        player_hud_widget(avatar, name, level, health, xp, font.clone(), HudPosition::Left),
         GameplayHud,
     ));
+    commands.spawn((stamina_bar_widget(font), GameplayHud));
+    crate::ui::touch_controls::spawn_touch_controls(commands, font_assets, GameplayHud);
+    crate::ui::compass::spawn_compass(commands, font_assets, GameplayHud);
+    crate::ui::coin_counter::spawn_coin_counter(commands, font_assets, ui_assets, GameplayHud);
 }
 
 fn spawn_objectives_ui(
     commands: &mut Commands,
     font_assets: &Res<FontAssets>,
     _ui_assets: &Res<UiAssets>,
+    locale: &crate::resources::locale::Locale,
 ) {
 
     
@@ -177,7 +219,7 @@ fn spawn_objectives_ui(
                     ..default()
                 },
                 children![(
-                    Text::new("OBJECTIVES"),
+                    Text::new(locale.t("objectives.title")),
                     TextFont {
                         font: font.clone(),
                         font_size: 27.0,
@@ -219,10 +261,81 @@ fn cleanup_preloaded_environment(
     }
 }
 
-fn despawn_collectibles(mut commands: Commands, query: Query<Entity, With<crate::systems::collectibles::Collectible>>) {
-    for entity in &query {
+/// Re-applies the current level's objectives and coins to every level-scoped resource;
+/// shared by restart so it matches a fresh entry without reloading the scene
+fn apply_level(
+    commands: &mut Commands,
+    objective_manager: &mut crate::systems::objectives::ObjectiveManager,
+    boundary: &crate::systems::boundary::BoundaryConstraint,
+    streaming_manager: &mut CoinStreamingManager,
+) {
+    crate::systems::objectives::apply_level_objectives(objective_manager, boundary);
+    streaming_manager.reset_progress(commands);
+}
+
+/// Puts the level back to its starting state in place once a restart is confirmed
+fn restart_level(
+    trigger: Trigger<crate::ui::modal::ConfirmDialogAccepted>,
+    mut commands: Commands,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut LinearVelocity,
+            &mut crate::game::Health,
+            &mut crate::systems::character_controller::Stamina,
+            &mut crate::systems::character_controller::AnimationState,
+        ),
+        With<CharacterController>,
+    >,
+    enemy_query: Query<Entity, With<crate::systems::enemy_ai::Enemy>>,
+    item_query: Query<Entity, With<crate::ui::inventory::InventoryItem>>,
+    mut objective_manager: ResMut<crate::systems::objectives::ObjectiveManager>,
+    boundary: Res<crate::systems::boundary::BoundaryConstraint>,
+    mut streaming_manager: ResMut<CoinStreamingManager>,
+    mut progress: ResMut<crate::systems::collectibles::CollectibleProgressTracker>,
+    mut player_stats: ResMut<crate::systems::collectibles::PlayerStats>,
+    mut modal_state: ResMut<crate::ui::modal::ModalState>,
+    mut modal_background: Query<&mut Visibility, With<crate::ui::modal::ModalBackground>>,
+    player_spawn: Res<crate::systems::level_data::PlayerSpawn>,
+    checkpoints: Res<crate::systems::checkpoints::CheckpointState>,
+) {
+    if trigger.0 != crate::ui::modal::ConfirmAction::RestartLevel {
+        return;
+    }
+    info!("Restarting level");
+    let respawn_position = crate::systems::checkpoints::respawn_position(&checkpoints, &player_spawn);
+
+    for (mut transform, mut velocity, mut health, mut stamina, mut animation_state) in &mut player_query {
+        transform.translation = respawn_position;
+        transform.rotation = player_spawn.rotation;
+        velocity.0 = Vec3::ZERO;
+        health.current = health.max;
+        *stamina = default();
+        animation_state.forward_hold_time = 0.0;
+    }
+
+    for entity in enemy_query.iter().chain(item_query.iter()) {
         commands.entity(entity).despawn();
     }
+
+    apply_level(&mut commands, &mut objective_manager, &boundary, &mut streaming_manager);
+    progress.reset();
+    *player_stats = default();
+    // Pickups made before the last checkpoint count as kept, and their coins stay gone
+    if let Some(checkpoint) = &checkpoints.last {
+        *progress = checkpoint.progress.clone();
+        *player_stats = checkpoint.player_stats;
+        streaming_manager
+            .collected_positions
+            .extend(checkpoint.collected_coins.iter().copied());
+        objective_manager.objectives = checkpoint.objectives.clone();
+        commands.insert_resource(crate::ui::inventory::InventoryRestore(
+            checkpoint.inventory.clone(),
+        ));
+    }
+    commands.run_system_cached(crate::systems::waves::reset_waves);
+
+    crate::ui::modal::close_modal(&mut modal_state, &mut modal_background);
 }
 
 // ===== PLAYING SCENE IMPLEMENTATION =====
@@ -233,7 +346,9 @@ impl PlayingScene {
         assets: Res<ModelAssets>,
         font_assets: Res<FontAssets>,
         ui_assets: Res<UiAssets>,
+        locale: Res<crate::resources::locale::Locale>,
         windows: Query<&Window>,
+        player_spawn: Res<crate::systems::level_data::PlayerSpawn>,
     ) {
     
 
@@ -251,6 +366,8 @@ impl PlayingScene {
                 std::f32::consts::FRAC_PI_4,
                 0.0,
             )),
+            crate::systems::lighting::SunLight,
+            crate::resources::graphics::ShadowCastingLight,
             PlayingScene,
         ));
 
@@ -307,9 +424,9 @@ impl PlayingScene {
                 Name::new("Player"),
                 GltfSceneRoot::new(assets.player.clone()),
                 Transform {
-                    translation: Vec3::new(0.0, 2.0, 0.0),
+                    translation: player_spawn.position,
+                    rotation: player_spawn.rotation,
                     scale: Vec3::splat(4.0),
-                    ..default()
                 },
                 CharacterControllerBundle::new(),
                 Friction::new(0.5),
@@ -335,22 +452,11 @@ impl PlayingScene {
 
         spawn_inventory_ui::<PlayingScene>(&mut commands);
         spawn_player_hud(&mut commands, &font_assets, &ui_assets);
-        spawn_objectives_ui(&mut commands, &font_assets, &ui_assets);
-        crate::ui::modal::spawn_objectives_modal(&mut commands, &font_assets, &ui_assets);
+        spawn_objectives_ui(&mut commands, &font_assets, &ui_assets, &locale);
+        crate::ui::modal::spawn_objectives_modal(&mut commands, &font_assets, &ui_assets, &locale);
         
-        // Spawn the 'Press E to Open' dialog for Mystery Boxes
-        use crate::ui::dialog::{spawn_dialog, DialogConfig, DialogPosition};
-        spawn_dialog(
-            &mut commands,
-            &font_assets,
-            windows,
-            DialogConfig {
-                text: "Press E to Open".to_string(),
-                position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
-                ..Default::default()
-            },
-            PlayingScene,
-        );
+        // Spawn the prompt shown when the player is near an interactable
+        crate::ui::dialog::spawn_interaction_prompt(&mut commands, &font_assets, windows, PlayingScene);
 
     
     }
@@ -431,7 +537,10 @@ fn fallback_spawn_collectibles(
     collectible_query: Query<Entity, With<crate::systems::collectibles::Collectible>>,
     _spatial_query: SpatialQuery,
     mut fallback_spawned: Local<bool>,
-    boundary_constraint: Option<Res<crate::systems::boundary::BoundaryConstraint>>,
+    boundary_constraint: Res<crate::systems::boundary::BoundaryConstraint>,
+    world_seed: Res<crate::systems::collectibles::WorldSeed>,
+    collider_cache: Res<crate::systems::collectibles::CoinColliderCache>,
+    motion_config: Res<crate::systems::collectibles::CollectibleMotionConfig>,
 ) {
     // Only run once, and only if no collectible entities exist
     if *fallback_spawned || !collectible_query.is_empty() || collectible_spawner.coins_spawned > 0 {
@@ -442,7 +551,8 @@ fn fallback_spawn_collectibles(
         if nav_spawner.loaded {
     
             
-            let mut rng = rand::rng();
+            // Seeded so the fallback layout is as reproducible as the preloaded one
+            let mut rng = world_seed.rng();
             let mut spawned_positions = Vec::new();
             let mut coins_spawned = 0;
 
@@ -461,15 +571,7 @@ fn fallback_spawn_collectibles(
                 let potential_pos = *nav_pos + offset;
 
                 // Check boundary constraints
-                let mut within_bounds = true;
-                if let Some(constraint) = &boundary_constraint {
-                    within_bounds = potential_pos.x >= constraint.min_x 
-                        && potential_pos.x <= constraint.max_x 
-                        && potential_pos.z >= constraint.min_z 
-                        && potential_pos.z <= constraint.max_z;
-                }
-
-                if !within_bounds {
+                if !boundary_constraint.contains(potential_pos) {
                     continue;
                 }
 
@@ -491,6 +593,8 @@ fn fallback_spawn_collectibles(
                 spawn_fallback_collectible(
                     &mut commands,
                     &assets,
+                    &collider_cache,
+                    &motion_config,
                     coin_pos,
                 );
 
@@ -512,21 +616,11 @@ fn fallback_spawn_collectibles(
 fn spawn_fallback_collectible(
     commands: &mut Commands,
     assets: &Res<ModelAssets>,
+    collider_cache: &crate::systems::collectibles::CoinColliderCache,
+    motion_config: &crate::systems::collectibles::CollectibleMotionConfig,
     position: Vec3,
 ) {
-    use crate::systems::collectibles::{
-        Collectible, CollectibleType, FloatingItem, CollectibleRotation, 
-        Sensor
-    };
-
-    // Create a compound collider that better approximates a coin shape
-    // This is more performant than mesh-fitted colliders while still being more accurate than a single sphere
-    let coin_collider = Collider::compound(vec![
-        // Main body - slightly flattened sphere
-        (Vec3::ZERO, Quat::IDENTITY, Collider::sphere(0.4)),
-        // Edge rings for better coin-like collision
-        (Vec3::new(0.0, 0.0, 0.0), Quat::IDENTITY, Collider::cylinder(0.4, 0.1)),
-    ]);
+    use crate::systems::collectibles::{Collectible, CollectibleType, Sensor};
 
     commands.spawn((
         Name::new("Fallback Coin"),
@@ -536,21 +630,13 @@ fn spawn_fallback_collectible(
             scale: Vec3::splat(0.75),
             ..default()
         },
-        coin_collider,
+        collider_cache.0.clone(),
         RigidBody::Kinematic,
         Visibility::Visible,
         Collectible,
         CollectibleType::Coin,
-        FloatingItem {
-            base_height: position.y,
-            hover_amplitude: 0.2,
-            hover_speed: 2.0,
-        },
-        CollectibleRotation {
-            enabled: true,
-            clockwise: true,
-            speed: 1.0,
-        },
+        CollectibleType::Coin.default_reward(),
+        motion_config.components(CollectibleType::Coin, position.y),
         Sensor,
         CollisionEventsEnabled, // Enable collision events for this coin
         PlayingScene,
@@ -13,7 +13,7 @@ pub fn plugin(app: &mut App) {
         );
 }
 
-fn setup_loading_screen(mut commands: Commands) {
+fn setup_loading_screen(mut commands: Commands, locale: Res<crate::resources::locale::Locale>) {
     commands
         .spawn((
             LoadingScreen,
@@ -27,7 +27,7 @@ fn setup_loading_screen(mut commands: Commands) {
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("Loading..."),
+                Text::new(locale.t("loading.generic")),
                 TextFont {
                     font_size: 60.0,
                     ..default()
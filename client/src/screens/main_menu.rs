@@ -1,18 +1,48 @@
-use bevy::{picking::*, prelude::*, sprite::Anchor};
+use bevy::{picking::*, prelude::*, sprite::Anchor, window::PrimaryWindow};
 use bevy_lunex::*;
 
 use super::{Screen, despawn_scene};
 use crate::assets::{FontAssets, UiAssets};
+use crate::constants::menu::MenuBackgroundConfig;
+use crate::resources::shutdown::QuitRequested;
+use crate::systems::save::{ResumeSave, SaveData};
 use crate::ui::styles::ElysiumDescentColorPalette;
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::MainMenu), (reset_ui_camera, spawn).chain())
+        .add_systems(Update, drift_background.run_if(in_state(Screen::MainMenu)))
         .add_systems(OnExit(Screen::MainMenu), despawn_scene::<MainMenuScene>);
 }
 
 #[derive(Component)]
 struct MainMenuScene;
 
+/// The background image, moved by `drift_background`
+#[derive(Component)]
+struct MenuBackground;
+
+/// Background layout shifted by `offset` percent of the screen from center
+fn background_layout(offset: Vec2) -> UiLayout {
+    UiLayout::window()
+        .anchor(Anchor::Center)
+        .pos(Rl((50.0 + offset.x, 50.0 + offset.y)))
+        .size(Rl(100.0 + MenuBackgroundConfig::OVERSCAN_PERCENT))
+        .pack()
+}
+
+/// Idle drift at `elapsed` seconds: a slow loop whose axes run at different rates,
+/// so it never visibly repeats a straight line
+pub fn background_drift(elapsed: f32) -> Vec2 {
+    let phase = elapsed / MenuBackgroundConfig::DRIFT_PERIOD_SECS * std::f32::consts::TAU;
+    Vec2::new(phase.sin(), (phase * 0.77).cos()) * MenuBackgroundConfig::DRIFT_PERCENT
+}
+
+/// Parallax for a cursor at `cursor` (-1 to 1 from the screen center on each axis);
+/// the background moves away from it
+pub fn background_parallax(cursor: Vec2) -> Vec2 {
+    -cursor.clamp(Vec2::NEG_ONE, Vec2::ONE) * MenuBackgroundConfig::PARALLAX_PERCENT
+}
+
 /// Reset the UI camera to its proper state when entering main menu
 fn reset_ui_camera(
     mut ui_cameras: Query<&mut Transform, (With<Camera2d>, With<bevy_lunex::UiSourceCamera<0>>)>,
@@ -41,8 +71,16 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                     .size((1920.0, 1080.0))
                     .scaling(Scaling::Fill)
                     .pack(),
-                Sprite::from_image(ui_assets.background.clone()),
-            ));
+            ))
+            .with_children(|ui| {
+                // Drawn a little oversized so the drift never shows an edge
+                ui.spawn((
+                    Name::new("Background Image"),
+                    background_layout(Vec2::ZERO),
+                    Sprite::from_image(ui_assets.background.clone()),
+                    MenuBackground,
+                ));
+            });
 
             // Add the panel boundary
             ui.spawn((UiLayout::solid()
@@ -82,7 +120,16 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                         let gap = 3.0;
                         let size = 14.0;
                         let mut offset = 0.0;
-                        for button in ["Continue", "New Game", "Load Game", "Settings", "Credits", "Quit Game"] {
+                        // Continue only when there's a save it can actually load
+                        let has_save = SaveData::load().is_some();
+                        let buttons = [
+                            has_save.then_some("Continue"),
+                            Some("New Game"),
+                            Some("Settings"),
+                            Some("Credits"),
+                            Some("Quit Game"),
+                        ];
+                        for button in buttons.into_iter().flatten() {
 
                             // Spawn the button
                             let mut button_entity = ui.spawn((
@@ -164,8 +211,9 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                             // Assign a functionality to the buttons
                             match button {
                                 "New Game" => {
-                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<Screen>>| {
-                                        // Change the state to settings
+                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut commands: Commands, mut next: ResMut<NextState<Screen>>| {
+                                        // Start fresh even if a save exists
+                                        commands.remove_resource::<ResumeSave>();
                                         next.set(Screen::NewGame);
                                     });
                                 },
@@ -176,15 +224,27 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                                     });
                                 },
                                 "Continue" => {
+                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut commands: Commands, mut next: ResMut<NextState<Screen>>| {
+                                        // Read again in case the file changed since the menu opened
+                                        let Some(save) = SaveData::load() else {
+                                            warn!("Save is gone, starting a new game instead");
+                                            next.set(Screen::NewGame);
+                                            return;
+                                        };
+                                        // Loaded like a new game, then applied once the player spawns
+                                        commands.insert_resource(ResumeSave(save));
+                                        next.set(Screen::NewGame);
+                                    });
+                                },
+                                "Credits" => {
                                     button_entity.observe(|_: Trigger<Pointer<Click>>, mut next: ResMut<NextState<Screen>>| {
-                                        // Change the state to PreGameLoading
-                                        next.set(Screen::PreGameLoading);
+                                        next.set(Screen::Credits);
                                     });
                                 },
                                 "Quit Game" => {
-                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut exit: EventWriter<AppExit>| {
-                                        // Close the app
-                                        exit.write(AppExit::Success);
+                                    button_entity.observe(|_: Trigger<Pointer<Click>>, mut quit: EventWriter<QuitRequested>| {
+                                        // Asks first, then saves and closes the app
+                                        quit.write(QuitRequested { confirm: true });
                                     });
                                 },
                                 _ => {
@@ -200,3 +260,27 @@ fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<Font
                 });
         });
 }
+
+/// Moves the background along its idle drift, plus a little away from the cursor
+fn drift_background(
+    time: Res<Time<Real>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut background_query: Query<&mut UiLayout, With<MenuBackground>>,
+    mut parallax: Local<Vec2>,
+) {
+    let cursor = window_query
+        .single()
+        .ok()
+        .and_then(|window| {
+            let position = window.cursor_position()?;
+            Some(position / window.size() * 2.0 - Vec2::ONE)
+        })
+        .unwrap_or(Vec2::ZERO);
+    let smoothing = (MenuBackgroundConfig::PARALLAX_SMOOTHING * time.delta_secs()).min(1.0);
+    *parallax = parallax.lerp(background_parallax(cursor), smoothing);
+
+    let offset = background_drift(time.elapsed_secs()) + *parallax;
+    for mut layout in &mut background_query {
+        *layout = background_layout(offset);
+    }
+}
@@ -1,3 +1,4 @@
+mod credits;
 mod fight;
 pub mod gameplay;
 mod loading;
@@ -13,6 +14,18 @@ pub fn despawn_scene<S: Component>(mut commands: Commands, query: Query<Entity,
     }
 }
 
+/// A resource holding state about a scene's entities, which has to be put back whenever
+/// those entities are despawned. Scheduled next to `despawn_scene` via `reset_scene_state`.
+pub trait SceneState: Resource {
+    fn reset_on_teardown(&mut self);
+}
+
+pub fn reset_scene_state<R: SceneState>(resource: Option<ResMut<R>>) {
+    if let Some(mut resource) = resource {
+        resource.reset_on_teardown();
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default, States)]
 pub(crate) enum Screen {
     #[default]
@@ -22,6 +35,7 @@ pub(crate) enum Screen {
     GamePlay,
     NewGame,
     Settings,
+    Credits,
     FightScene,
 }
 
@@ -34,6 +48,7 @@ pub(super) fn plugin(app: &mut App) {
         .add_systems(Update, handle_new_game_transition)
         .add_plugins((
             main_menu::plugin,
+            credits::plugin,
             settings::plugin,
             loading::plugin,
             pregame_loading::plugin,  // Add new plugin
@@ -54,3 +69,67 @@ fn handle_new_game_transition(
         next_state.set(Screen::PreGameLoading);  // Go to PreGameLoading instead of GamePlay
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::collectibles::CoinStreamingManager;
+    use crate::test_support::headless_app;
+    use crate::ui::modal::{ConfirmAction, ConfirmDialogState, ModalState};
+
+    #[derive(Component)]
+    struct TestScene;
+
+    #[test]
+    fn teardown_despawns_the_scene_and_resets_its_state() {
+        let mut app = headless_app();
+        app.init_resource::<CoinStreamingManager>()
+            .init_resource::<ModalState>()
+            .init_resource::<ConfirmDialogState>()
+            .add_systems(
+                Update,
+                (
+                    despawn_scene::<TestScene>,
+                    reset_scene_state::<CoinStreamingManager>,
+                    reset_scene_state::<ModalState>,
+                    reset_scene_state::<ConfirmDialogState>,
+                ),
+            );
+        let coin = app.world_mut().spawn(TestScene).id();
+        let pooled = app.world_mut().spawn(TestScene).id();
+        {
+            let mut manager = app.world_mut().resource_mut::<CoinStreamingManager>();
+            manager.add_position(Vec3::ONE);
+            manager.spawned_coins.insert(0, coin);
+            manager.pooled_coins.push(pooled);
+        }
+        {
+            let mut modal = app.world_mut().resource_mut::<ModalState>();
+            modal.visible = true;
+            modal.active_tab = "STATS".to_string();
+        }
+        app.world_mut().resource_mut::<ConfirmDialogState>().pending = Some(ConfirmAction::RestartLevel);
+
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<(), With<TestScene>>().iter(world).count(), 0);
+        let manager = world.resource::<CoinStreamingManager>();
+        assert!(manager.spawned_coins.is_empty());
+        assert!(manager.pooled_coins.is_empty());
+        // Coin positions outlive the scene, so streaming resumes where it left off
+        assert_eq!(manager.positions, vec![Vec3::ONE]);
+        let modal = world.resource::<ModalState>();
+        assert!(!modal.visible);
+        assert_eq!(modal.active_tab, "QUESTS");
+        assert!(world.resource::<ConfirmDialogState>().pending.is_none());
+    }
+
+    #[test]
+    fn resetting_a_missing_resource_is_a_no_op() {
+        let mut app = headless_app();
+        app.add_systems(Update, reset_scene_state::<ModalState>);
+        app.update();
+        assert!(!app.world().contains_resource::<ModalState>());
+    }
+}
@@ -1,12 +1,23 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on, futures_lite::future};
 use rand::Rng;
-use std::fs;
 
 use super::Screen;
-use crate::assets::{FontAssets, ModelAssets, UiAssets};
-use crate::constants::collectibles::{MAX_COINS, MAX_COIN_PLACEMENT_ATTEMPTS, MIN_DISTANCE_BETWEEN_COINS};
-use crate::systems::collectibles::{CollectibleSpawner, NavigationBasedSpawner, NavigationData, CoinStreamingManager};
+use crate::assets::{AssetLoadReport, FontAssets, ModelAssets, UiAssets, init_placeholder_assets};
+use crate::constants::boundary::BoundaryConstants;
+use crate::constants::loading::ASSET_LOAD_TIMEOUT_SECS;
+use crate::constants::navigation::NAV_DATA_PATH;
+use crate::resources::platform::{PlatformCapabilities, read_file};
+use crate::constants::collectibles::{
+    COIN_CANDIDATE_OVERSAMPLE, FALLBACK_COIN_GROUND_OFFSET, FALLBACK_GROUND_PROBE_HEIGHT, MAX_COINS, MAX_COIN_PLACEMENT_ATTEMPTS, MIN_DISTANCE_BETWEEN_COINS,
+};
+use crate::systems::boundary::BoundaryConstraint;
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::ui::widgets::{ProgressBar, ProgressBarColors, progress_bar_widget};
+use crate::systems::collectibles::{
+    CoinStreamingManager, CollectibleSpawner, NavigationBasedSpawner, NavigationData, WorldSeed,
+};
 
 #[derive(Component)]
 struct PreGameLoadingScreen;
@@ -60,33 +71,50 @@ impl LoadingProgress {
         }
     }
 
-    pub fn get_progress_percentage(&self, current_time: f32) -> f32 {
-        if let Some(start_time) = self.loading_start_time {
-            let elapsed = current_time - start_time;
-            let progress = (elapsed / self.minimum_loading_time).min(1.0);
-            progress * 100.0
-        } else {
-            0.0
-        }
+    /// Completion flag and weight of each stage, in loading order
+    fn stages(&self) -> [(bool, f32); 5] {
+        [
+            (self.assets_loaded, STAGE_WEIGHTS[0]),
+            (self.environment_spawned, STAGE_WEIGHTS[1]),
+            (self.navigation_loaded, STAGE_WEIGHTS[2]),
+            (self.collectibles_spawned, STAGE_WEIGHTS[3]),
+            (self.game_initialized, STAGE_WEIGHTS[4]),
+        ]
     }
 
-    pub fn get_current_task(&self, current_time: f32) -> &'static str {
-        if let Some(start_time) = self.loading_start_time {
-            let elapsed = current_time - start_time;
-            match elapsed {
-                t if t < 1.0 => "Loading Assets...",
-                t if t < 2.0 => "Spawning Environment...",
-                t if t < 3.0 => "Loading Navigation Data...",
-                t if t < 4.0 => "Spawning Collectibles...",
-                t if t < 5.0 => "Initializing Game...",
-                _ => "Ready! Starting game...",
-            }
-        } else {
+    /// Progress based on the stages that have actually finished, so a slow stage
+    /// shows a stalled bar. The minimum loading time is enforced separately.
+    pub fn get_progress_percentage(&self) -> f32 {
+        let stages = self.stages();
+        let total: f32 = stages.iter().map(|(_, weight)| weight).sum();
+        let done: f32 = stages
+            .iter()
+            .filter(|(completed, _)| *completed)
+            .map(|(_, weight)| weight)
+            .sum();
+        (done / total * 100.0).min(100.0)
+    }
+
+    pub fn get_current_task(&self) -> &'static str {
+        if !self.assets_loaded {
             "Loading Assets..."
+        } else if !self.environment_spawned {
+            "Spawning Environment..."
+        } else if !self.navigation_loaded {
+            "Loading Navigation Data..."
+        } else if !self.collectibles_spawned {
+            "Spawning Collectibles..."
+        } else if !self.game_initialized {
+            "Initializing Game..."
+        } else {
+            "Ready! Starting game..."
         }
     }
 }
 
+/// Share of the progress bar each stage accounts for (assets and environment dominate)
+const STAGE_WEIGHTS: [f32; 5] = [0.35, 0.25, 0.1, 0.2, 0.1];
+
 pub fn plugin(app: &mut App) {
     app.init_resource::<LoadingProgress>()
         .init_resource::<CoinStreamingManager>()  // Initialize here so it persists between screens
@@ -103,18 +131,26 @@ pub fn plugin(app: &mut App) {
                 update_loading_ui,
             ).run_if(in_state(Screen::PreGameLoading))
         )
-        .add_systems(OnExit(Screen::PreGameLoading), cleanup_pregame_loading_only);
+        .add_systems(OnExit(Screen::PreGameLoading), cleanup_pregame_loading_only)
+        .add_systems(
+            OnTransition {
+                exited: Screen::PreGameLoading,
+                entered: Screen::MainMenu,
+            },
+            cancel_pregame_loading,
+        );
 }
 
 fn setup_pregame_loading_screen(
     mut commands: Commands,
     font_assets: Res<FontAssets>,
+    locale: Res<crate::resources::locale::Locale>,
     ui_assets: Res<UiAssets>,
     mut loading_progress: ResMut<LoadingProgress>,
     mut streaming_manager: ResMut<CoinStreamingManager>,
     mut collectible_spawner: ResMut<CollectibleSpawner>,
     mut nav_spawner: ResMut<NavigationBasedSpawner>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
 ) {
     // Reset all loading-related resources to prevent hanging on re-entry
     *loading_progress = LoadingProgress::new();
@@ -123,8 +159,9 @@ fn setup_pregame_loading_screen(
     // Reset streaming manager to clear old coin positions and spawned state
     *streaming_manager = CoinStreamingManager::default();
     
-    // Reset collectible spawner
+    // Reset collectible spawner and drop any placement still running from a previous visit
     collectible_spawner.coins_spawned = 0;
+    commands.remove_resource::<CoinPlacementTask>();
     
     // Reset navigation spawner loaded state to force reload
     nav_spawner.loaded = false;
@@ -182,7 +219,7 @@ fn setup_pregame_loading_screen(
             )).with_children(|parent| {
                 // Loading text
                 parent.spawn((
-                    Text::new("Loading Assets..."),
+                    Text::new(locale.t("loading.assets")),
                     TextFont {
                         font: font_assets.rajdhani_medium.clone(),
                         font_size: 40.0,
@@ -192,29 +229,27 @@ fn setup_pregame_loading_screen(
                     LoadingStatusText,
                 ));
 
-                // Progress bar background
+                // Progress bar
                 parent.spawn((
                     Node {
-                        width: Val::Px(400.0),
-                        height: Val::Px(20.0),
                         margin: UiRect::top(Val::Px(20.0)),
-                        border: UiRect::all(Val::Px(2.0)),
                         ..default()
                     },
-                    BorderColor(Color::WHITE),
-                    BackgroundColor(Color::BLACK.with_alpha(0.5)),
-                )).with_children(|parent| {
-                    // Progress bar fill
-                    parent.spawn((
-                        Node {
-                            width: Val::Percent(0.0),
-                            height: Val::Percent(100.0),
-                            ..default()
-                        },
-                        BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
-                        ProgressBarFill,
-                    ));
-                });
+                    children![(
+                        progress_bar_widget(
+                            400.0,
+                            20.0,
+                            0.0,
+                            ProgressBarColors {
+                                track: Color::BLACK.with_alpha(0.5),
+                                border: Color::WHITE,
+                                fill: Color::srgb(0.2, 0.8, 0.2),
+                                chip: None,
+                            },
+                        ),
+                        LoadingProgressBar,
+                    )],
+                ));
 
                 // Progress percentage
                 parent.spawn((
@@ -231,7 +266,53 @@ fn setup_pregame_loading_screen(
                     },
                     ProgressPercentageText,
                 ));
+
+                // Asset failures, filled in by update_loading_ui
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font: font_assets.rajdhani_medium.clone(),
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.3, 0.3)),
+                    Node {
+                        margin: UiRect::top(Val::Px(10.0)),
+                        max_width: Val::Px(600.0),
+                        ..default()
+                    },
+                    AssetErrorText,
+                ));
             });
+
+            // Way out if loading hangs; ESC does the same through the system input context
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(48.0),
+                        padding: UiRect::axes(Val::Px(32.0), Val::Px(12.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    Button,
+                    BackgroundColor(Color::DARKER_GLASS),
+                    BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                    BorderRadius::all(Val::Px(8.0)),
+                    children![(
+                        Text::new(locale.t("loading.cancel")),
+                        TextFont {
+                            font: font_assets.rajdhani_bold.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::ELYSIUM_GOLD),
+                        Pickable::IGNORE,
+                    )],
+                ))
+                .observe(|_: Trigger<Pointer<Click>>, mut next_state: ResMut<NextState<Screen>>| {
+                    next_state.set(Screen::MainMenu);
+                });
         });
 }
 
@@ -239,22 +320,33 @@ fn setup_pregame_loading_screen(
 struct LoadingStatusText;
 
 #[derive(Component)]
-struct ProgressBarFill;
+struct LoadingProgressBar;
 
 #[derive(Component)]
 struct ProgressPercentageText;
 
+#[derive(Component)]
+struct AssetErrorText;
+
 fn check_assets_loaded(
+    mut commands: Commands,
     model_assets: Option<Res<ModelAssets>>,
     font_assets: Option<Res<FontAssets>>,
     ui_assets: Option<Res<UiAssets>>,
     mut loading_progress: ResMut<LoadingProgress>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
 ) {
     if !loading_progress.assets_loaded && loading_progress.should_load_stage(0, time.elapsed_secs()) {
         if model_assets.is_some() && font_assets.is_some() && ui_assets.is_some() {
             loading_progress.assets_loaded = true;
-
+        } else if loading_progress
+            .loading_start_time
+            .is_some_and(|start| time.elapsed_secs() - start >= ASSET_LOAD_TIMEOUT_SECS)
+        {
+            // Don't hang here forever; carry on with whatever placeholders we can provide
+            warn!("Asset collections still missing after {}s, using placeholders", ASSET_LOAD_TIMEOUT_SECS);
+            init_placeholder_assets(&mut commands);
+            loading_progress.assets_loaded = true;
         }
     }
 }
@@ -263,7 +355,7 @@ fn spawn_environment_system(
     mut commands: Commands,
     assets: Option<Res<ModelAssets>>,
     mut loading_progress: ResMut<LoadingProgress>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
 ) {
     if loading_progress.assets_loaded 
         && !loading_progress.environment_spawned 
@@ -306,13 +398,18 @@ pub struct EnvironmentPreload;
 fn load_navigation_system(
     mut nav_spawner: ResMut<NavigationBasedSpawner>,
     mut loading_progress: ResMut<LoadingProgress>,
-    time: Res<Time>,
+    mut boundary_constraint: ResMut<BoundaryConstraint>,
+    capabilities: Res<PlatformCapabilities>,
+    time: Res<Time<Real>>,
 ) {
     if loading_progress.environment_spawned 
         && !loading_progress.navigation_loaded 
         && loading_progress.should_load_stage(2, time.elapsed_secs()) {
-        if !nav_spawner.loaded {
-            match fs::read_to_string("nav.json") {
+        if !nav_spawner.loaded && !capabilities.has_nav_data {
+            // Nothing to load on this platform; coins fall back to scattered placement
+            loading_progress.navigation_loaded = true;
+        } else if !nav_spawner.loaded {
+            match read_file(NAV_DATA_PATH) {
                 Ok(contents) => {
                                             match serde_json::from_str::<NavigationData>(&contents) {
                         Ok(nav_data) => {
@@ -320,6 +417,15 @@ fn load_navigation_system(
                                 .iter()
                                 .map(|point| Vec3::new(point.position[0], point.position[1], point.position[2]))
                                 .collect();
+
+                            // Derive the playable area from the recorded navigation bounds
+                            match BoundaryConstraint::from_navigation_stats(
+                                &nav_data.statistics,
+                                BoundaryConstants::WALL_MARGIN,
+                            ) {
+                                Some(constraint) => *boundary_constraint = constraint,
+                                None => warn!("nav.json bounds are unusable, keeping default boundary"),
+                            }
                             
                             nav_spawner.loaded = true;
                             loading_progress.navigation_loaded = true;
@@ -344,101 +450,164 @@ fn load_navigation_system(
     }
 }
 
+/// Candidate coin positions being generated off the main thread
+#[derive(Resource)]
+struct CoinPlacementTask {
+    task: Task<Vec<Vec3>>,
+    // Without navigation data candidates still need to be dropped onto the ground
+    from_navigation: bool,
+}
+
 fn spawn_collectibles_system(
+    mut commands: Commands,
     nav_spawner: Res<NavigationBasedSpawner>,
     mut collectible_spawner: ResMut<CollectibleSpawner>,
     mut streaming_manager: ResMut<CoinStreamingManager>,
     mut loading_progress: ResMut<LoadingProgress>,
     spatial_query: SpatialQuery,
-    time: Res<Time>,
-    boundary_constraint: Option<Res<crate::systems::boundary::BoundaryConstraint>>,
+    time: Res<Time<Real>>,
+    boundary_constraint: Res<BoundaryConstraint>,
+    world_seed: Res<WorldSeed>,
+    placement_task: Option<ResMut<CoinPlacementTask>>,
 ) {
-    if !loading_progress.collectibles_spawned 
-        && loading_progress.should_load_stage(3, time.elapsed_secs()) {
-        if collectible_spawner.coins_spawned == 0 {
-            // Pre-calculate coin positions using navigation data
-
-            
-            if nav_spawner.loaded && !nav_spawner.nav_positions.is_empty() {
+    // Wait for navigation so coins are placed against the derived boundary
+    if loading_progress.collectibles_spawned
+        || !loading_progress.navigation_loaded
+        || !loading_progress.should_load_stage(3, time.elapsed_secs())
+        || collectible_spawner.coins_spawned != 0
+    {
+        return;
+    }
 
-            } else {
+    let Some(mut placement_task) = placement_task else {
+        // Distance and boundary checks are pure math, so run them on the compute pool
+        let nav_positions = if nav_spawner.loaded {
+            nav_spawner.nav_positions.clone()
+        } else {
+            Vec::new()
+        };
+        let from_navigation = !nav_positions.is_empty();
+        let boundary = boundary_constraint.clone();
+        let mut rng = world_seed.rng();
+        info!("Placing coins with world seed {}", world_seed.0);
+        let task = AsyncComputeTaskPool::get()
+            .spawn(async move { generate_coin_candidates(&nav_positions, &boundary, &mut rng) });
+        commands.insert_resource(CoinPlacementTask { task, from_navigation });
+        return;
+    };
+
+    let Some(candidates) = block_on(future::poll_once(&mut placement_task.task)) else {
+        return; // Still generating
+    };
+    commands.remove_resource::<CoinPlacementTask>();
+
+    // SpatialQuery isn't Send, so only the physics validation happens here
+    let mut coins_calculated = 0;
+    for coin_pos in candidates {
+        if coins_calculated >= MAX_COINS {
+            break;
+        }
 
+        let coin_pos = if placement_task.from_navigation {
+            coin_pos
+        } else {
+            match ground_fallback_position(coin_pos, &spatial_query) {
+                Some(grounded) => grounded,
+                None => continue, // Nothing underneath, e.g. a gap in the level
             }
-            
-            let mut rng = rand::rng();
-            let mut spawned_positions = Vec::new();
-            let mut coins_calculated = 0;
-            let mut attempts = 0;
-
-            while coins_calculated < MAX_COINS && attempts < MAX_COIN_PLACEMENT_ATTEMPTS {
-                attempts += 1;
-
-                // Use navigation positions if available, otherwise generate fallback positions
-                let base_pos = if nav_spawner.loaded && !nav_spawner.nav_positions.is_empty() {
-                    // Use actual navigation data
-                    nav_spawner.nav_positions[rng.random_range(0..nav_spawner.nav_positions.len())]
-                } else {
-                    // Generate fallback positions closer to spawn
-                    Vec3::new(
-                        rng.random_range(-60.0..60.0), // Reasonable range around spawn
-                        2.0, // Above ground for visibility
-                        rng.random_range(-60.0..60.0), // Reasonable range around spawn
-                    )
-                };
-                
-                // Add some randomness around the navigation position
-                let offset_x = rng.random_range(-5.0..5.0);
-                let offset_z = rng.random_range(-5.0..5.0);
-                let coin_pos = Vec3::new(
-                    base_pos.x + offset_x,
-                    base_pos.y.max(1.5), // Ensure above ground
-                    base_pos.z + offset_z,
-                );
-
-                // Check boundary constraints
-                let mut within_bounds = true;
-                if let Some(constraint) = &boundary_constraint {
-                    within_bounds = coin_pos.x >= constraint.min_x 
-                        && coin_pos.x <= constraint.max_x 
-                        && coin_pos.z >= constraint.min_z 
-                        && coin_pos.z <= constraint.max_z;
-                }
+        };
 
-                if !within_bounds {
-                    continue;
-                }
-
-                // Check minimum distance from other coins
-                let too_close = spawned_positions.iter().any(|&other_pos: &Vec3| {
-                    coin_pos.distance(other_pos) < MIN_DISTANCE_BETWEEN_COINS
-                });
-
-                if !too_close && is_valid_coin_position_preload(coin_pos, &spatial_query) {
-                    streaming_manager.add_position(coin_pos);
-                    spawned_positions.push(coin_pos);
-                    coins_calculated += 1;
-
-                    // Log progress every 100 coins
-
-                }
-            }
+        if is_valid_coin_position_preload(coin_pos, &spatial_query) {
+            streaming_manager.add_position(coin_pos);
+            coins_calculated += 1;
+        }
+    }
 
-            collectible_spawner.coins_spawned = coins_calculated;
-            loading_progress.collectibles_spawned = true;
+    if placement_task.from_navigation {
+        info!("Placed {} coins from navigation data", coins_calculated);
+    } else {
+        info!("Placed {} coins via fallback (no navigation data)", coins_calculated);
+    }
 
-            if coins_calculated < MAX_COINS {
+    collectible_spawner.coins_spawned = coins_calculated;
+    loading_progress.collectibles_spawned = true;
+}
 
-            } else {
+/// Generates well-spaced coin positions inside the boundary. Produces more than
+/// `MAX_COINS` so the physics check on the main thread can reject some.
+fn generate_coin_candidates(
+    nav_positions: &[Vec3],
+    boundary: &BoundaryConstraint,
+    rng: &mut impl Rng,
+) -> Vec<Vec3> {
+    let target = MAX_COINS * COIN_CANDIDATE_OVERSAMPLE;
+    let mut candidates: Vec<Vec3> = Vec::with_capacity(target);
+    let mut attempts = 0;
+
+    while candidates.len() < target && attempts < MAX_COIN_PLACEMENT_ATTEMPTS {
+        attempts += 1;
+
+        // Use navigation positions if available, otherwise generate fallback positions
+        let base_pos = if !nav_positions.is_empty() {
+            // Use actual navigation data
+            nav_positions[rng.random_range(0..nav_positions.len())]
+        } else {
+            // No navigation data (e.g. wasm): scatter across the whole playable area,
+            // the main thread drops these onto the ground afterwards
+            Vec3::new(
+                rng.random_range(boundary.min_x..boundary.max_x),
+                FALLBACK_GROUND_PROBE_HEIGHT,
+                rng.random_range(boundary.min_z..boundary.max_z),
+            )
+        };
+
+        // Add some randomness around the navigation position
+        let offset_x = rng.random_range(-5.0..5.0);
+        let offset_z = rng.random_range(-5.0..5.0);
+        let coin_pos = Vec3::new(
+            base_pos.x + offset_x,
+            base_pos.y.max(1.5), // Ensure above ground
+            base_pos.z + offset_z,
+        );
+
+        // Check boundary constraints
+        if !boundary.contains(coin_pos) {
+            continue;
+        }
 
-            }
-            
+        // Check minimum distance from other coins
+        let too_close = candidates
+            .iter()
+            .any(|other_pos| coin_pos.distance(*other_pos) < MIN_DISTANCE_BETWEEN_COINS);
 
+        if !too_close {
+            candidates.push(coin_pos);
         }
     }
+
+    candidates
 }
 
 // Removed: No longer pre-spawning collectible entities
 
+/// Casts down from a fallback candidate and returns the spot just above the ground it hits
+fn ground_fallback_position(candidate: Vec3, spatial_query: &SpatialQuery) -> Option<Vec3> {
+    let origin = Vec3::new(candidate.x, FALLBACK_GROUND_PROBE_HEIGHT, candidate.z);
+    let hit = spatial_query.cast_ray(
+        origin,
+        Dir3::NEG_Y,
+        FALLBACK_GROUND_PROBE_HEIGHT * 2.0,
+        true,
+        &SpatialQueryFilter::default(),
+    )?;
+
+    Some(Vec3::new(
+        candidate.x,
+        origin.y - hit.distance + FALLBACK_COIN_GROUND_OFFSET,
+        candidate.z,
+    ))
+}
+
 fn is_valid_coin_position_preload(
     position: Vec3,
     spatial_query: &SpatialQuery,
@@ -461,7 +630,7 @@ fn is_valid_coin_position_preload(
 
 fn initialize_game_system(
     mut loading_progress: ResMut<LoadingProgress>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
 ) {
     if loading_progress.collectibles_spawned 
         && !loading_progress.game_initialized 
@@ -479,17 +648,26 @@ fn initialize_game_system(
 fn check_loading_complete(
     mut loading_progress: ResMut<LoadingProgress>,
     mut next_state: ResMut<NextState<Screen>>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
+    mut worst_frame_secs: Local<f32>,
 ) {
     let current_time = time.elapsed_secs();
+
+    // Longest frame seen while loading, to keep an eye on main-thread spikes
+    *worst_frame_secs = worst_frame_secs.max(time.delta_secs());
     
     if loading_progress.can_transition(current_time) && !loading_progress.loading_complete {
         loading_progress.loading_complete = true;
         
         if let Some(start_time) = loading_progress.loading_start_time {
-            let _elapsed = current_time - start_time;
-            // Removed logging statement
+            let elapsed = current_time - start_time;
+            info!(
+                "Loading finished in {:.2}s, worst frame {:.1}ms",
+                elapsed,
+                *worst_frame_secs * 1000.0
+            );
         }
+        *worst_frame_secs = 0.0;
         
         next_state.set(Screen::GamePlay);
     } else if loading_progress.is_ready() && loading_progress.loading_start_time.is_some() {
@@ -515,29 +693,85 @@ fn cleanup_pregame_loading_only(
 
 }
 
+/// Backing out to the menu throws away everything loaded so far, so the next
+/// attempt starts clean. Dropping the placement task cancels it on the compute pool.
+fn cancel_pregame_loading(
+    mut commands: Commands,
+    preload_query: Query<Entity, With<EnvironmentPreload>>,
+    mut loading_progress: ResMut<LoadingProgress>,
+    mut streaming_manager: ResMut<CoinStreamingManager>,
+    mut collectible_spawner: ResMut<CollectibleSpawner>,
+) {
+    info!("Loading cancelled, returning to the main menu");
+    commands.remove_resource::<CoinPlacementTask>();
+    commands.remove_resource::<crate::systems::save::ResumeSave>();
+    for entity in &preload_query {
+        commands.entity(entity).despawn();
+    }
+    *loading_progress = LoadingProgress::default();
+    *streaming_manager = CoinStreamingManager::default();
+    collectible_spawner.coins_spawned = 0;
+}
+
 fn update_loading_ui(
     loading_progress: Res<LoadingProgress>,
     mut status_text_query: Query<&mut Text, With<LoadingStatusText>>,
-    mut progress_bar_query: Query<&mut Node, With<ProgressBarFill>>,
+    mut progress_bar_query: Query<&mut ProgressBar, With<LoadingProgressBar>>,
     mut percentage_text_query: Query<&mut Text, (With<ProgressPercentageText>, Without<LoadingStatusText>)>,
-    time: Res<Time>,
+    mut error_text_query: Query<&mut Text, (With<AssetErrorText>, Without<LoadingStatusText>, Without<ProgressPercentageText>)>,
+    asset_report: Res<AssetLoadReport>,
 ) {
-    let current_time = time.elapsed_secs();
-    
-    if loading_progress.is_changed() || loading_progress.loading_start_time.is_some() {
+    // Surface asset failures so a broken install doesn't look like a normal load
+    if asset_report.is_changed() || asset_report.has_errors() {
+        if let Ok(mut text) = error_text_query.single_mut() {
+            **text = match asset_report.failed.as_slice() {
+                [] if asset_report.timed_out => "Some assets took too long to load, using placeholders".to_string(),
+                [] => String::new(),
+                [only] => format!("Failed to load {}", only),
+                [first, rest @ ..] => format!("Failed to load {} (and {} more)", first, rest.len()),
+            };
+        }
+    }
+
+    // Stage flags only change when work finishes, so there's nothing to animate in between
+    if loading_progress.is_changed() {
+        let percentage = loading_progress.get_progress_percentage();
+
         // Update status text
         if let Ok(mut text) = status_text_query.single_mut() {
-            **text = loading_progress.get_current_task(current_time).to_string();
+            **text = loading_progress.get_current_task().to_string();
         }
 
         // Update progress bar
-        if let Ok(mut node) = progress_bar_query.single_mut() {
-            node.width = Val::Percent(loading_progress.get_progress_percentage(current_time));
+        if let Ok(mut bar) = progress_bar_query.single_mut() {
+            bar.progress = percentage / 100.0;
         }
 
         // Update percentage text
         if let Ok(mut text) = percentage_text_query.single_mut() {
-            **text = format!("{:.0}%", loading_progress.get_progress_percentage(current_time));
+            **text = format!("{:.0}%", percentage);
         }
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(seed: u64, nav_positions: &[Vec3]) -> Vec<Vec3> {
+        generate_coin_candidates(nav_positions, &BoundaryConstraint::default(), &mut WorldSeed(seed).rng())
+    }
+
+    #[test]
+    fn same_seed_places_the_same_coins() {
+        let nav_positions = [Vec3::new(0.0, 2.0, 0.0), Vec3::new(20.0, 2.0, -15.0), Vec3::new(-30.0, 3.0, 25.0)];
+        assert_eq!(candidates(7, &nav_positions), candidates(7, &nav_positions));
+        // The fallback scatter used without navigation data is seeded too
+        assert_eq!(candidates(7, &[]), candidates(7, &[]));
+    }
+
+    #[test]
+    fn different_seeds_place_different_coins() {
+        assert_ne!(candidates(1, &[]), candidates(2, &[]));
+    }
+}
@@ -5,8 +5,22 @@ use bevy_lunex::*;
 use super::{MainTrack, Screen};
 use crate::assets::{FontAssets, UiAssets};
 use crate::audio::AudioSettings;
-use crate::ui::styles::ElysiumDescentColorPalette;
-use crate::ui::widgets::volume_widget;
+use crate::keybinding::CONTROL_BINDINGS;
+use crate::ui::styles::{ElysiumDescentColorPalette, UiPalette};
+use crate::constants::graphics::{FOV_STEP_DEGREES, RENDER_DISTANCE_STEP};
+use crate::constants::hud::UI_SCALE_STEP;
+use crate::constants::input::STICK_DEADZONE_STEP;
+use crate::constants::save::AutosaveConfig;
+use crate::resources::graphics::GraphicsSettings;
+use crate::resources::difficulty::DifficultySettings;
+use crate::resources::hud::HudSettings;
+use crate::resources::input::InputSettings;
+use crate::resources::locale::{Locale, LocaleSettings};
+use crate::systems::save::AutosaveSettings;
+use crate::ui::widgets::{
+    MuteToggleLabel, VolumeChannel, mute_label, stepper_widget, toggle_button_widget,
+    update_volume_widgets, volume_widget,
+};
 
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
 enum SettingsTab {
@@ -14,6 +28,7 @@ enum SettingsTab {
     Sound,
     Graphics,
     Window,
+    Game,
 }
 
 #[derive(Resource, Default)]
@@ -25,6 +40,34 @@ impl Default for SettingsTab {
     }
 }
 
+impl SettingsTab {
+    /// Tab order used by the chevrons, matching the tab bar left to right
+    const ORDER: [SettingsTab; 5] = [
+        SettingsTab::Controls,
+        SettingsTab::Sound,
+        SettingsTab::Graphics,
+        SettingsTab::Window,
+        SettingsTab::Game,
+    ];
+
+    /// String table key for the tab's name
+    fn label_key(self) -> &'static str {
+        match self {
+            SettingsTab::Controls => "settings.tab.controls",
+            SettingsTab::Sound => "settings.tab.sound",
+            SettingsTab::Graphics => "settings.tab.graphics",
+            SettingsTab::Window => "settings.tab.window",
+            SettingsTab::Game => "settings.tab.game",
+        }
+    }
+
+    fn offset(self, step: isize) -> Self {
+        let len = Self::ORDER.len() as isize;
+        let index = Self::ORDER.iter().position(|tab| *tab == self).unwrap_or(0) as isize;
+        Self::ORDER[(index + step).rem_euclid(len) as usize]
+    }
+}
+
 #[derive(Resource, Default)]
 struct LastRenderedTab(Option<SettingsTab>);
 
@@ -37,54 +80,389 @@ struct SettingsContentRoot;
 #[derive(Component)]
 struct TabLabel(SettingsTab);
 
-fn update_tab_colors(current: SettingsTab, mut query: Query<(&TabLabel, &mut UiColor)>) {
+/// Repaints the tab row when the selection or the colorblind palette changes
+fn update_tab_colors(
+    selected_tab: Res<SelectedTab>,
+    palette: Res<UiPalette>,
+    mut query: Query<(&TabLabel, &mut UiColor)>,
+) {
     for (label, mut color) in &mut query {
         color.insert(
             UiBase::id(),
-            (if label.0 == current {
+            (if label.0 == selected_tab.0 {
                 Color::WHITE
             } else {
-                Color::ELYSIUM_DESCENT_RED
+                palette.accent
             })
             .into(),
         );
     }
 }
 
-fn render_sound_settings(
-    _: Trigger<Pointer<Click>>,
-    mut selected_tab: ResMut<SelectedTab>,
-    query: Query<(&TabLabel, &mut UiColor)>,
-) {
+fn render_sound_settings(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
     selected_tab.0 = SettingsTab::Sound;
-    update_tab_colors(SettingsTab::Sound, query);
 }
 
-fn render_controls_settings(
-    _: Trigger<Pointer<Click>>,
-    mut selected_tab: ResMut<SelectedTab>,
-    query: Query<(&TabLabel, &mut UiColor)>,
-) {
+fn render_controls_settings(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
     selected_tab.0 = SettingsTab::Controls;
-    update_tab_colors(SettingsTab::Controls, query);
 }
 
-fn render_graphics_settings(
-    _: Trigger<Pointer<Click>>,
-    mut selected_tab: ResMut<SelectedTab>,
-    query: Query<(&TabLabel, &mut UiColor)>,
-) {
+fn render_graphics_settings(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
     selected_tab.0 = SettingsTab::Graphics;
-    update_tab_colors(SettingsTab::Graphics, query);
 }
 
-fn render_windows_settings(
-    _: Trigger<Pointer<Click>>,
-    mut selected_tab: ResMut<SelectedTab>,
-    query: Query<(&TabLabel, &mut UiColor)>,
-) {
+fn render_windows_settings(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
     selected_tab.0 = SettingsTab::Window;
-    update_tab_colors(SettingsTab::Window, query);
+}
+
+fn render_game_settings(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
+    selected_tab.0 = SettingsTab::Game;
+}
+
+fn select_previous_tab(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
+    selected_tab.0 = selected_tab.0.offset(-1);
+}
+
+fn select_next_tab(_: Trigger<Pointer<Click>>, mut selected_tab: ResMut<SelectedTab>) {
+    selected_tab.0 = selected_tab.0.offset(1);
+}
+
+fn toggle_mute_all(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
+    settings.muted = !settings.muted;
+    info!("Mute all: {}", settings.muted);
+}
+
+#[derive(Component)]
+struct ShadowsToggleLabel;
+
+#[derive(Component)]
+struct ShadowQualityLabel;
+
+#[derive(Component)]
+struct GroundShadowsToggleLabel;
+
+fn shadows_label(settings: &GraphicsSettings) -> String {
+    format!("SHADOWS: {}", if settings.shadows_enabled { "ON" } else { "OFF" })
+}
+
+fn ground_shadows_label(settings: &GraphicsSettings) -> String {
+    format!("CHARACTER SHADOWS: {}", if settings.ground_shadows { "ON" } else { "OFF" })
+}
+
+#[derive(Component)]
+struct DayNightCycleLabel;
+
+fn day_night_cycle_label(settings: &GraphicsSettings) -> String {
+    format!("DAY/NIGHT CYCLE: {}", if settings.day_night_cycle { "ON" } else { "OFF" })
+}
+
+fn toggle_day_night_cycle(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.day_night_cycle = !settings.day_night_cycle;
+}
+
+fn shadow_quality_label(settings: &GraphicsSettings) -> String {
+    format!("SHADOW QUALITY: {}", settings.shadow_quality.supported().label())
+}
+
+fn toggle_shadows(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.shadows_enabled = !settings.shadows_enabled;
+}
+
+fn toggle_ground_shadows(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.ground_shadows = !settings.ground_shadows;
+}
+
+fn cycle_shadow_quality(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    settings.shadow_quality = settings.shadow_quality.next();
+    // Skip levels the platform can't run
+    while settings.shadow_quality.supported() != settings.shadow_quality {
+        settings.shadow_quality = settings.shadow_quality.next();
+    }
+}
+
+#[derive(Component)]
+struct FovLabel;
+
+#[derive(Component)]
+struct RenderDistanceLabel;
+
+fn fov_label(settings: &GraphicsSettings) -> String {
+    format!("{:.0}", settings.fov_degrees)
+}
+
+fn render_distance_label(settings: &GraphicsSettings) -> String {
+    format!("{:.0}m", settings.render_distance)
+}
+
+fn lower_fov(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    let fov = settings.fov_degrees - FOV_STEP_DEGREES;
+    settings.set_fov_degrees(fov);
+}
+
+fn raise_fov(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    let fov = settings.fov_degrees + FOV_STEP_DEGREES;
+    settings.set_fov_degrees(fov);
+}
+
+fn lower_render_distance(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    let distance = settings.render_distance - RENDER_DISTANCE_STEP;
+    settings.set_render_distance(distance);
+}
+
+fn raise_render_distance(_: Trigger<Pointer<Click>>, mut settings: ResMut<GraphicsSettings>) {
+    let distance = settings.render_distance + RENDER_DISTANCE_STEP;
+    settings.set_render_distance(distance);
+}
+
+#[derive(Component)]
+struct StickDeadzoneLabel;
+
+fn stick_deadzone_label(settings: &InputSettings) -> String {
+    format!("{:.0}%", settings.stick_deadzone * 100.0)
+}
+
+fn lower_stick_deadzone(_: Trigger<Pointer<Click>>, mut settings: ResMut<InputSettings>) {
+    let deadzone = settings.stick_deadzone - STICK_DEADZONE_STEP;
+    settings.set_stick_deadzone(deadzone);
+}
+
+fn raise_stick_deadzone(_: Trigger<Pointer<Click>>, mut settings: ResMut<InputSettings>) {
+    let deadzone = settings.stick_deadzone + STICK_DEADZONE_STEP;
+    settings.set_stick_deadzone(deadzone);
+}
+
+fn update_stick_deadzone_label(
+    settings: Res<InputSettings>,
+    mut label_query: Query<&mut Text, With<StickDeadzoneLabel>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in label_query.iter_mut() {
+        text.0 = stick_deadzone_label(&settings);
+    }
+}
+
+#[derive(Component)]
+struct AutosaveToggleLabel;
+
+#[derive(Component)]
+struct AutosaveIntervalLabel;
+
+fn autosave_label(settings: &AutosaveSettings) -> String {
+    format!("AUTOSAVE: {}", if settings.enabled { "ON" } else { "OFF" })
+}
+
+fn autosave_interval_label(settings: &AutosaveSettings) -> String {
+    format!("{:.0}s", settings.interval_secs)
+}
+
+fn toggle_autosave(_: Trigger<Pointer<Click>>, mut settings: ResMut<AutosaveSettings>) {
+    settings.enabled = !settings.enabled;
+}
+
+fn lower_autosave_interval(_: Trigger<Pointer<Click>>, mut settings: ResMut<AutosaveSettings>) {
+    let secs = settings.interval_secs - AutosaveConfig::INTERVAL_STEP_SECS;
+    settings.set_interval_secs(secs);
+}
+
+fn raise_autosave_interval(_: Trigger<Pointer<Click>>, mut settings: ResMut<AutosaveSettings>) {
+    let secs = settings.interval_secs + AutosaveConfig::INTERVAL_STEP_SECS;
+    settings.set_interval_secs(secs);
+}
+
+#[derive(Component)]
+struct CompletedObjectivesLabel;
+
+fn completed_objectives_label(settings: &HudSettings) -> String {
+    format!(
+        "COMPLETED OBJECTIVES: {}",
+        if settings.show_completed_objectives { "SHOWN" } else { "HIDDEN" }
+    )
+}
+
+#[derive(Component)]
+struct CompassToggleLabel;
+
+fn compass_label(settings: &HudSettings) -> String {
+    format!("COMPASS: {}", if settings.compass_visible { "ON" } else { "OFF" })
+}
+
+#[derive(Component)]
+struct UiScaleLabel;
+
+fn ui_scale_label(settings: &HudSettings) -> String {
+    format!("{:.0}%", settings.ui_scale * 100.0)
+}
+
+#[derive(Component)]
+struct ColorblindModeLabel;
+
+fn colorblind_mode_label(settings: &HudSettings) -> String {
+    format!("COLORS: {}", settings.colorblind_mode.label())
+}
+
+fn cycle_colorblind_mode(_: Trigger<Pointer<Click>>, mut settings: ResMut<HudSettings>) {
+    settings.colorblind_mode = settings.colorblind_mode.next();
+}
+
+fn lower_ui_scale(_: Trigger<Pointer<Click>>, mut settings: ResMut<HudSettings>) {
+    let scale = settings.ui_scale - UI_SCALE_STEP;
+    settings.set_ui_scale(scale);
+}
+
+fn raise_ui_scale(_: Trigger<Pointer<Click>>, mut settings: ResMut<HudSettings>) {
+    let scale = settings.ui_scale + UI_SCALE_STEP;
+    settings.set_ui_scale(scale);
+}
+
+fn toggle_compass(_: Trigger<Pointer<Click>>, mut settings: ResMut<HudSettings>) {
+    settings.compass_visible = !settings.compass_visible;
+}
+
+fn toggle_completed_objectives(_: Trigger<Pointer<Click>>, mut settings: ResMut<HudSettings>) {
+    settings.show_completed_objectives = !settings.show_completed_objectives;
+}
+
+fn update_hud_labels(
+    settings: Res<HudSettings>,
+    mut objectives_query: Query<&mut Text, (With<CompletedObjectivesLabel>, Without<CompassToggleLabel>)>,
+    mut compass_query: Query<&mut Text, (With<CompassToggleLabel>, Without<CompletedObjectivesLabel>)>,
+    mut scale_query: Query<
+        &mut Text,
+        (
+            With<UiScaleLabel>,
+            Without<CompletedObjectivesLabel>,
+            Without<CompassToggleLabel>,
+        ),
+    >,
+    mut colors_query: Query<
+        &mut Text,
+        (
+            With<ColorblindModeLabel>,
+            Without<CompletedObjectivesLabel>,
+            Without<CompassToggleLabel>,
+            Without<UiScaleLabel>,
+        ),
+    >,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in objectives_query.iter_mut() {
+        text.0 = completed_objectives_label(&settings);
+    }
+    for mut text in compass_query.iter_mut() {
+        text.0 = compass_label(&settings);
+    }
+    for mut text in scale_query.iter_mut() {
+        text.0 = ui_scale_label(&settings);
+    }
+    for mut text in colors_query.iter_mut() {
+        text.0 = colorblind_mode_label(&settings);
+    }
+}
+
+#[derive(Component)]
+struct DifficultyLabel;
+
+fn difficulty_label(settings: &DifficultySettings) -> String {
+    format!("DIFFICULTY: {}", settings.difficulty.label())
+}
+
+/// Only read when a level starts, so picking a new one mid-run waits for the next level
+fn cycle_difficulty(_: Trigger<Pointer<Click>>, mut settings: ResMut<DifficultySettings>) {
+    settings.difficulty = settings.difficulty.next();
+}
+
+fn update_difficulty_label(
+    settings: Res<DifficultySettings>,
+    mut label_query: Query<&mut Text, With<DifficultyLabel>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in label_query.iter_mut() {
+        text.0 = difficulty_label(&settings);
+    }
+}
+
+#[derive(Component)]
+struct LanguageLabel;
+
+fn language_label(locale: &Locale) -> String {
+    format!("{}: {}", locale.t("settings.language"), locale.language.label())
+}
+
+fn cycle_language(_: Trigger<Pointer<Click>>, mut settings: ResMut<LocaleSettings>) {
+    settings.language = settings.language.next();
+}
+
+/// Re-translates the labels already on screen once the new string table is in
+fn update_language_labels(
+    locale: Res<Locale>,
+    mut language_query: Query<&mut Text, With<LanguageLabel>>,
+    mut tab_query: Query<(&TabLabel, &mut Text2d)>,
+) {
+    if !locale.is_changed() {
+        return;
+    }
+    for mut text in language_query.iter_mut() {
+        text.0 = language_label(&locale);
+    }
+    for (tab, mut text) in tab_query.iter_mut() {
+        text.0 = locale.t(tab.0.label_key());
+    }
+}
+
+fn update_autosave_labels(
+    settings: Res<AutosaveSettings>,
+    mut toggle_query: Query<&mut Text, (With<AutosaveToggleLabel>, Without<AutosaveIntervalLabel>)>,
+    mut interval_query: Query<&mut Text, (With<AutosaveIntervalLabel>, Without<AutosaveToggleLabel>)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in toggle_query.iter_mut() {
+        text.0 = autosave_label(&settings);
+    }
+    for mut text in interval_query.iter_mut() {
+        text.0 = autosave_interval_label(&settings);
+    }
+}
+
+fn update_graphics_labels(
+    settings: Res<GraphicsSettings>,
+    mut label_query: Query<(
+        &mut Text,
+        Has<ShadowsToggleLabel>,
+        Has<GroundShadowsToggleLabel>,
+        Has<ShadowQualityLabel>,
+        Has<FovLabel>,
+        Has<RenderDistanceLabel>,
+        Has<DayNightCycleLabel>,
+    )>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (mut text, shadows, ground_shadows, quality, fov, render_distance, day_night) in
+        label_query.iter_mut()
+    {
+        if shadows {
+            text.0 = shadows_label(&settings);
+        } else if ground_shadows {
+            text.0 = ground_shadows_label(&settings);
+        } else if quality {
+            text.0 = shadow_quality_label(&settings);
+        } else if fov {
+            text.0 = fov_label(&settings);
+        } else if render_distance {
+            text.0 = render_distance_label(&settings);
+        } else if day_night {
+            text.0 = day_night_cycle_label(&settings);
+        }
+    }
 }
 
 fn lower_master_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettings>) {
@@ -118,14 +496,29 @@ fn raise_sfx_volume(_: Trigger<Pointer<Click>>, mut settings: ResMut<AudioSettin
 }
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Settings), SettingsScene::spawn)
+    app.add_systems(
+        OnEnter(Screen::Settings),
+        (SettingsScene::spawn, update_tab_colors).chain(),
+    )
         .add_systems(
             OnExit(Screen::Settings),
             super::despawn_scene::<SettingsScene>,
         )
         .add_systems(
             Update,
-            render_tab_content.run_if(in_state(Screen::Settings)),
+            (
+                render_tab_content,
+                update_volume_widgets,
+                update_graphics_labels,
+                update_stick_deadzone_label,
+                update_autosave_labels,
+                update_hud_labels,
+                update_difficulty_label,
+                update_language_labels,
+                update_tab_colors
+                    .run_if(resource_changed::<SelectedTab>.or(resource_changed::<UiPalette>)),
+            )
+                .run_if(in_state(Screen::Settings)),
         )
         .insert_resource(SelectedTab::default())
         .insert_resource(LastRenderedTab::default())
@@ -145,6 +538,12 @@ fn render_tab_content(
     mut resize_events: EventReader<WindowResized>,
     last_height: Local<Option<f32>>,
     audio_settings: Res<AudioSettings>,
+    graphics_settings: Res<GraphicsSettings>,
+    autosave_settings: Res<AutosaveSettings>,
+    input_settings: Res<InputSettings>,
+    hud_settings: Res<HudSettings>,
+    difficulty_settings: Res<DifficultySettings>,
+    locale: Res<Locale>,
 ) {
     let window = windows.single().unwrap();
     let window_height = window.height();
@@ -154,9 +553,9 @@ fn render_tab_content(
 
     let tab_changed = last_rendered.0 != Some(selected_tab.0);
 
-    let settings_changed = audio_settings.is_changed();
-
-    if tab_changed || resized || settings_changed {
+    // Volume changes are applied in place by `update_volume_widgets`, so a slider
+    // being dragged isn't despawned from under the pointer
+    if tab_changed || resized {
         last_rendered.0 = Some(selected_tab.0);
 
         // Remove old tab content
@@ -198,13 +597,23 @@ fn render_tab_content(
                                         ..default()
                                     },))
                                     .with_children(|content| {
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            mute_label(audio_settings.muted),
+                                            MuteToggleLabel,
+                                            12.0,
+                                            toggle_mute_all,
+                                        );
+
                                         volume_widget(
                                             content,
                                             window_height,
                                             font_assets.rajdhani_medium.clone(),
                                             "Master Volume",
-                                            ((audio_settings.master_volume * 10.0).floor() as i32)
-                                                .to_string(),
+                                            VolumeChannel::Master,
+                                            audio_settings.master_volume,
                                             30.0,
                                             lower_master_volume,
                                             raise_master_volume,
@@ -215,8 +624,8 @@ fn render_tab_content(
                                             window_height,
                                             font_assets.rajdhani_medium.clone(),
                                             "Music Volume",
-                                            ((audio_settings.music_volume * 10.0).floor() as i32)
-                                                .to_string(),
+                                            VolumeChannel::Music,
+                                            audio_settings.music_volume,
                                             50.0,
                                             lower_music_volume,
                                             raise_music_volume,
@@ -227,17 +636,256 @@ fn render_tab_content(
                                             window_height,
                                             font_assets.rajdhani_medium.clone(),
                                             "SFX Volume",
-                                            ((audio_settings.sfx_volume * 10.0).floor() as i32)
-                                                .to_string(),
+                                            VolumeChannel::Sfx,
+                                            audio_settings.sfx_volume,
                                             70.0,
                                             lower_sfx_volume,
                                             raise_sfx_volume,
                                         );
                                     });
                             }
-                            SettingsTab::Controls => {}
-                            SettingsTab::Graphics => {}
-                            SettingsTab::Window => {}
+                            SettingsTab::Controls => {
+                                let font_size = window_height * 0.028;
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(80.0),
+                                        height: Val::Percent(100.0),
+                                        flex_direction: FlexDirection::Column,
+                                        justify_content: JustifyContent::Center,
+                                        row_gap: Val::Px(font_size * 0.5),
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        let header = ("ACTION", "KEYBOARD", "GAMEPAD");
+                                        for (action, keyboard, gamepad) in
+                                            std::iter::once(header).chain(CONTROL_BINDINGS)
+                                        {
+                                            content
+                                                .spawn(Node {
+                                                    width: Val::Percent(100.0),
+                                                    flex_direction: FlexDirection::Row,
+                                                    ..default()
+                                                })
+                                                .with_children(|row| {
+                                                    for column in [action, keyboard, gamepad] {
+                                                        row.spawn((
+                                                            Node {
+                                                                width: Val::Percent(100.0 / 3.0),
+                                                                ..default()
+                                                            },
+                                                            Text::new(column),
+                                                            TextFont {
+                                                                font: font_assets.rajdhani_medium.clone(),
+                                                                font_size,
+                                                                ..default()
+                                                            },
+                                                            TextColor(if action == header.0 {
+                                                                Color::ELYSIUM_GOLD
+                                                            } else {
+                                                                Color::WHITE
+                                                            }),
+                                                        ));
+                                                    }
+                                                });
+                                        }
+                                    });
+
+                                stepper_widget(
+                                    content,
+                                    window_height,
+                                    font_assets.rajdhani_medium.clone(),
+                                    "Stick Deadzone",
+                                    stick_deadzone_label(&input_settings),
+                                    StickDeadzoneLabel,
+                                    72.0,
+                                    lower_stick_deadzone,
+                                    raise_stick_deadzone,
+                                );
+                            }
+                            SettingsTab::Graphics => {
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            shadows_label(&graphics_settings),
+                                            ShadowsToggleLabel,
+                                            4.0,
+                                            toggle_shadows,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            ground_shadows_label(&graphics_settings),
+                                            GroundShadowsToggleLabel,
+                                            18.0,
+                                            toggle_ground_shadows,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            shadow_quality_label(&graphics_settings),
+                                            ShadowQualityLabel,
+                                            32.0,
+                                            cycle_shadow_quality,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            day_night_cycle_label(&graphics_settings),
+                                            DayNightCycleLabel,
+                                            46.0,
+                                            toggle_day_night_cycle,
+                                        );
+
+                                        stepper_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            "Field of View",
+                                            fov_label(&graphics_settings),
+                                            FovLabel,
+                                            62.0,
+                                            lower_fov,
+                                            raise_fov,
+                                        );
+
+                                        stepper_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            "Render Distance",
+                                            render_distance_label(&graphics_settings),
+                                            RenderDistanceLabel,
+                                            78.0,
+                                            lower_render_distance,
+                                            raise_render_distance,
+                                        );
+                                    });
+                            }
+                            SettingsTab::Window => {
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        stepper_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            "UI Scale",
+                                            ui_scale_label(&hud_settings),
+                                            UiScaleLabel,
+                                            12.0,
+                                            lower_ui_scale,
+                                            raise_ui_scale,
+                                        );
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            colorblind_mode_label(&hud_settings),
+                                            ColorblindModeLabel,
+                                            28.0,
+                                            cycle_colorblind_mode,
+                                        );
+                                    });
+                            }
+                            SettingsTab::Game => {
+                                content
+                                    .spawn((Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },))
+                                    .with_children(|content| {
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            autosave_label(&autosave_settings),
+                                            AutosaveToggleLabel,
+                                            6.0,
+                                            toggle_autosave,
+                                        );
+
+                                        stepper_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            "Autosave Interval",
+                                            autosave_interval_label(&autosave_settings),
+                                            AutosaveIntervalLabel,
+                                            21.0,
+                                            lower_autosave_interval,
+                                            raise_autosave_interval,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            completed_objectives_label(&hud_settings),
+                                            CompletedObjectivesLabel,
+                                            36.0,
+                                            toggle_completed_objectives,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            compass_label(&hud_settings),
+                                            CompassToggleLabel,
+                                            51.0,
+                                            toggle_compass,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            difficulty_label(&difficulty_settings),
+                                            DifficultyLabel,
+                                            66.0,
+                                            cycle_difficulty,
+                                        );
+
+                                        toggle_button_widget(
+                                            content,
+                                            window_height,
+                                            font_assets.rajdhani_medium.clone(),
+                                            language_label(&locale),
+                                            LanguageLabel,
+                                            81.0,
+                                            cycle_language,
+                                        );
+                                    });
+                            }
                         }
                     });
             });
@@ -253,7 +901,12 @@ struct SettingsScene;
 // ===== SETTINGS SCENE IMPLEMENTATION =====
 
 impl SettingsScene {
-    fn spawn(mut commands: Commands, ui_assets: Res<UiAssets>, font_assets: Res<FontAssets>) {
+    fn spawn(
+        mut commands: Commands,
+        ui_assets: Res<UiAssets>,
+        font_assets: Res<FontAssets>,
+        locale: Res<Locale>,
+    ) {
         // Create UI
         commands
             .spawn((
@@ -298,7 +951,8 @@ impl SettingsScene {
                             ]),
                         ))
                         .observe(hover_set::<Pointer<Over>, true>)
-                        .observe(hover_set::<Pointer<Out>, false>);
+                        .observe(hover_set::<Pointer<Out>, false>)
+                        .observe(select_previous_tab);
 
                         // Spawn right chevron
                         ui.spawn((
@@ -316,7 +970,8 @@ impl SettingsScene {
                             ]),
                         ))
                         .observe(hover_set::<Pointer<Over>, true>)
-                        .observe(hover_set::<Pointer<Out>, false>);
+                        .observe(hover_set::<Pointer<Out>, false>)
+                        .observe(select_next_tab);
 
                         // Spawn the control bar
                         ui.spawn((UiLayout::window()
@@ -324,12 +979,13 @@ impl SettingsScene {
                             .size(Rl((80.0, 100.0)))
                             .pack(),))
                             .with_children(|ui| {
-                                let categories = ["Controls", "Sound", "Graphics", "Window"];
+                                let categories = ["Controls", "Sound", "Graphics", "Window", "Game"];
                                 let categories_actions = [
                                     render_controls_settings,
                                     render_sound_settings,
                                     render_graphics_settings,
                                     render_windows_settings,
+                                    render_game_settings,
                                 ];
                                 let pos = 100.0 / categories.len() as f32;
                                 for (i, category) in categories.into_iter().enumerate() {
@@ -338,6 +994,7 @@ impl SettingsScene {
                                         "Sound" => SettingsTab::Sound,
                                         "Graphics" => SettingsTab::Graphics,
                                         "Window" => SettingsTab::Window,
+                                        "Game" => SettingsTab::Game,
                                         _ => continue,
                                     };
 
@@ -398,7 +1055,7 @@ impl SettingsScene {
                                                     ]),
                                                     UiHover::new().instant(true),
                                                     UiTextSize::from(Rh(50.0)),
-                                                    Text2d::new(category.to_ascii_uppercase()),
+                                                    Text2d::new(locale.t(tab_enum.label_key())),
                                                     TextFont {
                                                         font: font_assets.rajdhani_medium.clone(),
                                                         font_size: 64.0,
@@ -0,0 +1,339 @@
+//! Achievements, unlocked from gameplay events rather than from the systems
+//! that cause them. Progress is lifetime, kept in its own file next to the save
+//! game and written whenever the game saves, an achievement unlocks, the
+//! player leaves gameplay or the game quits.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::FontAssets;
+use crate::constants::achievements::{ACHIEVEMENTS_PATH, AchievementConfig};
+use crate::game::Health;
+use crate::resources::platform::{PlatformCapabilities, read_file, write_file};
+use crate::resources::shutdown::ShutdownSave;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::dojo::PickupItemEvent;
+use crate::systems::enemy_ai::EnemyDefeatedEvent;
+use crate::systems::level_exit::LevelCompleteEvent;
+use crate::systems::save::SaveGameEvent;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::ui::toast::{Toast, spawn_toast};
+
+// ===== COMPONENTS & RESOURCES =====
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AchievementId {
+    CoinCollector,
+    Flawless,
+    EnemySlayer,
+}
+
+impl AchievementId {
+    pub const ALL: [AchievementId; 3] = [
+        AchievementId::CoinCollector,
+        AchievementId::Flawless,
+        AchievementId::EnemySlayer,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            AchievementId::CoinCollector => "Coin Collector",
+            AchievementId::Flawless => "Flawless",
+            AchievementId::EnemySlayer => "Enemy Slayer",
+        }
+    }
+
+    pub fn description(self) -> String {
+        match self {
+            AchievementId::CoinCollector => {
+                format!("Collect {} coins", AchievementConfig::COIN_COLLECTOR_COINS)
+            }
+            AchievementId::Flawless => "Complete a level without taking damage".to_string(),
+            AchievementId::EnemySlayer => {
+                format!("Defeat {} enemies", AchievementConfig::ENEMY_SLAYER_KILLS)
+            }
+        }
+    }
+
+    /// Progress needed to unlock; one-off achievements need 1
+    pub fn target(self) -> u32 {
+        match self {
+            AchievementId::CoinCollector => AchievementConfig::COIN_COLLECTOR_COINS,
+            AchievementId::Flawless => 1,
+            AchievementId::EnemySlayer => AchievementConfig::ENEMY_SLAYER_KILLS,
+        }
+    }
+}
+
+/// Lifetime progress toward every achievement, and which ones are unlocked
+#[derive(Resource, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    progress: HashMap<AchievementId, u32>,
+    /// In unlock order
+    unlocked: Vec<AchievementId>,
+}
+
+impl Achievements {
+    pub fn progress(&self, id: AchievementId) -> u32 {
+        self.progress.get(&id).copied().unwrap_or(0)
+    }
+
+    pub fn is_unlocked(&self, id: AchievementId) -> bool {
+        self.unlocked.contains(&id)
+    }
+
+    pub fn unlocked(&self) -> impl Iterator<Item = AchievementId> + '_ {
+        self.unlocked.iter().copied()
+    }
+
+    /// Adds `amount` to `id`'s progress; true only on the call that unlocks it
+    pub fn add_progress(&mut self, id: AchievementId, amount: u32) -> bool {
+        if self.is_unlocked(id) {
+            return false;
+        }
+        let progress = self.progress.entry(id).or_insert(0);
+        *progress = progress.saturating_add(amount);
+        if !reaches_threshold(*progress, id.target()) {
+            return false;
+        }
+        self.unlocked.push(id);
+        true
+    }
+
+    /// Saved progress, or nothing unlocked when there's no file or no storage
+    fn load() -> Self {
+        read_file(ACHIEVEMENTS_PATH)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(e) = write_file(ACHIEVEMENTS_PATH, &contents) {
+                    error!("Failed to write {}: {}", ACHIEVEMENTS_PATH, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize achievements: {}", e),
+        }
+    }
+}
+
+pub fn reaches_threshold(progress: u32, target: u32) -> bool {
+    progress >= target
+}
+
+#[derive(Event, Debug)]
+pub struct AchievementUnlocked(pub AchievementId);
+
+/// Whether the player has lost health since the level (or its restart) began
+#[derive(Resource, Default)]
+struct LevelDamageTracker {
+    damaged: bool,
+    last_health: Option<u32>,
+}
+
+// ===== PLUGIN =====
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Achievements::load())
+            .init_resource::<LevelDamageTracker>()
+            .add_event::<AchievementUnlocked>()
+            .add_systems(OnEnter(Screen::GamePlay), reset_damage_tracker)
+            .add_systems(OnExit(Screen::GamePlay), persist_achievements)
+            .add_systems(
+                Update,
+                (
+                    (
+                        count_coin_pickups,
+                        count_enemy_defeats,
+                        track_damage_taken,
+                        check_flawless_level.after(track_damage_taken),
+                    ),
+                    announce_unlocks,
+                    save_achievements,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_observer(reset_damage_tracker_on_restart)
+            .add_observer(persist_achievements_on_shutdown);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_damage_tracker(mut tracker: ResMut<LevelDamageTracker>) {
+    *tracker = LevelDamageTracker::default();
+}
+
+fn reset_damage_tracker_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut tracker: ResMut<LevelDamageTracker>,
+) {
+    if trigger.0 == ConfirmAction::RestartLevel {
+        *tracker = LevelDamageTracker::default();
+    }
+}
+
+fn count_coin_pickups(
+    mut pickup_events: EventReader<PickupItemEvent>,
+    mut achievements: ResMut<Achievements>,
+    mut unlocked_events: EventWriter<AchievementUnlocked>,
+) {
+    let coins = pickup_events
+        .read()
+        .filter(|event| event.item_type == CollectibleType::Coin)
+        .count() as u32;
+    if coins > 0 && achievements.add_progress(AchievementId::CoinCollector, coins) {
+        unlocked_events.write(AchievementUnlocked(AchievementId::CoinCollector));
+    }
+}
+
+fn count_enemy_defeats(
+    mut defeated_events: EventReader<EnemyDefeatedEvent>,
+    mut achievements: ResMut<Achievements>,
+    mut unlocked_events: EventWriter<AchievementUnlocked>,
+) {
+    let kills = defeated_events.read().count() as u32;
+    if kills > 0 && achievements.add_progress(AchievementId::EnemySlayer, kills) {
+        unlocked_events.write(AchievementUnlocked(AchievementId::EnemySlayer));
+    }
+}
+
+fn track_damage_taken(
+    mut tracker: ResMut<LevelDamageTracker>,
+    player_query: Query<&Health, (With<CharacterController>, Changed<Health>)>,
+) {
+    let Ok(health) = player_query.single() else {
+        return;
+    };
+    if tracker
+        .last_health
+        .is_some_and(|last| health.current < last)
+    {
+        tracker.damaged = true;
+    }
+    tracker.last_health = Some(health.current);
+}
+
+fn check_flawless_level(
+    mut complete_events: EventReader<LevelCompleteEvent>,
+    tracker: Res<LevelDamageTracker>,
+    mut achievements: ResMut<Achievements>,
+    mut unlocked_events: EventWriter<AchievementUnlocked>,
+) {
+    if complete_events.read().last().is_none() || tracker.damaged {
+        return;
+    }
+    if achievements.add_progress(AchievementId::Flawless, 1) {
+        unlocked_events.write(AchievementUnlocked(AchievementId::Flawless));
+    }
+}
+
+fn announce_unlocks(
+    mut commands: Commands,
+    mut unlocked_events: EventReader<AchievementUnlocked>,
+    toast_query: Query<Entity, With<Toast>>,
+    font_assets: Res<FontAssets>,
+) {
+    // Toasts replace each other, so only the last unlock of a frame shows
+    if let Some(AchievementUnlocked(id)) = unlocked_events.read().last() {
+        info!("Achievement unlocked: {}", id.title());
+        spawn_toast(
+            &mut commands,
+            &font_assets,
+            &toast_query,
+            format!("Achievement unlocked: {}", id.title()),
+            Color::ELYSIUM_GOLD,
+        );
+    }
+}
+
+/// Writes progress alongside every game save and straight away on an unlock,
+/// rather than on every coin
+fn save_achievements(
+    mut save_events: EventReader<SaveGameEvent>,
+    mut unlocked_events: EventReader<AchievementUnlocked>,
+    achievements: Res<Achievements>,
+    capabilities: Res<PlatformCapabilities>,
+) {
+    let saving = save_events.read().count() > 0;
+    let unlocked = unlocked_events.read().count() > 0;
+    if (saving || unlocked) && capabilities.has_storage {
+        achievements.save();
+    }
+}
+
+fn persist_achievements(achievements: Res<Achievements>, capabilities: Res<PlatformCapabilities>) {
+    if capabilities.has_storage {
+        achievements.save();
+    }
+}
+
+/// Quitting skips `OnExit`, so progress since the last write is flushed here
+fn persist_achievements_on_shutdown(
+    _: Trigger<ShutdownSave>,
+    achievements: Res<Achievements>,
+    capabilities: Res<PlatformCapabilities>,
+) {
+    if capabilities.has_storage {
+        achievements.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_is_reached_at_the_target() {
+        assert!(!reaches_threshold(9, 10));
+        assert!(reaches_threshold(10, 10));
+        assert!(reaches_threshold(11, 10));
+    }
+
+    #[test]
+    fn achievement_unlocks_once_progress_hits_its_target() {
+        let mut achievements = Achievements::default();
+        let target = AchievementId::CoinCollector.target();
+        assert!(!achievements.add_progress(AchievementId::CoinCollector, target - 1));
+        assert!(!achievements.is_unlocked(AchievementId::CoinCollector));
+
+        assert!(achievements.add_progress(AchievementId::CoinCollector, 1));
+        assert!(achievements.is_unlocked(AchievementId::CoinCollector));
+        // Already unlocked, so later progress neither re-unlocks nor counts
+        assert!(!achievements.add_progress(AchievementId::CoinCollector, 5));
+        assert_eq!(achievements.progress(AchievementId::CoinCollector), target);
+    }
+
+    #[test]
+    fn one_off_achievements_unlock_on_the_first_event() {
+        let mut achievements = Achievements::default();
+        assert!(achievements.add_progress(AchievementId::Flawless, 1));
+        assert_eq!(achievements.unlocked().collect::<Vec<_>>(), vec![AchievementId::Flawless]);
+    }
+
+    #[test]
+    fn unlocks_survive_a_save_round_trip() {
+        let mut achievements = Achievements::default();
+        achievements.add_progress(AchievementId::Flawless, 1);
+        achievements.add_progress(AchievementId::EnemySlayer, 3);
+
+        let json = serde_json::to_string(&achievements).unwrap();
+        let restored: Achievements = serde_json::from_str(&json).unwrap();
+        assert!(restored.is_unlocked(AchievementId::Flawless));
+        assert_eq!(restored.progress(AchievementId::EnemySlayer), 3);
+        assert!(!restored.is_unlocked(AchievementId::EnemySlayer));
+    }
+}
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
 use crate::assets::ModelAssets;
-use crate::ui::dialog::{spawn_dialog_with_proximity, DialogConfig, DialogPosition, DialogProximity};
+use crate::ui::dialog::Interactable;
 
 // ===== COMPONENTS =====
 
@@ -24,30 +24,13 @@ pub fn spawn_book(
         },
         Collider::cuboid(1.0, 1.0, 1.0), // Add collision box
         RigidBody::Static, // Make it static so it doesn't move
-        Book,
-    ));
-}
-
-pub fn spawn_book_modal(
-    mut commands: Commands,
-    font_assets: Res<crate::assets::FontAssets>,
-    windows: Query<&Window>,
-) {
-    spawn_dialog_with_proximity(
-        &mut commands,
-        &font_assets,
-        windows,
-        DialogConfig {
-            text: "Press E to Burn Items to Enter Realm".to_string(),
-            position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
-            ..Default::default()
+        Interactable {
+            action: "Press E to Burn Items to Enter Realm".to_string(),
+            range: 5.0,
         },
+        Book,
         crate::screens::gameplay::PlayingScene,
-        Some(DialogProximity {
-            target_position: Vec3::new(90.0, 22.0, -54.0),
-            proximity_threshold: 5.0,
-        }),
-    );
+    ));
 }
 
 // ===== PLUGIN =====
@@ -56,7 +39,6 @@ pub struct BookInteractionPlugin;
 
 impl Plugin for BookInteractionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(crate::screens::Screen::GamePlay), spawn_book)
-            .add_systems(OnEnter(crate::screens::Screen::GamePlay), spawn_book_modal);
+        app.add_systems(OnEnter(crate::screens::Screen::GamePlay), spawn_book);
     }
 } 
\ No newline at end of file
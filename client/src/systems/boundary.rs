@@ -10,7 +10,9 @@
 //! - Collectible spawning boundary constraints
 //! 
 //! ## Boundary Dimensions
-//! Based on navigation data analysis, the playable area is defined as:
+//! The playable area is derived from the `min_bounds`/`max_bounds` recorded in
+//! `nav.json` once navigation data is loaded, kept `WALL_MARGIN` inside the walls.
+//! Without navigation data it falls back to the hand-measured area:
 //! - X: -40.0 to 285.0 (325 units wide)
 //! - Z: -130.0 to 200.0 (330 units deep)
 //! - Y: No vertical constraints (player can jump/fall naturally)
@@ -21,12 +23,13 @@
 //! 
 //! ## Components
 //! - `BoundaryWall`: Marker for boundary wall entities
-//! - `BoundaryConstraint`: Resource defining boundary limits
+//! - `BoundaryConstraint`: Resource defining boundary limits (always present)
 
 use bevy::prelude::*;
 use avian3d::prelude::*;
 // Mesh3d and MeshMaterial3d are re-exported in prelude in Bevy 0.16
 use crate::constants::boundary::BoundaryConstants;
+use crate::systems::collectibles::NavigationStats;
 
 /// Marker component for boundary walls
 #[derive(Component)]
@@ -57,12 +60,49 @@ impl Default for BoundaryConstraint {
     }
 }
 
+impl BoundaryConstraint {
+    /// Builds the walkable area from recorded navigation bounds, keeping `margin`
+    /// units away from the outermost recorded positions. Returns `None` when the
+    /// statistics don't describe a usable area (e.g. no points were recorded).
+    pub fn from_navigation_stats(stats: &NavigationStats, margin: f32) -> Option<Self> {
+        let [min_x, _, min_z] = stats.min_bounds;
+        let [max_x, _, max_z] = stats.max_bounds;
+        let constraint = Self {
+            min_x: min_x + margin,
+            max_x: max_x - margin,
+            min_z: min_z + margin,
+            max_z: max_z - margin,
+        };
+
+        let finite = [constraint.min_x, constraint.max_x, constraint.min_z, constraint.max_z]
+            .iter()
+            .all(|value| value.is_finite());
+        (finite && constraint.min_x < constraint.max_x && constraint.min_z < constraint.max_z)
+            .then_some(constraint)
+    }
+
+    /// Whether a position lies inside the walkable area (Y is ignored)
+    pub fn contains(&self, position: Vec3) -> bool {
+        position.x >= self.min_x
+            && position.x <= self.max_x
+            && position.z >= self.min_z
+            && position.z <= self.max_z
+    }
+
+    /// Center of the walkable area on the XZ plane
+    pub fn center(&self) -> Vec2 {
+        Vec2::new((self.min_x + self.max_x) / 2.0, (self.min_z + self.max_z) / 2.0)
+    }
+}
+
 /// Plugin for boundary systems
 pub struct BoundaryPlugin;
 
 impl Plugin for BoundaryPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        // Present from startup so loading can overwrite it with navigation-derived bounds
+        app.init_resource::<BoundaryConstraint>()
+        .add_systems(
             OnEnter(crate::screens::Screen::GamePlay),
             spawn_boundary_walls,
         )
@@ -82,20 +122,18 @@ impl Plugin for BoundaryPlugin {
 /// - West wall (negative X)
 /// 
 /// Each wall has a collision box for physics but no visual representation.
+/// Walls sit `WALL_MARGIN` outside the walkable area so nothing ends up
+/// standing exactly against them.
 fn spawn_boundary_walls(
     mut commands: Commands,
+    constraint: Res<BoundaryConstraint>,
 ) {
-    let constraint = BoundaryConstraint::default();
-    
-    // Store boundary values before moving the constraint
-    let min_x = constraint.min_x;
-    let max_x = constraint.max_x;
-    let min_z = constraint.min_z;
-    let max_z = constraint.max_z;
+    let margin = BoundaryConstants::WALL_MARGIN;
+    let center = constraint.center();
     
-    // Calculate wall dimensions and positions more accurately
-    let world_width = max_x - min_x;
-    let world_depth = max_z - min_z;
+    // Calculate wall dimensions and positions from the walkable area plus margin
+    let world_width = (constraint.max_x - constraint.min_x) + margin * 2.0;
+    let world_depth = (constraint.max_z - constraint.min_z) + margin * 2.0;
     let wall_thickness = 2.0;
     let wall_height = 24.0; // tall enough to cover above player
     let ground_y = -1.5; // environment ground offset
@@ -107,8 +145,9 @@ fn spawn_boundary_walls(
         BoundaryWall,
         RigidBody::Static,
         Collider::cuboid(world_width / 2.0, wall_height / 2.0, wall_thickness / 2.0),
-        Transform::from_xyz(BoundaryConstants::WORLD_CENTER_X, y_center, BoundaryConstants::WORLD_CENTER_Z + world_depth / 2.0),
+        Transform::from_xyz(center.x, y_center, center.y + world_depth / 2.0),
         GlobalTransform::default(),
+        crate::screens::gameplay::PlayingScene,
     ));
 
     // South wall (negative Z) - spans the full width
@@ -116,8 +155,9 @@ fn spawn_boundary_walls(
         BoundaryWall,
         RigidBody::Static,
         Collider::cuboid(world_width / 2.0, wall_height / 2.0, wall_thickness / 2.0),
-        Transform::from_xyz(BoundaryConstants::WORLD_CENTER_X, y_center, BoundaryConstants::WORLD_CENTER_Z - world_depth / 2.0),
+        Transform::from_xyz(center.x, y_center, center.y - world_depth / 2.0),
         GlobalTransform::default(),
+        crate::screens::gameplay::PlayingScene,
     ));
 
     // East wall (positive X) - spans the full depth
@@ -125,8 +165,9 @@ fn spawn_boundary_walls(
         BoundaryWall,
         RigidBody::Static,
         Collider::cuboid(wall_thickness / 2.0, wall_height / 2.0, world_depth / 2.0),
-        Transform::from_xyz(BoundaryConstants::WORLD_CENTER_X + world_width / 2.0, y_center, BoundaryConstants::WORLD_CENTER_Z),
+        Transform::from_xyz(center.x + world_width / 2.0, y_center, center.y),
         GlobalTransform::default(),
+        crate::screens::gameplay::PlayingScene,
     ));
 
     // West wall (negative X) - spans the full depth
@@ -134,8 +175,9 @@ fn spawn_boundary_walls(
         BoundaryWall,
         RigidBody::Static,
         Collider::cuboid(wall_thickness / 2.0, wall_height / 2.0, world_depth / 2.0),
-        Transform::from_xyz(BoundaryConstants::WORLD_CENTER_X - world_width / 2.0, y_center, BoundaryConstants::WORLD_CENTER_Z),
+        Transform::from_xyz(center.x - world_width / 2.0, y_center, center.y),
         GlobalTransform::default(),
+        crate::screens::gameplay::PlayingScene,
     ));
 
     // Safety floor to prevent falling through gaps
@@ -143,9 +185,9 @@ fn spawn_boundary_walls(
     commands.spawn((
         Name::new("Boundary Safety Floor"),
         Transform::from_xyz(
-            BoundaryConstants::WORLD_CENTER_X,
+            center.x,
             ground_y - (floor_thickness / 2.0), // Top of the floor aligns with ground_y
-            BoundaryConstants::WORLD_CENTER_Z,
+            center.y,
         ),
         Collider::cuboid(
             world_width / 2.0,  // Half the world width
@@ -156,9 +198,6 @@ fn spawn_boundary_walls(
         BoundaryWall,
         crate::screens::gameplay::PlayingScene,
     ));
-
-    // Add the boundary constraint as a resource
-    commands.insert_resource(constraint);
 }
 
 /// Constrains player movement to stay within boundaries
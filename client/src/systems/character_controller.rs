@@ -1,8 +1,13 @@
-use crate::constants::movement::{CharacterAnimationConfig, CharacterMovementConfig};
+use crate::constants::combat::CombatConfig;
+use crate::constants::movement::{CharacterAnimationConfig, CharacterMovementConfig, StaminaConfig};
 use avian3d::{math::*, prelude::*};
 use bevy::prelude::*;
 use bevy_gltf_animation::prelude::*;
 use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::combat::FightMoveKind;
+use crate::keybinding::is_stick_held;
+use crate::resources::input::InputSettings;
+use crate::ui::touch_controls::VirtualJoystick;
 
 pub struct CharacterControllerPlugin;
 
@@ -10,10 +15,14 @@ impl Plugin for CharacterControllerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<LastInputDirection>()
             .init_resource::<JumpCooldown>()
+            .init_resource::<MovementInputHeld>()
+            .init_resource::<MovementTuning>()
+            .init_resource::<FightMoveBuffer>()
             .add_event::<MovementAction>()
+            .add_event::<CharacterLanded>()
             .add_systems(
                 Update,
-                (movement, apply_movement_damping, update_animations).chain(),
+                (update_movement_input_held, sync_jump_impulse, sync_gravity_scale, update_grounded, apply_fall_damage, update_jump_state, movement, update_stair_stepping, apply_movement_damping, update_stamina, update_animations, update_stamina_bar).chain().run_if(crate::systems::health::player_alive),
             );
     }
 }
@@ -23,6 +32,8 @@ impl Plugin for CharacterControllerPlugin {
 pub enum MovementAction {
     Move(Vector2),
     Jump,
+    /// The jump input was let go; cuts the jump short if still rising
+    JumpReleased,
     FightMove1,
     FightMove2,
 }
@@ -31,16 +42,141 @@ pub enum MovementAction {
 #[derive(Component)]
 pub struct CharacterController;
 
-/// Component to track stair climbing state for smoother transitions
+/// A marker component indicating that the character's ground cast is touching the ground.
 #[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct Grounded;
+
+/// Component to track stair climbing state for smoother transitions
+#[derive(Component, Default)]
 pub struct StairClimbingState {
-    // Reserved for future stair climbing state tracking
+    /// Feet height of the ledge currently being stepped onto
+    pub target_height: Option<f32>,
+}
+
+impl StairClimbingState {
+    pub fn is_stepping(&self) -> bool {
+        self.target_height.is_some()
+    }
+}
+
+/// Whether a ledge this far above the feet is stepped onto rather than treated as a wall
+pub fn is_climbable_step(step_height: f32, max_step_height: f32) -> bool {
+    step_height > CharacterMovementConfig::MIN_STEP_HEIGHT && step_height <= max_step_height
+}
+
+/// Stamina spent by running and fight moves. Running dry blocks both until
+/// stamina recovers past `RECOVERY_THRESHOLD`.
+#[derive(Component, Debug, Clone)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    pub exhausted: bool,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self {
+            current: StaminaConfig::MAX_STAMINA,
+            max: StaminaConfig::MAX_STAMINA,
+            exhausted: false,
+        }
+    }
+}
+
+impl Stamina {
+    pub fn drain(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+        if self.current <= 0.0 {
+            self.exhausted = true;
+        }
+    }
+
+    pub fn regenerate(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+        if self.exhausted && self.current >= StaminaConfig::RECOVERY_THRESHOLD {
+            self.exhausted = false;
+        }
+    }
+
+    /// Spends `cost` if there's enough stamina, returning whether it was spent
+    pub fn try_spend(&mut self, cost: f32) -> bool {
+        if self.exhausted || self.current < cost {
+            return false;
+        }
+        self.drain(cost);
+        true
+    }
+
+    pub fn can_run(&self) -> bool {
+        !self.exhausted && self.current > 0.0
+    }
+
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 { self.current / self.max } else { 0.0 }
+    }
 }
 
 /// The strength of a jump.
 #[derive(Component)]
 pub struct JumpImpulse(pub Scalar);
 
+/// Ground contact history used for coyote time and variable jump height
+#[derive(Component, Debug, Default)]
+pub struct JumpState {
+    /// Seconds since the ground cast last hit; infinite once a jump has used up the ground
+    pub time_since_grounded: f32,
+    /// Rising from a jump whose height can still be cut by releasing the key
+    pub jumping: bool,
+}
+
+/// Fastest downward speed since the character left the ground
+#[derive(Component, Debug, Default)]
+pub struct FallState {
+    pub peak_fall_speed: f32,
+}
+
+/// Sent on the frame a character's ground cast finds the ground again
+#[derive(Event, Debug)]
+pub struct CharacterLanded {
+    pub entity: Entity,
+    /// Downward speed at impact, in units per second
+    pub impact_speed: f32,
+}
+
+/// Health lost for landing at `impact_speed`; nothing up to `safe_speed`, then
+/// `damage_per_speed` for every unit per second beyond it
+pub fn fall_damage(impact_speed: f32, safe_speed: f32, damage_per_speed: f32) -> u32 {
+    let excess = impact_speed - safe_speed;
+    if excess <= 0.0 {
+        return 0;
+    }
+    (excess * damage_per_speed).round() as u32
+}
+
+/// Whether a jump is still allowed this long after the character last touched the ground
+pub fn within_coyote_time(time_since_grounded: f32, coyote_time: f32) -> bool {
+    time_since_grounded <= coyote_time
+}
+
+/// Whether ground with this surface normal is shallow enough to walk up.
+/// Degenerate normals count as walkable so they never block movement.
+pub fn is_walkable_slope(normal: Vec3, max_slope_degrees: f32) -> bool {
+    let Some(normal) = normal.try_normalize() else {
+        return true;
+    };
+    normal.angle_between(Vec3::Y) <= max_slope_degrees.to_radians()
+}
+
+/// Vertical velocity after an early jump release; falling is never sped up or slowed
+pub fn cut_jump_velocity(vertical_velocity: f32, cut_multiplier: f32) -> f32 {
+    if vertical_velocity > 0.0 {
+        vertical_velocity * cut_multiplier
+    } else {
+        vertical_velocity
+    }
+}
+
 /// A bundle that contains the components needed for a basic
 /// kinematic character controller with animation support.
 #[derive(Bundle)]
@@ -53,6 +189,10 @@ pub struct CharacterControllerBundle {
     pub movement: MovementBundle,
     pub animation_state: AnimationState,
     pub stair_climbing_state: StairClimbingState,
+    pub stamina: Stamina,
+    pub health: crate::game::Health,
+    pub jump_state: JumpState,
+    pub fall_state: FallState,
 }
 
 /// A bundle that contains components for character movement.
@@ -71,7 +211,46 @@ impl MovementBundle {
 
 impl Default for MovementBundle {
     fn default() -> Self {
-        Self::new(CharacterMovementConfig::MOVEMENT_ACCELERATION, 0.9, 7.0)
+        Self::new(
+            CharacterMovementConfig::MOVEMENT_ACCELERATION,
+            0.9,
+            CharacterMovementConfig::JUMP_IMPULSE,
+        )
+    }
+}
+
+/// Live copy of the movement constants that the controller systems read, so feel can be
+/// tuned at runtime from the movement tuning panel. Defaults are the `CharacterMovementConfig` values.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct MovementTuning {
+    pub max_speed: f32,
+    pub max_run_speed: f32,
+    pub rotation_speed: f32,
+    pub acceleration: f32,
+    pub deceleration: f32,
+    pub air_resistance: f32,
+    pub jump_impulse: f32,
+    pub coyote_time: f32,
+    pub jump_cut_multiplier: f32,
+    pub gravity_scale: f32,
+    pub fall_damage: bool,
+}
+
+impl Default for MovementTuning {
+    fn default() -> Self {
+        Self {
+            max_speed: CharacterMovementConfig::MAX_SPEED,
+            max_run_speed: CharacterMovementConfig::MAX_RUN_SPEED,
+            rotation_speed: CharacterMovementConfig::ROTATION_SPEED,
+            acceleration: CharacterMovementConfig::MOVEMENT_ACCELERATION,
+            deceleration: CharacterMovementConfig::MOVEMENT_DECELERATION,
+            air_resistance: CharacterMovementConfig::AIR_RESISTANCE,
+            jump_impulse: CharacterMovementConfig::JUMP_IMPULSE,
+            coyote_time: CharacterMovementConfig::COYOTE_TIME,
+            jump_cut_multiplier: CharacterMovementConfig::JUMP_CUT_MULTIPLIER,
+            gravity_scale: CharacterMovementConfig::GRAVITY_SCALE,
+            fall_damage: CharacterMovementConfig::FALL_DAMAGE_ENABLED,
+        }
     }
 }
 
@@ -89,8 +268,9 @@ pub struct JumpCooldown {
 impl Default for JumpCooldown {
     fn default() -> Self {
         Self {
-            last_jump_time: 0.0,
-            cooldown_duration: 1.5, // 1.5 second cooldown
+            // Start out of cooldown so the first jump isn't blocked
+            last_jump_time: CharacterMovementConfig::JUMP_COOLDOWN,
+            cooldown_duration: CharacterMovementConfig::JUMP_COOLDOWN,
         }
     }
 }
@@ -106,57 +286,217 @@ const MOVEMENT_KEYS: [KeyCode; 8] = [
     KeyCode::ArrowRight,
 ];
 
+/// The last fight move pressed while another action was still playing, and when
+#[derive(Resource, Default, Debug)]
+pub struct FightMoveBuffer {
+    pending: Option<(FightMoveKind, f32)>,
+}
+
+impl FightMoveBuffer {
+    /// Queues `fight_move`, replacing anything older
+    pub fn press(&mut self, fight_move: FightMoveKind, now: f32) {
+        self.pending = Some((fight_move, now));
+    }
+
+    /// Takes the queued move if it was pressed within `window` seconds of `now`;
+    /// an expired one is discarded either way
+    pub fn take(&mut self, now: f32, window: f32) -> Option<FightMoveKind> {
+        self.pending.take().and_then(|(fight_move, pressed_at)| {
+            buffered_input_valid(pressed_at, now, window).then_some(fight_move)
+        })
+    }
+}
+
+pub fn buffered_input_valid(pressed_at: f32, now: f32, window: f32) -> bool {
+    now - pressed_at <= window
+}
+
+/// Whether the player is holding movement on any device: keyboard, touch joystick or gamepad stick
+#[derive(Resource, Default)]
+pub struct MovementInputHeld(pub bool);
+
+/// Refreshes [`MovementInputHeld`] before movement, damping and animations read it
+fn update_movement_input_held(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<VirtualJoystick>,
+    gamepads: Query<&Gamepad>,
+    input_settings: Res<InputSettings>,
+    mut held: ResMut<MovementInputHeld>,
+) {
+    let stick_held = gamepads
+        .iter()
+        .any(|gamepad| is_stick_held(gamepad.left_stick(), input_settings.stick_deadzone));
+    held.0 = keyboard.any_pressed(MOVEMENT_KEYS) || joystick.is_active() || stick_held;
+}
+
+/// Walk or run speed for a move input. Stick tilt scales it proportionally, while
+/// keyboard input always arrives at full length and so stays digital.
+fn target_move_speed(direction: Vector2, running: bool, tuning: &MovementTuning) -> Scalar {
+    let max_speed = if running {
+        tuning.max_run_speed
+    } else {
+        tuning.max_speed
+    };
+    max_speed * direction.length().min(1.0)
+}
+
+/// Adds or removes [`Grounded`] from the ground cast hits, reporting landings
+fn update_grounded(
+    mut commands: Commands,
+    mut query: Query<
+        (Entity, &ShapeHits, &LinearVelocity, Has<Grounded>, Option<&mut FallState>),
+        With<CharacterController>,
+    >,
+    mut landed_events: EventWriter<CharacterLanded>,
+) {
+    for (entity, hits, linear_velocity, was_grounded, fall_state) in &mut query {
+        // The cast still reaches the ground for a few frames after takeoff, so rising doesn't count
+        let is_grounded = !hits.is_empty() && linear_velocity.y <= 0.0;
+        if is_grounded && !was_grounded {
+            commands.entity(entity).insert(Grounded);
+        } else if !is_grounded && was_grounded {
+            commands.entity(entity).remove::<Grounded>();
+        }
+
+        let Some(mut fall_state) = fall_state else {
+            continue;
+        };
+        if !is_grounded {
+            fall_state.peak_fall_speed = fall_state.peak_fall_speed.max(-linear_velocity.y);
+        } else if !was_grounded {
+            // The cast reaches the ground just before the collider does, so this is the impact speed
+            landed_events.write(CharacterLanded {
+                entity,
+                impact_speed: fall_state.peak_fall_speed.max(-linear_velocity.y),
+            });
+            fall_state.peak_fall_speed = 0.0;
+        }
+    }
+}
+
+fn apply_fall_damage(
+    tuning: Res<MovementTuning>,
+    mut landed_events: EventReader<CharacterLanded>,
+    mut health_query: Query<&mut crate::game::Health, With<CharacterController>>,
+) {
+    for event in landed_events.read() {
+        if !tuning.fall_damage {
+            continue;
+        }
+        let damage = fall_damage(
+            event.impact_speed,
+            CharacterMovementConfig::SAFE_FALL_SPEED,
+            CharacterMovementConfig::FALL_DAMAGE_PER_SPEED,
+        );
+        if damage == 0 {
+            continue;
+        }
+        if let Ok(mut health) = health_query.get_mut(event.entity) {
+            info!("Fall damage {} at {:.1} units/s", damage, event.impact_speed);
+            health.take_damage(damage);
+        }
+    }
+}
+
+/// Applies the tuned gravity scale to character controllers, including newly spawned ones
+fn sync_gravity_scale(
+    tuning: Res<MovementTuning>,
+    mut query: Query<&mut GravityScale, With<CharacterController>>,
+) {
+    for mut gravity_scale in &mut query {
+        if (tuning.is_changed() || gravity_scale.is_added()) && gravity_scale.0 != tuning.gravity_scale {
+            gravity_scale.0 = tuning.gravity_scale;
+        }
+    }
+}
+
+/// Applies the tuned jump impulse to character controllers, including newly spawned ones
+fn sync_jump_impulse(
+    tuning: Res<MovementTuning>,
+    mut query: Query<&mut JumpImpulse, With<CharacterController>>,
+) {
+    for mut jump_impulse in &mut query {
+        if (tuning.is_changed() || jump_impulse.is_added()) && jump_impulse.0 != tuning.jump_impulse {
+            jump_impulse.0 = tuning.jump_impulse;
+        }
+    }
+}
+
+/// Tracks how long each character has been off the ground, for coyote time
+fn update_jump_state(
+    time: Res<Time>,
+    mut query: Query<(Has<Grounded>, &mut JumpState), With<CharacterController>>,
+) {
+    for (grounded, mut jump_state) in &mut query {
+        if grounded {
+            jump_state.time_since_grounded = 0.0;
+            jump_state.jumping = false;
+        } else {
+            jump_state.time_since_grounded += time.delta_secs();
+        }
+    }
+}
+
 /// Responds to [`MovementAction`] events and moves character controllers accordingly
 fn movement(
     time: Res<Time>,
     mut movement_event_reader: EventReader<MovementAction>,
+    mut fight_move_buffer: ResMut<FightMoveBuffer>,
     mut controllers: Query<(
         &JumpImpulse,
         &mut LinearVelocity,
         &mut Transform,
         &mut AnimationState,
+        &mut Stamina,
+        &mut JumpState,
     )>,
     mut jump_cooldown: ResMut<JumpCooldown>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    movement_input: Res<MovementInputHeld>,
+    tuning: Res<MovementTuning>,
     boundary_constraint: Option<Res<BoundaryConstraint>>,
 ) {
     let delta_time = time.delta_secs();
     jump_cooldown.last_jump_time += delta_time;
 
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed(MOVEMENT_KEYS);
+    // Check if any movement input is held
+    let is_movement_pressed = movement_input.0;
 
     for event in movement_event_reader.read() {
-        for (jump_impulse, mut linear_velocity, mut transform, mut animation_state) in
-            &mut controllers
+        for (
+            jump_impulse,
+            mut linear_velocity,
+            mut transform,
+            mut animation_state,
+            mut stamina,
+            mut jump_state,
+        ) in &mut controllers
         {
             match event {
                 MovementAction::Move(direction) => {
                     // Smooth rotation
                     if direction.x != 0.0 {
                         let target_rotation =
-                            -direction.x * CharacterMovementConfig::ROTATION_SPEED * delta_time;
+                            -direction.x * tuning.rotation_speed * delta_time;
                         transform.rotate_y(target_rotation);
                     }
 
                     // Get movement vectors
                     let forward = transform.forward();
                     let right = transform.right();
-                    let movement_direction = (forward * -direction.y) + (right * direction.x);
+                    // Direction only; the input magnitude is applied through the target speed
+                    let movement_direction =
+                        ((forward * -direction.y) + (right * direction.x)).normalize_or_zero();
 
-                    // Calculate target velocity
-                    let target_speed = if animation_state.forward_hold_time >= 3.0 {
-                        CharacterMovementConfig::MAX_RUN_SPEED * direction.length()
-                    } else {
-                        CharacterMovementConfig::MAX_SPEED * direction.length()
-                    };
+                    // Calculate target velocity (walk speed only once stamina runs out)
+                    let running = animation_state.forward_hold_time >= 3.0 && stamina.can_run();
+                    let target_speed = target_move_speed(*direction, running, &tuning);
                     let current_speed = Vec2::new(linear_velocity.x, linear_velocity.z).length();
 
                     // Smooth acceleration/deceleration
                     let acceleration = if target_speed > current_speed {
-                        CharacterMovementConfig::MOVEMENT_ACCELERATION
+                        tuning.acceleration
                     } else {
-                        CharacterMovementConfig::MOVEMENT_DECELERATION
+                        tuning.deceleration
                     };
 
                     // Apply movement with stability for running
@@ -199,7 +539,8 @@ fn movement(
                         linear_velocity.z = linear_velocity.z.lerp(target_velocity.z, acceleration * delta_time);
                     }
 
-                    // Update animation state
+                    // Update animation state; any tilt that actually moves the character counts
+                    // towards the run trigger, so a held analog stick still breaks into a run
                     let horizontal_speed = Vec2::new(linear_velocity.x, linear_velocity.z).length();
                     if horizontal_speed > 0.1 {
                         animation_state.forward_hold_time += delta_time;
@@ -208,26 +549,63 @@ fn movement(
                     }
                 }
                 MovementAction::Jump => {
-                    if jump_cooldown.last_jump_time >= jump_cooldown.cooldown_duration {
+                    // Ground contact gates the jump: `time_since_grounded` is zero while
+                    // `Grounded` and counts up from there. The cooldown only debounces presses.
+                    let on_ground = within_coyote_time(
+                        jump_state.time_since_grounded,
+                        tuning.coyote_time,
+                    );
+                    let can_jump = on_ground
+                        && jump_cooldown.last_jump_time >= jump_cooldown.cooldown_duration;
+                    if can_jump {
                         linear_velocity.y = jump_impulse.0;
                         jump_cooldown.last_jump_time = 0.0;
+                        // One jump per ground contact, even within the coyote window
+                        jump_state.time_since_grounded = f32::INFINITY;
+                        jump_state.jumping = true;
                     }
                 }
-                MovementAction::FightMove1 => {
-                    // Trigger fight move 1 animation
-                    animation_state.fight_move_1 = true;
+                MovementAction::JumpReleased => {
+                    if jump_state.jumping {
+                        linear_velocity.y = cut_jump_velocity(
+                            linear_velocity.y,
+                            tuning.jump_cut_multiplier,
+                        );
+                        jump_state.jumping = false;
+                    }
                 }
-                MovementAction::FightMove2 => {
-                    // Trigger fight move 2 animation
-                    animation_state.fight_move_2 = true;
+                MovementAction::FightMove1 | MovementAction::FightMove2 => {
+                    let fight_move = if matches!(event, MovementAction::FightMove1) {
+                        FightMoveKind::FightMove1
+                    } else {
+                        FightMoveKind::FightMove2
+                    };
+                    // Mid-move presses wait in the buffer instead of being dropped
+                    if animation_state.fight_move_active() {
+                        fight_move_buffer.press(fight_move, time.elapsed_secs());
+                    } else {
+                        start_fight_move(fight_move, &mut animation_state, &mut stamina);
+                    }
                 }
             }
         }
     }
 
+    // A move pressed just before the last one ended starts as soon as it has
+    for (_, _, _, mut animation_state, mut stamina, _) in &mut controllers {
+        if animation_state.fight_move_active() {
+            continue;
+        }
+        if let Some(fight_move) =
+            fight_move_buffer.take(time.elapsed_secs(), CombatConfig::FIGHT_MOVE_BUFFER_SECS)
+        {
+            start_fight_move(fight_move, &mut animation_state, &mut stamina);
+        }
+    }
+
     // If no movement keys are pressed, immediately stop movement
     if !is_movement_pressed {
-        for (_, mut linear_velocity, _, mut animation_state) in &mut controllers {
+        for (_, mut linear_velocity, _, mut animation_state, _, _) in &mut controllers {
             // Immediately stop horizontal movement
             linear_velocity.x = 0.0;
             linear_velocity.z = 0.0;
@@ -238,15 +616,126 @@ fn movement(
     }
 }
 
+/// Drains stamina while running and regenerates it otherwise
+fn update_stamina(
+    time: Res<Time>,
+    mut query: Query<(&LinearVelocity, &AnimationState, &mut Stamina), With<CharacterController>>,
+    tuning: Res<MovementTuning>,
+    difficulty: Res<crate::resources::difficulty::ActiveDifficulty>,
+) {
+    let delta_time = time.delta_secs();
+    let regen_per_second = StaminaConfig::REGEN_PER_SECOND * difficulty.modifiers().player_stamina_regen;
+
+    for (linear_velocity, animation_state, mut stamina) in &mut query {
+        let horizontal_speed = Vec2::new(linear_velocity.x, linear_velocity.z).length();
+        let is_running = stamina.can_run()
+            && animation_state.forward_hold_time >= CharacterMovementConfig::RUN_TRIGGER_HOLD_TIME
+            && horizontal_speed > tuning.max_speed;
+
+        if is_running {
+            stamina.drain(StaminaConfig::RUN_DRAIN_PER_SECOND * delta_time);
+        } else if stamina.current < stamina.max {
+            stamina.regenerate(regen_per_second * delta_time);
+        }
+    }
+}
+
+/// Keeps the HUD stamina bar in sync with the player's stamina
+fn update_stamina_bar(
+    stamina_query: Query<&Stamina, (With<CharacterController>, Changed<Stamina>)>,
+    mut bar_query: Query<&mut Node, With<crate::ui::widgets::StaminaBarFill>>,
+) {
+    let Ok(stamina) = stamina_query.single() else {
+        return;
+    };
+
+    for mut node in &mut bar_query {
+        node.width = Val::Percent(stamina.fraction() * 100.0);
+    }
+}
+
+/// Lifts grounded characters onto low ledges ahead of them, like stairs, a little each frame
+fn update_stair_stepping(
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    ignored_query: Query<(), With<crate::systems::collectibles::Collectible>>,
+    mut query: Query<
+        (Entity, &mut Transform, &mut LinearVelocity, &mut StairClimbingState, Has<Grounded>),
+        With<CharacterController>,
+    >,
+) {
+    for (entity, mut transform, mut linear_velocity, mut stair_state, grounded) in &mut query {
+        if let Some(target_height) = stair_state.target_height {
+            let step = CharacterMovementConfig::STEP_UP_SPEED * time.delta_secs();
+            transform.translation.y = (transform.translation.y + step).min(target_height);
+            linear_velocity.y = linear_velocity.y.max(0.0);
+            if transform.translation.y >= target_height {
+                stair_state.target_height = None;
+            }
+            continue;
+        }
+
+        let horizontal = Vec3::new(linear_velocity.x, 0.0, linear_velocity.z);
+        let Ok(direction) = Dir3::new(horizontal) else {
+            continue;
+        };
+        if !grounded || horizontal.length() < 0.1 {
+            continue;
+        }
+
+        let filter = SpatialQueryFilter::default().with_excluded_entities([entity]);
+        let predicate = |hit_entity: Entity| !ignored_query.contains(hit_entity);
+        let feet = transform.translation;
+
+        // Something in the way just above the feet...
+        let ankle = feet + Vec3::Y * (CharacterMovementConfig::MIN_STEP_HEIGHT * 2.0);
+        let Some(front_hit) = spatial_query.cast_ray_predicate(
+            ankle,
+            direction,
+            CharacterMovementConfig::STEP_PROBE_DISTANCE,
+            true,
+            &filter,
+            &predicate,
+        ) else {
+            continue;
+        };
+
+        // ...with a walkable top no higher than a step
+        let probe_height = CharacterMovementConfig::MAX_STEP_HEIGHT + 0.05;
+        let top_origin =
+            feet + *direction * (front_hit.distance + 0.05) + Vec3::Y * probe_height;
+        let Some(top_hit) = spatial_query.cast_ray_predicate(
+            top_origin,
+            Dir3::NEG_Y,
+            probe_height,
+            true,
+            &filter,
+            &predicate,
+        ) else {
+            continue;
+        };
+        let step_height = probe_height - top_hit.distance;
+        if is_climbable_step(step_height, CharacterMovementConfig::MAX_STEP_HEIGHT)
+            && is_walkable_slope(top_hit.normal, CharacterMovementConfig::MAX_WALKABLE_SLOPE_DEGREES)
+        {
+            stair_state.target_height = Some(feet.y + step_height);
+        }
+    }
+}
+
 /// Applies movement damping and prevents unwanted climbing
 fn apply_movement_damping(
-    mut query: Query<(&mut LinearVelocity, &AnimationState, &Transform), With<CharacterController>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    mut query: Query<
+        (&mut LinearVelocity, &AnimationState, &ShapeHits, &JumpState, &StairClimbingState),
+        With<CharacterController>,
+    >,
+    movement_input: Res<MovementInputHeld>,
+    tuning: Res<MovementTuning>,
 ) {
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed(MOVEMENT_KEYS);
+    // Check if any movement input is held
+    let is_movement_pressed = movement_input.0;
 
-    for (mut linear_velocity, animation_state, _transform) in &mut query {
+    for (mut linear_velocity, animation_state, hits, jump_state, stair_state) in &mut query {
         // If no movement keys are pressed, immediately stop horizontal movement
         if !is_movement_pressed {
             linear_velocity.x = 0.0;
@@ -254,27 +743,44 @@ fn apply_movement_damping(
             continue;
         }
 
-        // Check for unwanted climbing behavior
-        let horizontal_speed = Vec2::new(linear_velocity.x, linear_velocity.z).length();
-        let is_moving_horizontally = horizontal_speed > 0.1;
-        let is_rising_gradually = linear_velocity.y > 0.1 && linear_velocity.y < 2.0;
-
-        // If moving horizontally and rising gradually, this is likely unwanted climbing
-        if is_moving_horizontally && is_rising_gradually {
-            // Reduce the climbing effect
-            linear_velocity.y *= 0.1;
-            // Also reduce horizontal movement slightly to prevent getting stuck
-            linear_velocity.x *= 0.8;
-            linear_velocity.z *= 0.8;
+        // Only standing on nothing but steep surfaces counts as climbing; ramps, stairs
+        // and jumps rise freely
+        let mut steep_normal = None;
+        let mut on_walkable_ground = false;
+        for hit in hits.iter() {
+            let normal = -hit.normal2;
+            if is_walkable_slope(normal, CharacterMovementConfig::MAX_WALKABLE_SLOPE_DEGREES) {
+                on_walkable_ground = true;
+            } else {
+                steep_normal = Some(normal);
+            }
+        }
+
+        // A step's riser shows up as steep, so an in-progress step up is left alone
+        let climbing_freely = on_walkable_ground || jump_state.jumping || stair_state.is_stepping();
+        if let (Some(normal), false) = (steep_normal, climbing_freely) {
+            // Don't let the physics push the character up the wall
+            if linear_velocity.y > 0.0 {
+                linear_velocity.y = 0.0;
+            }
+            // Drop the part of the horizontal velocity that runs into the wall, keeping the slide along it
+            let wall_out = Vec2::new(normal.x, normal.z).normalize_or_zero();
+            let horizontal = Vec2::new(linear_velocity.x, linear_velocity.z);
+            let into_wall = horizontal.dot(wall_out);
+            if into_wall < 0.0 {
+                let slide = horizontal - wall_out * into_wall;
+                linear_velocity.x = slide.x;
+                linear_velocity.z = slide.y;
+            }
         }
 
         // Apply different damping based on movement state
         let damping_factor = if animation_state.forward_hold_time >= 3.0 {
             // More stable damping for running
-            CharacterMovementConfig::AIR_RESISTANCE * 0.95
+            tuning.air_resistance * 0.95
         } else {
             // Normal damping for walking
-            CharacterMovementConfig::AIR_RESISTANCE
+            tuning.air_resistance
         };
 
         // Apply air resistance
@@ -299,19 +805,41 @@ pub struct AnimationState {
     pub fight_move_2: bool,
 }
 
+impl AnimationState {
+    pub fn fight_move_active(&self) -> bool {
+        self.fight_move_1 || self.fight_move_2
+    }
+}
+
+/// Triggers the fight move's animation, if there's stamina for it
+fn start_fight_move(
+    fight_move: FightMoveKind,
+    animation_state: &mut AnimationState,
+    stamina: &mut Stamina,
+) {
+    if !stamina.try_spend(StaminaConfig::FIGHT_MOVE_COST) {
+        return;
+    }
+    match fight_move {
+        FightMoveKind::FightMove1 => animation_state.fight_move_1 = true,
+        FightMoveKind::FightMove2 => animation_state.fight_move_2 = true,
+    }
+}
+
 /// Updates animations based on character movement
 fn update_animations(
     mut query: Query<
-        (&LinearVelocity, &mut GltfAnimations, &mut AnimationState),
+        (&LinearVelocity, &mut GltfAnimations, &mut AnimationState, Option<&Stamina>),
         Without<crate::systems::enemy_ai::Enemy>,
     >,
     mut animation_players: Query<&mut AnimationPlayer>,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    movement_input: Res<MovementInputHeld>,
 ) {
-    // Check if any movement keys are pressed
-    let is_movement_pressed = keyboard.any_pressed(MOVEMENT_KEYS);
+    // Check if any movement input is held
+    let is_movement_pressed = movement_input.0;
 
-    for (velocity, mut animations, mut animation_state) in &mut query {
+    for (velocity, mut animations, mut animation_state, stamina) in &mut query {
+        let can_run = stamina.is_none_or(|stamina| stamina.can_run());
         let horizontal_velocity = Vec2::new(velocity.x, velocity.z);
         let is_moving = horizontal_velocity.length() > 0.1;
 
@@ -360,6 +888,7 @@ fn update_animations(
                 CharacterAnimationConfig::IDLE // Idle - immediately when no input or no movement
             } else if animation_state.forward_hold_time
                 >= CharacterMovementConfig::RUN_TRIGGER_HOLD_TIME
+                && can_run
             {
                 CharacterAnimationConfig::RUNNING // Running
             } else {
@@ -422,14 +951,177 @@ impl CharacterControllerBundle {
             )
             .with_max_distance(CharacterMovementConfig::GROUND_SNAP_DISTANCE + 0.1),
             locked_axes: LockedAxes::ROTATION_LOCKED,
-            movement: MovementBundle::new(CharacterMovementConfig::MOVEMENT_ACCELERATION, 0.9, 7.0),
+            movement: MovementBundle::new(
+                CharacterMovementConfig::MOVEMENT_ACCELERATION,
+                0.9,
+                CharacterMovementConfig::JUMP_IMPULSE,
+            ),
             animation_state: AnimationState {
                 forward_hold_time: 0.0,
                 current_animation: CharacterAnimationConfig::IDLE, // Start with idle animation
                 fight_move_1: false,
                 fight_move_2: false,
             },
-            stair_climbing_state: StairClimbingState {},
+            stair_climbing_state: StairClimbingState::default(),
+            stamina: Stamina::default(),
+            health: crate::game::Health::new(CombatConfig::PLAYER_MAX_HEALTH),
+            jump_state: JumpState::default(),
+            fall_state: FallState::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_dry_exhausts_stamina() {
+        let mut stamina = Stamina::default();
+        stamina.drain(StaminaConfig::MAX_STAMINA * 2.0);
+        assert_eq!(stamina.current, 0.0);
+        assert!(stamina.exhausted);
+        assert!(!stamina.can_run());
+    }
+
+    #[test]
+    fn exhaustion_lasts_until_the_recovery_threshold() {
+        let mut stamina = Stamina::default();
+        stamina.drain(StaminaConfig::MAX_STAMINA);
+        stamina.regenerate(StaminaConfig::RECOVERY_THRESHOLD - 1.0);
+        assert!(!stamina.can_run());
+        stamina.regenerate(1.0);
+        assert!(stamina.can_run());
+    }
+
+    #[test]
+    fn regeneration_stops_at_max() {
+        let mut stamina = Stamina::default();
+        stamina.drain(10.0);
+        stamina.regenerate(50.0);
+        assert_eq!(stamina.current, stamina.max);
+    }
+
+    #[test]
+    fn fight_moves_need_enough_stamina() {
+        let mut stamina = Stamina {
+            current: StaminaConfig::FIGHT_MOVE_COST - 1.0,
+            ..default()
+        };
+        assert!(!stamina.try_spend(StaminaConfig::FIGHT_MOVE_COST));
+        assert_eq!(stamina.current, StaminaConfig::FIGHT_MOVE_COST - 1.0);
+
+        stamina.current = StaminaConfig::FIGHT_MOVE_COST;
+        assert!(stamina.try_spend(StaminaConfig::FIGHT_MOVE_COST));
+        assert_eq!(stamina.current, 0.0);
+        // Spending the last of it exhausts, so the next swing waits for recovery
+        assert!(!stamina.try_spend(0.0));
+    }
+
+    #[test]
+    fn half_stick_tilt_walks_at_half_speed() {
+        let tuning = MovementTuning::default();
+        let speed = target_move_speed(Vector2::new(0.0, 0.5), false, &tuning);
+        assert!((speed - tuning.max_speed * 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn keyboard_input_moves_at_full_speed() {
+        let tuning = MovementTuning::default();
+        assert_eq!(target_move_speed(Vector2::new(0.0, 1.0), false, &tuning), tuning.max_speed);
+        assert_eq!(target_move_speed(Vector2::new(0.0, 1.0), true, &tuning), tuning.max_run_speed);
+    }
+
+    #[test]
+    fn overlong_input_never_exceeds_max_speed() {
+        let tuning = MovementTuning::default();
+        assert_eq!(target_move_speed(Vector2::new(1.0, 1.0), false, &tuning), tuning.max_speed);
+    }
+
+    #[test]
+    fn jumps_stay_allowed_through_the_coyote_window() {
+        let coyote = CharacterMovementConfig::COYOTE_TIME;
+        assert!(within_coyote_time(0.0, coyote));
+        assert!(within_coyote_time(coyote, coyote));
+        assert!(!within_coyote_time(coyote + 0.01, coyote));
+    }
+
+    #[test]
+    fn releasing_jump_early_cuts_only_upward_velocity() {
+        assert_eq!(cut_jump_velocity(8.0, 0.5), 4.0);
+        assert_eq!(cut_jump_velocity(-3.0, 0.5), -3.0);
+        assert_eq!(cut_jump_velocity(0.0, 0.5), 0.0);
+    }
+
+    #[test]
+    fn shallow_slopes_are_walkable_and_walls_are_not() {
+        assert!(is_walkable_slope(Vec3::Y, 45.0));
+        // 30 degree ramp
+        assert!(is_walkable_slope(Vec3::new(0.5, 0.866, 0.0), 45.0));
+        // 60 degree incline
+        assert!(!is_walkable_slope(Vec3::new(0.866, 0.5, 0.0), 45.0));
+        assert!(!is_walkable_slope(Vec3::X, 45.0));
+    }
+
+    #[test]
+    fn slope_classification_ignores_normal_length() {
+        assert!(is_walkable_slope(Vec3::new(1.0, 1.0, 0.0) * 10.0, 45.1));
+        assert!(is_walkable_slope(Vec3::ZERO, 45.0));
+    }
+
+    #[test]
+    fn steps_up_to_the_max_height_are_climbable() {
+        let max = CharacterMovementConfig::MAX_STEP_HEIGHT;
+        assert!(is_climbable_step(max * 0.5, max));
+        assert!(is_climbable_step(max, max));
+        assert!(!is_climbable_step(max + 0.01, max));
+    }
+
+    #[test]
+    fn floor_noise_is_not_a_step() {
+        let max = CharacterMovementConfig::MAX_STEP_HEIGHT;
+        assert!(!is_climbable_step(0.0, max));
+        assert!(!is_climbable_step(CharacterMovementConfig::MIN_STEP_HEIGHT, max));
+    }
+
+    #[test]
+    fn landings_at_or_below_the_safe_speed_deal_no_damage() {
+        assert_eq!(fall_damage(0.0, 12.0, 4.0), 0);
+        assert_eq!(fall_damage(12.0, 12.0, 4.0), 0);
+    }
+
+    #[test]
+    fn fall_damage_scales_with_speed_above_the_safe_threshold() {
+        assert_eq!(fall_damage(13.0, 12.0, 4.0), 4);
+        assert_eq!(fall_damage(17.0, 12.0, 4.0), 20);
+        assert_eq!(fall_damage(12.1, 12.0, 4.0), 0);
+    }
+
+    #[test]
+    fn buffered_fight_move_registers_within_the_window() {
+        let window = CombatConfig::FIGHT_MOVE_BUFFER_SECS;
+        let mut buffer = FightMoveBuffer::default();
+        buffer.press(FightMoveKind::FightMove1, 1.0);
+        assert_eq!(buffer.take(1.0 + window * 0.5, window), Some(FightMoveKind::FightMove1));
+        // Taking consumes it
+        assert_eq!(buffer.take(1.0 + window * 0.5, window), None);
+    }
+
+    #[test]
+    fn expired_fight_move_is_discarded() {
+        let window = CombatConfig::FIGHT_MOVE_BUFFER_SECS;
+        let mut buffer = FightMoveBuffer::default();
+        buffer.press(FightMoveKind::FightMove2, 1.0);
+        assert_eq!(buffer.take(1.0 + window + 0.01, window), None);
+        assert!(!buffered_input_valid(1.0, 1.0 + window + 0.01, window));
+        assert!(buffered_input_valid(1.0, 1.0 + window, window));
+    }
+
+    #[test]
+    fn a_newer_press_replaces_the_buffered_one() {
+        let mut buffer = FightMoveBuffer::default();
+        buffer.press(FightMoveKind::FightMove1, 1.0);
+        buffer.press(FightMoveKind::FightMove2, 1.05);
+        assert_eq!(buffer.take(1.1, 0.15), Some(FightMoveKind::FightMove2));
+    }
+}
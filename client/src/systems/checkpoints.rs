@@ -0,0 +1,269 @@
+//! Checkpoints from the level data. Walking into one records where the player
+//! got to and what they had collected, and restarting the level (including
+//! Retry after dying) picks up from there instead of the spawn point.
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::assets::FontAssets;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::{CoinStreamingManager, CollectibleProgressTracker, PlayerStats};
+use crate::systems::level_data::{LevelData, PlayerSpawn};
+use crate::systems::level_exit::LevelCompleteEvent;
+use crate::systems::objectives::{Objective, ObjectiveManager};
+use crate::ui::inventory::{InventoryItem, InventoryItemSnapshot, InventorySlot};
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::ui::toast::{Toast, spawn_toast};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Trigger zone for the level data checkpoint at `index`
+#[derive(Component)]
+pub struct Checkpoint {
+    pub index: usize,
+    pub radius: f32,
+}
+
+/// What the player had when they reached a checkpoint
+#[derive(Debug, Clone)]
+pub struct CheckpointSnapshot {
+    #[allow(dead_code)] // For a checkpoint list in the pause menu
+    pub index: usize,
+    pub position: Vec3,
+    pub progress: CollectibleProgressTracker,
+    pub player_stats: PlayerStats,
+    /// Coin positions already picked up, so a restart doesn't respawn them
+    pub collected_coins: HashSet<usize>,
+    pub objectives: Vec<Objective>,
+    pub inventory: Vec<InventoryItemSnapshot>,
+}
+
+/// Checkpoints reached this run; cleared on finishing or leaving the level,
+/// but not on restarting it
+#[derive(Resource, Debug, Default)]
+pub struct CheckpointState {
+    pub activated: Vec<usize>,
+    pub last: Option<CheckpointSnapshot>,
+}
+
+impl CheckpointState {
+    /// Records reaching checkpoint `index`; false if it was already reached
+    pub fn activate(&mut self, snapshot: CheckpointSnapshot) -> bool {
+        if self.activated.contains(&snapshot.index) {
+            return false;
+        }
+        self.activated.push(snapshot.index);
+        self.last = Some(snapshot);
+        true
+    }
+}
+
+/// Where a restart puts the player: the last checkpoint, or the level's spawn point
+pub fn respawn_position(state: &CheckpointState, spawn: &PlayerSpawn) -> Vec3 {
+    state
+        .last
+        .as_ref()
+        .map_or(spawn.position, |checkpoint| checkpoint.position)
+}
+
+pub fn in_checkpoint(player_pos: Vec3, checkpoint_pos: Vec3, radius: f32) -> bool {
+    player_pos.xz().distance(checkpoint_pos.xz()) <= radius
+}
+
+// ===== PLUGIN =====
+
+pub struct CheckpointsPlugin;
+
+impl Plugin for CheckpointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CheckpointState>()
+            .add_systems(
+                OnEnter(Screen::GamePlay),
+                (reset_checkpoints, spawn_checkpoints),
+            )
+            .add_systems(OnExit(Screen::GamePlay), reset_checkpoints)
+            .add_systems(
+                Update,
+                (activate_checkpoints, reset_checkpoints_on_level_complete)
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay).and(crate::systems::health::player_alive)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_checkpoints(mut state: ResMut<CheckpointState>) {
+    *state = CheckpointState::default();
+}
+
+fn reset_checkpoints_on_level_complete(
+    mut complete_events: EventReader<LevelCompleteEvent>,
+    mut state: ResMut<CheckpointState>,
+) {
+    if complete_events.read().last().is_some() {
+        *state = CheckpointState::default();
+    }
+}
+
+fn spawn_checkpoints(
+    mut commands: Commands,
+    level: Res<LevelData>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::SUCCESS_GREEN.with_alpha(0.35),
+        emissive: Color::SUCCESS_GREEN.to_linear() * 0.5,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    for (index, checkpoint) in level.checkpoints.iter().enumerate() {
+        commands.spawn((
+            Name::new(format!("Checkpoint {}", index + 1)),
+            // A flat disc on the ground marks the zone
+            Mesh3d(meshes.add(Cylinder::new(checkpoint.radius, 0.05))),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(Vec3::from_array(checkpoint.position)),
+            Checkpoint {
+                index,
+                radius: checkpoint.radius,
+            },
+            bevy::pbr::NotShadowCaster,
+            PlayingScene,
+        ));
+    }
+}
+
+fn activate_checkpoints(
+    mut commands: Commands,
+    player_query: Query<&Transform, With<CharacterController>>,
+    checkpoint_query: Query<(&Checkpoint, &Transform), Without<CharacterController>>,
+    progress: Res<CollectibleProgressTracker>,
+    player_stats: Res<PlayerStats>,
+    streaming_manager: Res<CoinStreamingManager>,
+    objective_manager: Res<ObjectiveManager>,
+    item_query: Query<(&InventoryItem, &ChildOf)>,
+    slot_query: Query<&InventorySlot>,
+    mut state: ResMut<CheckpointState>,
+    toast_query: Query<Entity, With<Toast>>,
+    font_assets: Res<FontAssets>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    for (checkpoint, transform) in &checkpoint_query {
+        if !in_checkpoint(
+            player_transform.translation,
+            transform.translation,
+            checkpoint.radius,
+        ) {
+            continue;
+        }
+        // Skip building a snapshot every frame the player stands in a reached zone
+        if state.activated.contains(&checkpoint.index) {
+            continue;
+        }
+        let inventory = item_query
+            .iter()
+            .filter_map(|(item, child_of)| {
+                let slot = slot_query.get(child_of.parent()).ok()?;
+                Some(InventoryItemSnapshot {
+                    slot: slot.index,
+                    item_type: item.item_type,
+                    count: item.count,
+                })
+            })
+            .collect();
+        let activated = state.activate(CheckpointSnapshot {
+            index: checkpoint.index,
+            // Respawn where the player stood, which is known to be walkable
+            position: player_transform.translation,
+            progress: progress.clone(),
+            player_stats: *player_stats,
+            collected_coins: streaming_manager.collected_positions.clone(),
+            objectives: objective_manager.objectives.clone(),
+            inventory,
+        });
+        if activated {
+            info!("Checkpoint {} reached", checkpoint.index + 1);
+            spawn_toast(
+                &mut commands,
+                &font_assets,
+                &toast_query,
+                "Checkpoint reached",
+                Color::SUCCESS_GREEN,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::headless_app;
+
+    /// The player standing in checkpoint 0, away from the level's spawn point
+    fn checkpoint_app(player_pos: Vec3) -> App {
+        let mut app = headless_app();
+        app.init_resource::<CollectibleProgressTracker>()
+            .init_resource::<PlayerStats>()
+            .init_resource::<CoinStreamingManager>()
+            .init_resource::<ObjectiveManager>()
+            .init_resource::<CheckpointState>()
+            .init_resource::<FontAssets>()
+            .add_systems(Update, activate_checkpoints);
+        app.world_mut()
+            .spawn((Transform::from_translation(player_pos), CharacterController));
+        app.world_mut().spawn((
+            Checkpoint { index: 0, radius: 3.0 },
+            Transform::from_xyz(20.0, 0.0, 20.0),
+        ));
+        app
+    }
+
+    #[test]
+    fn restart_after_a_checkpoint_respawns_there() {
+        let player_pos = Vec3::new(21.0, 1.0, 20.0);
+        let mut app = checkpoint_app(player_pos);
+        app.world_mut().resource_mut::<PlayerStats>().currency = 7;
+        app.update();
+
+        let state = app.world().resource::<CheckpointState>();
+        assert_eq!(state.activated, vec![0]);
+        assert_eq!(state.last.as_ref().map(|checkpoint| checkpoint.player_stats.currency), Some(7));
+        assert_eq!(respawn_position(state, &PlayerSpawn::default()), player_pos);
+    }
+
+    #[test]
+    fn restart_without_a_checkpoint_uses_the_spawn_point() {
+        let mut app = checkpoint_app(Vec3::new(0.0, 1.0, 0.0));
+        app.update();
+
+        let spawn = PlayerSpawn::default();
+        let state = app.world().resource::<CheckpointState>();
+        assert!(state.last.is_none());
+        assert_eq!(respawn_position(state, &spawn), spawn.position);
+    }
+
+    #[test]
+    fn reaching_a_checkpoint_again_keeps_the_first_snapshot() {
+        let mut app = checkpoint_app(Vec3::new(20.0, 1.0, 20.0));
+        app.update();
+        app.world_mut().resource_mut::<PlayerStats>().currency = 50;
+        app.update();
+
+        let state = app.world().resource::<CheckpointState>();
+        assert_eq!(state.activated, vec![0]);
+        assert_eq!(state.last.as_ref().map(|checkpoint| checkpoint.player_stats.currency), Some(0));
+    }
+
+    #[test]
+    fn checkpoint_zone_ignores_height() {
+        assert!(in_checkpoint(Vec3::new(0.0, 30.0, 2.0), Vec3::ZERO, 2.0));
+        assert!(!in_checkpoint(Vec3::new(0.0, 0.0, 2.1), Vec3::ZERO, 2.0));
+    }
+}
@@ -1,10 +1,18 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use avian3d::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use std::collections::{HashMap, HashSet};
 
-use crate::constants::collectibles::COIN_STREAMING_RADIUS;
+use crate::constants::collectibles::{
+    COIN_LOD_DISTANCE, COIN_LOD_HYSTERESIS, COIN_LOD_IMPOSTOR_RADIUS, COIN_LOD_IMPOSTOR_THICKNESS,
+    COIN_STREAMING_PRIORITIZE_VIEW, COIN_STREAMING_RADIUS, COIN_STREAMING_SPAWN_BUDGET,
+    COIN_STREAMING_VIEW_WEIGHT, CollectibleMotionDefaults, CollectibleRewardConfig, PickupComboConfig,
+    PICKUP_MAGNET_RADIUS, PICKUP_MAGNET_SPEED,
+};
 use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
 use crate::systems::character_controller::CharacterController;
 use crate::systems::dojo::PickupItemEvent;
 use crate::assets::ModelAssets;
@@ -32,17 +40,205 @@ pub struct FloatingItem {
     pub hover_speed: f32,
 }
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+/// How one collectible type bobs and spins while waiting to be picked up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CollectibleMotion {
+    pub hover_amplitude: f32,
+    pub hover_speed: f32,
+    pub rotation_speed: f32,
+    pub clockwise: bool,
+}
+
+impl CollectibleMotion {
+    const fn from_defaults(
+        (hover_amplitude, hover_speed, rotation_speed, clockwise): (f32, f32, f32, bool),
+    ) -> Self {
+        Self {
+            hover_amplitude,
+            hover_speed,
+            rotation_speed,
+            clockwise,
+        }
+    }
+
+    pub fn floating(&self, base_height: f32) -> FloatingItem {
+        FloatingItem {
+            base_height,
+            hover_amplitude: self.hover_amplitude,
+            hover_speed: self.hover_speed,
+        }
+    }
+
+    pub fn rotation(&self) -> CollectibleRotation {
+        CollectibleRotation {
+            enabled: self.rotation_speed != 0.0,
+            clockwise: self.clockwise,
+            speed: self.rotation_speed,
+        }
+    }
+}
+
+/// Hover and rotation per collectible type, looked up by every spawn function
+#[derive(Resource, Debug, Clone)]
+pub struct CollectibleMotionConfig {
+    pub by_type: HashMap<CollectibleType, CollectibleMotion>,
+}
+
+impl Default for CollectibleMotionConfig {
+    fn default() -> Self {
+        let by_type = [
+            (CollectibleType::Coin, CollectibleMotionDefaults::COIN),
+            (CollectibleType::Book, CollectibleMotionDefaults::BOOK),
+            (CollectibleType::HealthPotion, CollectibleMotionDefaults::HEALTH_POTION),
+            (CollectibleType::SurvivalKit, CollectibleMotionDefaults::SURVIVAL_KIT),
+            (CollectibleType::PowerCrystal, CollectibleMotionDefaults::POWER_CRYSTAL),
+        ]
+        .into_iter()
+        .map(|(item_type, defaults)| (item_type, CollectibleMotion::from_defaults(defaults)))
+        .collect();
+        Self { by_type }
+    }
+}
+
+impl CollectibleMotionConfig {
+    /// Motion for `item_type`, falling back to the coin's when a type was removed from the map
+    pub fn get(&self, item_type: CollectibleType) -> CollectibleMotion {
+        self.by_type
+            .get(&item_type)
+            .copied()
+            .unwrap_or(CollectibleMotion::from_defaults(CollectibleMotionDefaults::COIN))
+    }
+
+    /// Hover and rotation components for a collectible of `item_type` floating at `base_height`
+    pub fn components(
+        &self,
+        item_type: CollectibleType,
+        base_height: f32,
+    ) -> (FloatingItem, CollectibleRotation) {
+        let motion = self.get(item_type);
+        (motion.floating(base_height), motion.rotation())
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum CollectibleType {
     Coin,
     HealthPotion,
     SurvivalKit,
     Book,
+    PowerCrystal,
+}
+
+impl CollectibleType {
+    /// Whether the item can be used up from the inventory; coins and books can't
+    pub fn is_consumable(self) -> bool {
+        matches!(
+            self,
+            CollectibleType::HealthPotion | CollectibleType::SurvivalKit | CollectibleType::PowerCrystal
+        )
+    }
+
+    /// Reward used when a collectible doesn't carry its own `CollectibleReward`
+    pub fn default_reward(self) -> CollectibleReward {
+        let (value, xp) = match self {
+            CollectibleType::Coin => (CollectibleRewardConfig::COIN_VALUE, CollectibleRewardConfig::COIN_XP),
+            CollectibleType::Book => (CollectibleRewardConfig::BOOK_VALUE, CollectibleRewardConfig::BOOK_XP),
+            CollectibleType::HealthPotion => (0, CollectibleRewardConfig::HEALTH_POTION_XP),
+            CollectibleType::SurvivalKit => (0, CollectibleRewardConfig::SURVIVAL_KIT_XP),
+            CollectibleType::PowerCrystal => (
+                CollectibleRewardConfig::POWER_CRYSTAL_VALUE,
+                CollectibleRewardConfig::POWER_CRYSTAL_XP,
+            ),
+        };
+        CollectibleReward { value, xp }
+    }
+}
+
+/// Currency and XP granted when this collectible is picked up
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollectibleReward {
+    pub value: u32,
+    pub xp: u32,
+}
+
+/// Currency and XP the player has earned from pickups this session
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct PlayerStats {
+    pub currency: u32,
+    pub xp: u32,
+}
+
+impl PlayerStats {
+    pub fn apply_reward(&mut self, reward: CollectibleReward) {
+        self.currency = self.currency.saturating_add(reward.value);
+        self.xp = self.xp.saturating_add(reward.xp);
+    }
+}
+
+/// Pickups chained within `PickupComboConfig::WINDOW_SECS` of each other
+#[derive(Resource, Default, Debug)]
+pub struct PickupCombo {
+    pub count: u32,
+    last_pickup: Option<f32>,
+}
+
+impl PickupCombo {
+    /// Counts a pickup at `now` (seconds), starting a new combo if the last one
+    /// was longer ago than the window; returns the combo length including this pickup
+    pub fn register(&mut self, now: f32) -> u32 {
+        let chained = self
+            .last_pickup
+            .is_some_and(|last| now - last <= PickupComboConfig::WINDOW_SECS);
+        self.count = if chained { self.count + 1 } else { 1 };
+        self.last_pickup = Some(now);
+        self.count
+    }
+}
+
+/// Reward multiplier for the `count`th pickup in a combo; a lone pickup gets 1x
+pub fn combo_multiplier(count: u32) -> f32 {
+    let bonus = PickupComboConfig::STEP_BONUS * count.saturating_sub(1) as f32;
+    (1.0 + bonus).min(PickupComboConfig::MAX_MULTIPLIER)
+}
+
+/// `reward` scaled by the combo multiplier, rounded to whole currency and XP
+pub fn reward_with_combo(reward: CollectibleReward, count: u32) -> CollectibleReward {
+    let multiplier = combo_multiplier(count);
+    CollectibleReward {
+        value: (reward.value as f32 * multiplier).round() as u32,
+        xp: (reward.xp as f32 * multiplier).round() as u32,
+    }
 }
 
 #[derive(Resource)]
 pub struct NextItemToAdd(pub CollectibleType);
 
+/// Running per-type totals of everything the player has collected this session
+#[derive(Resource, Default, Debug, Clone)]
+pub struct CollectibleProgressTracker {
+    pub coins: u32,
+    pub health_potions: u32,
+    pub survival_kits: u32,
+    pub books: u32,
+    pub power_crystals: u32,
+}
+
+impl CollectibleProgressTracker {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn record(&mut self, item_type: CollectibleType) {
+        match item_type {
+            CollectibleType::Coin => self.coins += 1,
+            CollectibleType::HealthPotion => self.health_potions += 1,
+            CollectibleType::SurvivalKit => self.survival_kits += 1,
+            CollectibleType::Book => self.books += 1,
+            CollectibleType::PowerCrystal => self.power_crystals += 1,
+        }
+    }
+}
+
 
 
 #[derive(Resource)]
@@ -67,15 +263,96 @@ pub struct StreamingCoin {
     pub position_id: usize,
 }
 
+/// Coin collider built once and cloned onto every coin. Colliders share their
+/// underlying shape, so cloning only bumps a reference count and pooled coins
+/// keep firing collision events like freshly spawned ones.
+#[derive(Resource)]
+pub struct CoinColliderCache(pub Collider);
+
+impl Default for CoinColliderCache {
+    fn default() -> Self {
+        // Create a compound collider that better approximates a coin shape
+        // This is more performant than mesh-fitted colliders while still being more accurate than a single sphere
+        Self(Collider::compound(vec![
+            // Main body - slightly flattened sphere
+            (Vec3::ZERO, Quat::IDENTITY, Collider::sphere(0.4)),
+            // Edge rings for better coin-like collision
+            (Vec3::new(0.0, 0.0, 0.0), Quat::IDENTITY, Collider::cylinder(0.4, 0.1)),
+        ]))
+    }
+}
+
+/// Which detail level a streaming coin is drawn at
+#[derive(Component, Default)]
+pub struct CoinLod {
+    pub far: bool,
+}
+
+/// Child holding the full coin model
+#[derive(Component)]
+pub struct CoinLodModel;
+
+/// Child holding the low-poly disc shown in place of the model far from the camera
+#[derive(Component)]
+pub struct CoinLodImpostor;
+
+/// Mesh and material shared by every coin impostor
+#[derive(Resource)]
+pub struct CoinImpostorAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+/// Optional pickup assist that pulls nearby coins toward the player; off by default
+#[derive(Resource)]
+pub struct PickupMagnet {
+    pub enabled: bool,
+    pub radius: f32,
+    pub speed: f32,
+}
+
+impl Default for PickupMagnet {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            radius: PICKUP_MAGNET_RADIUS,
+            speed: PICKUP_MAGNET_SPEED,
+        }
+    }
+}
+
+/// Marks a coin currently being pulled by the pickup magnet, which suspends its hover
+#[derive(Component)]
+pub struct MagnetPulled;
+
+/// Marks a streamed coin that is parked in the pool, hidden and with its collider disabled
+#[derive(Component)]
+pub struct PooledCoin;
+
+/// Running totals for streamed coin entities, logged to gauge allocation churn
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CoinPoolStats {
+    pub spawned: usize,
+    pub reused: usize,
+    pub released: usize,
+}
+
 /// Resource containing all coin positions and their spawned state
 #[derive(Resource)]
 pub struct CoinStreamingManager {
     pub positions: Vec<Vec3>,
     pub spawned_coins: HashMap<usize, Entity>,
+    /// Inactive coin entities waiting to be reused instead of spawning new ones
+    pub pooled_coins: Vec<Entity>,
+    pub pool_stats: CoinPoolStats,
     pub collected_positions: HashSet<usize>,  // Track collected positions to prevent respawning
     pub last_update_time: f32,
     pub update_interval: f32,
     pub spawn_radius: f32,
+    /// Most coins placed per update
+    pub spawn_budget: usize,
+    /// Spend the budget on coins the camera faces before the ones behind it
+    pub prioritize_view: bool,
 }
 
 impl Default for CoinStreamingManager {
@@ -83,14 +360,31 @@ impl Default for CoinStreamingManager {
         Self {
             positions: Vec::new(),
             spawned_coins: HashMap::new(),
+            pooled_coins: Vec::new(),
+            pool_stats: CoinPoolStats::default(),
             collected_positions: HashSet::new(),
             last_update_time: 0.0,
             update_interval: 1.0,
             spawn_radius: COIN_STREAMING_RADIUS,   // Use centralized constant
+            spawn_budget: COIN_STREAMING_SPAWN_BUDGET,
+            prioritize_view: COIN_STREAMING_PRIORITIZE_VIEW,
         }
     }
 }
 
+// Pooled coins are despawned with the other collectibles, so the manager must not hand them out again
+impl crate::screens::SceneState for CoinStreamingManager {
+    fn reset_on_teardown(&mut self) {
+        let stats = self.pool_stats;
+        // Once per level rather than every streaming update
+        debug!(
+            "Coin pool: {} spawned, {} reused, {} released",
+            stats.spawned, stats.reused, stats.released
+        );
+        self.clear_entities();
+    }
+}
+
 impl CoinStreamingManager {
 
     pub fn add_position(&mut self, position: Vec3) {
@@ -105,21 +399,65 @@ impl CoinStreamingManager {
     pub fn mark_updated(&mut self, current_time: f32) {
         self.last_update_time = current_time;
     }
+
+    /// Hides a coin and parks it in the pool so a later spawn can reuse it
+    pub fn release_coin(&mut self, commands: &mut Commands, entity: Entity, position_id: usize) {
+        self.spawned_coins.remove(&position_id);
+        commands
+            .entity(entity)
+            .insert((Visibility::Hidden, ColliderDisabled, PooledCoin))
+            .remove::<(StreamingCoin, MagnetPulled)>();
+        self.pooled_coins.push(entity);
+        self.pool_stats.released += 1;
+    }
+
+    /// Marks every coin uncollected again and pools the ones in the world, keeping the
+    /// level's positions. Streaming repopulates around the player on the next update.
+    pub fn reset_progress(&mut self, commands: &mut Commands) {
+        let spawned: Vec<(usize, Entity)> = self.spawned_coins.drain().collect();
+        for (position_id, entity) in spawned {
+            self.release_coin(commands, entity, position_id);
+        }
+        self.collected_positions.clear();
+        self.last_update_time = 0.0;
+    }
+
+    /// Forgets every coin entity, e.g. after they were despawned with the scene.
+    /// Positions and collected state are kept so streaming resumes where it left off.
+    pub fn clear_entities(&mut self) {
+        self.spawned_coins.clear();
+        self.pooled_coins.clear();
+    }
 }
 
-// Configuration for spawning collectibles - keeping for potential future use
-#[derive(Clone)]
-#[allow(dead_code)]
-pub struct CollectibleConfig {
-    pub position: Vec3,
-    pub collectible_type: CollectibleType,
-    pub scale: f32,
-    pub rotation: Option<CollectibleRotation>,
+/// Seed for everything that randomizes the world layout, so the same seed always
+/// produces the same coin placement. Picked randomly once per session.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct WorldSeed(pub u64);
+
+impl Default for WorldSeed {
+    fn default() -> Self {
+        Self(rand::random())
+    }
+}
+
+impl WorldSeed {
+    pub fn rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.0)
+    }
+
+    /// An independent generator for `stream` (e.g. one per enemy), so consumers don't
+    /// shift each other's numbers by drawing in a different order
+    pub fn rng_for(&self, stream: u64) -> StdRng {
+        StdRng::seed_from_u64(self.0 ^ stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
 }
 
 #[derive(Resource, Default)]
 pub struct PlayerMovementTracker {
     pub last_position: Option<Vec3>,
+    /// Distance covered since the previous recorded position, 0 on frames without movement
+    pub last_step: f32,
     pub time_stationary: f32,
     pub paused: bool,
 }
@@ -132,36 +470,116 @@ impl Plugin for CollectiblesPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(crate::ui::inventory::InventoryVisibilityState::default())
             .init_resource::<CollectibleSpawner>()
+            .init_resource::<CollectibleProgressTracker>()
+            .init_resource::<PlayerStats>()
+            .init_resource::<PickupCombo>()
+            .init_resource::<PickupMagnet>()
+            .add_event::<crate::ui::inventory::InventoryFullEvent>()
+            .add_event::<crate::ui::inventory::InventoryUseEvent>()
             .init_resource::<PlayerMovementTracker>()
             .init_resource::<NavigationBasedSpawner>()
+            .init_resource::<WorldSeed>()
+            .init_resource::<CoinColliderCache>()
+            .init_resource::<CollectibleMotionConfig>()
+            .add_systems(Startup, setup_coin_impostor_assets)
 
             // CoinStreamingManager now initialized in pregame_loading to persist between screens
             .add_systems(
                 Update,
                 (
+                    sync_coin_streaming_radius.before(update_coin_streaming),
                     update_coin_streaming,            // Stream coins every 2-3 seconds
                     handle_coin_collisions,           // Handle collision-based coin collection
+                    play_pickup_sfx.after(handle_coin_collisions),
+                    apply_pickup_magnet.before(update_floating_items),
                     update_floating_items,
                     rotate_collectibles,
+                    update_coin_lod,
 
+                    crate::ui::inventory::restore_inventory
+                        .run_if(resource_exists::<crate::ui::inventory::InventoryRestore>)
+                        .before(crate::ui::inventory::add_item_to_inventory),
                     crate::ui::inventory::add_item_to_inventory,
                     crate::ui::inventory::toggle_inventory_visibility,
                     crate::ui::inventory::adjust_inventory_for_dialogs,
+                    crate::ui::inventory::show_inventory_full_toast,
+                    crate::ui::inventory::use_inventory_slot_hotkeys,
+                    crate::ui::inventory::handle_inventory_use.after(crate::ui::inventory::use_inventory_slot_hotkeys),
                     track_player_movement,
                 )
-                    .run_if(in_state(Screen::GamePlay)),
+                    .run_if(in_state(Screen::GamePlay).and(crate::systems::health::player_alive)),
             );
     }
 }
 
 // ===== SYSTEMS =====
 
+fn setup_coin_impostor_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(
+        Cylinder::new(COIN_LOD_IMPOSTOR_RADIUS, COIN_LOD_IMPOSTOR_THICKNESS)
+            .mesh()
+            .resolution(8),
+    );
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.78, 0.2),
+        metallic: 0.8,
+        perceptual_roughness: 0.4,
+        ..default()
+    });
+    commands.insert_resource(CoinImpostorAssets { mesh, material });
+}
+
+/// Keeps the streaming radius within the render distance from the graphics settings
+fn sync_coin_streaming_radius(
+    graphics_settings: Res<crate::resources::graphics::GraphicsSettings>,
+    mut streaming_manager: ResMut<CoinStreamingManager>,
+) {
+    let radius = graphics_settings.coin_streaming_radius();
+    if streaming_manager.spawn_radius != radius {
+        streaming_manager.spawn_radius = radius;
+    }
+}
+
+/// Spawn priority of a coin at `position`, lower first. Distance from the player
+/// always counts; with a camera `view` (position and forward), coins further from
+/// the look direction on the ground plane rank as if further away.
+pub fn streaming_priority(position: Vec3, player_pos: Vec3, view: Option<(Vec3, Vec3)>) -> f32 {
+    let distance = player_pos.distance(position);
+    let Some((camera_pos, camera_forward)) = view else {
+        return distance;
+    };
+    let forward = camera_forward.xz().normalize_or_zero();
+    let to_coin = (position - camera_pos).xz().normalize_or_zero();
+    // 0 straight ahead, 1 directly behind
+    let off_axis = (1.0 - forward.dot(to_coin)) / 2.0;
+    distance * (1.0 + COIN_STREAMING_VIEW_WEIGHT * off_axis)
+}
+
+/// Orders candidate `(position_id, position)` pairs so the budget is spent on the best first
+pub fn sort_by_streaming_priority(
+    candidates: &mut [(usize, Vec3)],
+    player_pos: Vec3,
+    view: Option<(Vec3, Vec3)>,
+) {
+    candidates.sort_by(|(_, a), (_, b)| {
+        streaming_priority(*a, player_pos, view).total_cmp(&streaming_priority(*b, player_pos, view))
+    });
+}
+
 /// Streaming system that spawns/despawns coins based on player proximity every 2-3 seconds
 fn update_coin_streaming(
     mut commands: Commands,
     mut streaming_manager: ResMut<CoinStreamingManager>,
     player_query: Query<&Transform, With<CharacterController>>,
+    camera_query: Query<&GlobalTransform, (With<Camera3d>, With<PlayingScene>)>,
     model_assets: Option<Res<ModelAssets>>,
+    impostor_assets: Res<CoinImpostorAssets>,
+    collider_cache: Res<CoinColliderCache>,
+    motion_config: Res<CollectibleMotionConfig>,
     time: Res<Time>,
     existing_coins: Query<(Entity, &StreamingCoin)>,
 ) {
@@ -196,10 +614,9 @@ fn update_coin_streaming(
         }
     }
 
-    // Despawn distant coins
+    // Return distant coins to the pool
     for (entity, position_id) in to_despawn {
-        commands.entity(entity).despawn();
-        streaming_manager.spawned_coins.remove(&position_id);
+        streaming_manager.release_coin(&mut commands, entity, position_id);
     }
 
     // Collect positions that need to be spawned
@@ -226,92 +643,236 @@ fn update_coin_streaming(
 
 
 
-    // Spawn the collected positions
+    // Nearest first, or what the camera faces first when view priority is on
+    let view = camera_query
+        .single()
+        .ok()
+        .filter(|_| streaming_manager.prioritize_view)
+        .map(|camera| (camera.translation(), camera.forward().as_vec3()));
+    sort_by_streaming_priority(&mut positions_to_spawn, player_pos, view);
+    positions_to_spawn.truncate(streaming_manager.spawn_budget);
+
+    // Spawn the collected positions, reusing pooled coins where possible
     for (position_id, position) in positions_to_spawn {
-        
-        let entity = spawn_streaming_coin(&mut commands, &assets, position, position_id);
+        let entity = if let Some(entity) = streaming_manager.pooled_coins.pop() {
+            reuse_streaming_coin(&mut commands, &motion_config, entity, position, position_id);
+            streaming_manager.pool_stats.reused += 1;
+            entity
+        } else {
+            streaming_manager.pool_stats.spawned += 1;
+            spawn_streaming_coin(
+                &mut commands,
+                &assets,
+                &impostor_assets,
+                &collider_cache,
+                &motion_config,
+                position,
+                position_id,
+            )
+        };
         streaming_manager.spawned_coins.insert(position_id, entity);
     }
-
-    let _active_coins = streaming_manager.spawned_coins.len();
-
 }
 
 /// Spawn a single streaming coin
 fn spawn_streaming_coin(
     commands: &mut Commands,
     assets: &ModelAssets,
+    impostor_assets: &CoinImpostorAssets,
+    collider_cache: &CoinColliderCache,
+    motion_config: &CollectibleMotionConfig,
     position: Vec3,
     position_id: usize,
 ) -> Entity {
-    // Adjust Y position based on current value
-    let adjusted_position = Vec3::new(
-        position.x,
-        if position.y == -1.5 {
-            position.y // No change for -1.5
-        } else if position.y >= 10.0 {
-            position.y + 2.5 // Add 2.5 if at least 10
-        } else if position.y >= 5.0 {
-            position.y + 2.0 // Add 2.0 if at least 5
-        } else {
-            position.y // No change for other values
-        },
-        position.z,
-    );
-    
-    // Create a compound collider that better approximates a coin shape
-    // This is more performant than mesh-fitted colliders while still being more accurate than a single sphere
-    let coin_collider = Collider::compound(vec![
-        // Main body - slightly flattened sphere
-        (Vec3::ZERO, Quat::IDENTITY, Collider::sphere(0.4)),
-        // Edge rings for better coin-like collision
-        (Vec3::new(0.0, 0.0, 0.0), Quat::IDENTITY, Collider::cylinder(0.4, 0.1)),
-    ]);
+    let adjusted_position = streaming_coin_position(position);
     
     commands.spawn((
         Name::new("Streaming Coin"),
-        SceneRoot(assets.coin.clone()),
         Transform {
             translation: adjusted_position,
             scale: Vec3::splat(0.75),
             ..default()
         },
-        coin_collider,
+        collider_cache.0.clone(),
         RigidBody::Kinematic,
         Visibility::Visible,
         Collectible,
         CollectibleType::Coin,
-        FloatingItem {
-            base_height: adjusted_position.y, // Use adjusted position for floating base height
-            hover_amplitude: 0.2,
-            hover_speed: 2.0,
-        },
-        CollectibleRotation {
-            enabled: true,
-            clockwise: true,
-            speed: 1.0,
-        },
+        CollectibleType::Coin.default_reward(),
+        // Use adjusted position for floating base height
+        motion_config.components(CollectibleType::Coin, adjusted_position.y),
         Sensor, // This makes the coin non-solid but still detects collisions
         CollisionEventsEnabled, // Enable collision events for this coin
         StreamingCoin { position_id },
+        CoinLod::default(),
+        children![
+            (SceneRoot(assets.coin.clone()), CoinLodModel),
+            (
+                Mesh3d(impostor_assets.mesh.clone()),
+                MeshMaterial3d(impostor_assets.material.clone()),
+                // Stand the disc upright like the coin model
+                Transform::from_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+                Visibility::Hidden,
+                CoinLodImpostor,
+            ),
+        ],
     )).id()
 }
 
+/// Model for a loose collectible; only coins and books have their own so far
+pub fn collectible_scene(assets: &ModelAssets, item_type: CollectibleType) -> Handle<Scene> {
+    match item_type {
+        CollectibleType::Coin => assets.coin.clone(),
+        CollectibleType::Book => assets.book.clone(),
+        CollectibleType::HealthPotion | CollectibleType::SurvivalKit | CollectibleType::PowerCrystal => {
+            assets.mystery_box.clone()
+        }
+    }
+}
+
+/// Spawns a single floating collectible that the player picks up on contact,
+/// the same way as streamed coins
+pub fn spawn_collectible(
+    commands: &mut Commands,
+    assets: &ModelAssets,
+    collider_cache: &CoinColliderCache,
+    motion_config: &CollectibleMotionConfig,
+    item_type: CollectibleType,
+    position: Vec3,
+) -> Entity {
+    commands.spawn((
+        Name::new(format!("{:?}", item_type)),
+        SceneRoot(collectible_scene(assets, item_type)),
+        Transform {
+            translation: position,
+            scale: Vec3::splat(0.75),
+            ..default()
+        },
+        collider_cache.0.clone(),
+        RigidBody::Kinematic,
+        Visibility::Visible,
+        Collectible,
+        item_type,
+        item_type.default_reward(),
+        motion_config.components(item_type, position.y),
+        Sensor,
+        CollisionEventsEnabled,
+        PlayingScene,
+    )).id()
+}
+
+/// Moves a pooled coin to a new streaming position and makes it collectible again
+fn reuse_streaming_coin(
+    commands: &mut Commands,
+    motion_config: &CollectibleMotionConfig,
+    entity: Entity,
+    position: Vec3,
+    position_id: usize,
+) {
+    let adjusted_position = streaming_coin_position(position);
+
+    commands
+        .entity(entity)
+        .insert((
+            Transform {
+                translation: adjusted_position,
+                scale: Vec3::splat(0.75),
+                ..default()
+            },
+            Visibility::Visible,
+            motion_config.components(CollectibleType::Coin, adjusted_position.y),
+            StreamingCoin { position_id },
+        ))
+        .remove::<(ColliderDisabled, PooledCoin)>();
+}
+
+/// Swaps distant coins' full model for the impostor disc and back as the camera moves.
+/// Coins switch to the impostor past `COIN_LOD_DISTANCE + COIN_LOD_HYSTERESIS` and back
+/// inside `COIN_LOD_DISTANCE`, so they don't flicker at the boundary.
+fn update_coin_lod(
+    camera_query: Query<&GlobalTransform, (With<Camera3d>, With<PlayingScene>)>,
+    mut coin_query: Query<(&GlobalTransform, &mut CoinLod, &Children), With<StreamingCoin>>,
+    mut model_query: Query<&mut Visibility, (With<CoinLodModel>, Without<CoinLodImpostor>)>,
+    mut impostor_query: Query<&mut Visibility, (With<CoinLodImpostor>, Without<CoinLodModel>)>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let camera_pos = camera_transform.translation();
+
+    for (coin_transform, mut lod, children) in &mut coin_query {
+        let distance = camera_pos.distance(coin_transform.translation());
+        let far = if lod.far {
+            distance > COIN_LOD_DISTANCE
+        } else {
+            distance > COIN_LOD_DISTANCE + COIN_LOD_HYSTERESIS
+        };
+        if far == lod.far {
+            continue;
+        }
+        lod.far = far;
+
+        let (model_visibility, impostor_visibility) = if far {
+            (Visibility::Hidden, Visibility::Inherited)
+        } else {
+            (Visibility::Inherited, Visibility::Hidden)
+        };
+        for child in children.iter() {
+            if let Ok(mut visibility) = model_query.get_mut(child) {
+                *visibility = model_visibility;
+            } else if let Ok(mut visibility) = impostor_query.get_mut(child) {
+                *visibility = impostor_visibility;
+            }
+        }
+    }
+}
+
+/// Lifts coins on raised ground so they don't sink into the terrain
+fn streaming_coin_position(position: Vec3) -> Vec3 {
+    // Adjust Y position based on current value
+    Vec3::new(
+        position.x,
+        if position.y == -1.5 {
+            position.y // No change for -1.5
+        } else if position.y >= 10.0 {
+            position.y + 2.5 // Add 2.5 if at least 10
+        } else if position.y >= 5.0 {
+            position.y + 2.0 // Add 2.0 if at least 5
+        } else {
+            position.y // No change for other values
+        },
+        position.z,
+    )
+}
+
 /// System that handles coin collection through collision events
 fn handle_coin_collisions(
     mut commands: Commands,
     mut collision_events: EventReader<CollisionStarted>,
     player_query: Query<Entity, With<CharacterController>>,
-    coin_query: Query<(Entity, &CollectibleType, Option<&StreamingCoin>), (With<Collectible>, Without<Collected>)>,
+    coin_query: Query<
+        (Entity, &CollectibleType, &Transform, Option<&CollectibleReward>, Option<&StreamingCoin>),
+        (With<Collectible>, Without<Collected>, Without<PooledCoin>),
+    >,
     mut pickup_events: EventWriter<PickupItemEvent>,
     mut streaming_manager: ResMut<CoinStreamingManager>,
-    mut sfx_events: EventWriter<PlaySfxEvent>,
+    mut progress_tracker: ResMut<CollectibleProgressTracker>,
+    mut player_stats: ResMut<PlayerStats>,
+    mut combo: ResMut<PickupCombo>,
+    time: Res<Time>,
+    inventory_items: Query<&crate::ui::inventory::InventoryItem>,
+    mut inventory_full_events: EventWriter<crate::ui::inventory::InventoryFullEvent>,
+    difficulty: Res<crate::resources::difficulty::ActiveDifficulty>,
 ) {
     // Get the player entity
     let Ok(player_entity) = player_query.single() else {
         return;
     };
 
+    // `Collected` and the despawn only land once commands apply, so a second contact
+    // with the same coin this frame must not count it (or emit its event) again
+    let mut collected_this_frame = HashSet::new();
+
     // Process collision events
     for CollisionStarted(collider1, collider2) in collision_events.read() {
         // Determine which entity is the player and which is the collectible
@@ -323,41 +884,109 @@ fn handle_coin_collisions(
             continue; // Neither entity is the player
         };
 
-        // Check if the collectible is a coin
-        if let Ok((entity, collectible_type, streaming_coin)) = coin_query.get(collectible_entity) {
-            if *collectible_type == CollectibleType::Coin {
-                // Remove from streaming manager if it's a streaming coin
-                if let Some(streaming) = streaming_coin {
-                    streaming_manager.spawned_coins.remove(&streaming.position_id);
-                    streaming_manager.collected_positions.insert(streaming.position_id);
-                }
-
-                // Play coin collection sound effect
-                sfx_events.write(PlaySfxEvent {
-                    sfx_type: SfxType::CoinCollect,
+        // Check if the other entity is an uncollected collectible
+        if let Ok((entity, collectible_type, transform, reward, streaming_coin)) = coin_query.get(collectible_entity) {
+            if !should_collect(&mut collected_this_frame, entity) {
+                continue;
+            }
+
+            // Leave the collectible in the world if there's no room for it
+            if !crate::ui::inventory::inventory_accepts(inventory_items.iter(), *collectible_type) {
+                inventory_full_events.write(crate::ui::inventory::InventoryFullEvent {
+                    item_type: *collectible_type,
                 });
+                continue;
+            }
 
-                // Mark as collected
+            let mut pooled = false;
+            // Streaming coins go back to the pool instead of being despawned
+            if let Some(streaming) = streaming_coin {
+                streaming_manager.collected_positions.insert(streaming.position_id);
+                streaming_manager.release_coin(&mut commands, entity, streaming.position_id);
+                pooled = true;
+            }
+
+            // Count the pickup against its own type
+            progress_tracker.record(*collectible_type);
+            let mut reward = reward.copied().unwrap_or_else(|| collectible_type.default_reward());
+            reward.value = crate::resources::difficulty::scale_u32(reward.value, difficulty.modifiers().coin_value);
+            let combo_count = combo.register(time.elapsed_secs());
+            player_stats.apply_reward(reward_with_combo(reward, combo_count));
+
+            // Insert NextItemToAdd so inventory system will add it
+            commands.insert_resource(NextItemToAdd(*collectible_type));
+            if !pooled {
+                // Mark as collected and despawn the entity immediately
                 commands.entity(entity).insert(Collected);
-                // Insert NextItemToAdd so inventory system will add it
-                commands.insert_resource(NextItemToAdd(*collectible_type));
-                // Despawn the entity immediately
                 commands.entity(entity).despawn();
-                // Trigger blockchain event
-                pickup_events.write(PickupItemEvent {
-                    item_type: *collectible_type,
-                    item_entity: entity,
-                });
+            }
+            // Everything else that reacts to pickups (blockchain, objectives, sfx) listens for this
+            pickup_events.write(PickupItemEvent {
+                item_type: *collectible_type,
+                item_entity: entity,
+                position: transform.translation,
+            });
+        }
+    }
+}
+
+/// True the first time `entity` is seen this frame; the rest of its collisions are ignored
+pub fn should_collect(collected_this_frame: &mut HashSet<Entity>, entity: Entity) -> bool {
+    collected_this_frame.insert(entity)
+}
 
+fn play_pickup_sfx(
+    mut pickup_events: EventReader<PickupItemEvent>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+    for _ in pickup_events.read() {
+        sfx_events.write(PlaySfxEvent {
+            sfx_type: SfxType::CoinCollect,
+        });
+    }
+}
+
+/// Pulls coins inside the magnet radius toward the player until the collision handler picks them up
+fn apply_pickup_magnet(
+    mut commands: Commands,
+    magnet: Res<PickupMagnet>,
+    player_query: Query<&Transform, (With<CharacterController>, Without<Collectible>)>,
+    mut coin_query: Query<
+        (Entity, &mut Transform, Has<MagnetPulled>),
+        (With<Collectible>, Without<Collected>, Without<PooledCoin>),
+    >,
+    time: Res<Time>,
+) {
+    if !magnet.enabled {
+        return;
+    }
 
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+
+    for (entity, mut transform, pulled) in coin_query.iter_mut() {
+        if transform.translation.distance(player_pos) > magnet.radius {
+            if pulled {
+                // Player outran the coin; let it hover again where it is
+                commands.entity(entity).remove::<MagnetPulled>();
             }
+            continue;
         }
+
+        if !pulled {
+            commands.entity(entity).insert(MagnetPulled);
+        }
+        transform.translation = transform
+            .translation
+            .lerp(player_pos, (magnet.speed * time.delta_secs()).min(1.0));
     }
 }
 
 fn update_floating_items(
     time: Res<Time>, 
-    mut query: Query<(&FloatingItem, &mut Transform), With<Collectible>>
+    mut query: Query<(&FloatingItem, &mut Transform), (With<Collectible>, Without<MagnetPulled>)>
 ) {
     for (floating, mut transform) in query.iter_mut() {
         let time = time.elapsed_secs();
@@ -383,18 +1012,16 @@ pub fn rotate_collectibles(
 }
 
 // System to track player movement and update PlayerMovementTracker
-fn track_player_movement(
+pub fn track_player_movement(
     time: Res<Time>,
     player_query: Query<&Transform, With<CharacterController>>,
     mut tracker: ResMut<PlayerMovementTracker>,
 ) {
     let Ok(player_transform) = player_query.single() else { return; };
     let pos = player_transform.translation;
-    let moved = if let Some(last) = tracker.last_position {
-        pos.distance(last) > 0.05 // movement threshold
-    } else {
-        true
-    };
+    let step = tracker.last_position.map_or(0.0, |last| pos.distance(last));
+    let moved = tracker.last_position.is_none() || step > 0.05; // movement threshold
+    tracker.last_step = if moved { step } else { 0.0 };
     if moved {
         tracker.time_stationary = 0.0;
         tracker.paused = false;
@@ -456,6 +1083,35 @@ impl Default for NavigationData {
 
 
 
+impl NavigationData {
+    /// Appends a sampled position, keeping bounds and average up to date incrementally
+    pub fn record_point(&mut self, position: Vec3, session_time: f32) {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs_f64())
+            .unwrap_or_default();
+
+        let point = position.to_array();
+        let stats = &mut self.statistics;
+        stats.total_points += 1;
+        stats.session_duration = session_time;
+        for axis in 0..3 {
+            stats.min_bounds[axis] = stats.min_bounds[axis].min(point[axis]);
+            stats.max_bounds[axis] = stats.max_bounds[axis].max(point[axis]);
+            // Running mean, so nothing needs to be re-summed per sample
+            stats.average_position[axis] +=
+                (point[axis] - stats.average_position[axis]) / stats.total_points as f32;
+        }
+
+        self.positions.push(NavigationPoint {
+            timestamp,
+            position: point,
+            session_time,
+        });
+    }
+}
+
 // Replace the surface-based spawning with navigation-based spawning
 #[derive(Resource)]
 pub struct NavigationBasedSpawner {
@@ -473,3 +1129,379 @@ impl Default for NavigationBasedSpawner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coins_pay_currency_and_books_pay_xp() {
+        let coin = CollectibleType::Coin.default_reward();
+        let book = CollectibleType::Book.default_reward();
+        assert_eq!(coin.value, CollectibleRewardConfig::COIN_VALUE);
+        assert_eq!(book.xp, CollectibleRewardConfig::BOOK_XP);
+        assert_eq!(book.value, 0);
+    }
+
+    #[test]
+    fn apply_reward_adds_value_and_xp() {
+        let mut stats = PlayerStats::default();
+        stats.apply_reward(CollectibleReward { value: 3, xp: 7 });
+        stats.apply_reward(CollectibleReward { value: 2, xp: 1 });
+        assert_eq!((stats.currency, stats.xp), (5, 8));
+    }
+
+    #[test]
+    fn lone_pickup_is_not_multiplied() {
+        let reward = CollectibleReward { value: 10, xp: 5 };
+        assert_eq!(reward_with_combo(reward, 1), reward);
+    }
+
+    #[test]
+    fn combo_scales_value_and_xp() {
+        let reward = CollectibleReward { value: 10, xp: 5 };
+        // Third pickup in a row: 1 + 2 * STEP_BONUS
+        assert_eq!(
+            reward_with_combo(reward, 3),
+            CollectibleReward { value: 12, xp: 6 }
+        );
+    }
+
+    #[test]
+    fn combo_multiplier_is_capped() {
+        assert_eq!(combo_multiplier(1_000), PickupComboConfig::MAX_MULTIPLIER);
+    }
+
+    #[test]
+    fn pickups_within_the_window_chain() {
+        let mut combo = PickupCombo::default();
+        assert_eq!(combo.register(0.0), 1);
+        assert_eq!(combo.register(PickupComboConfig::WINDOW_SECS * 0.5), 2);
+        assert_eq!(combo.register(PickupComboConfig::WINDOW_SECS), 3);
+    }
+
+    #[test]
+    fn combo_resets_after_the_window() {
+        let mut combo = PickupCombo::default();
+        combo.register(0.0);
+        combo.register(0.1);
+        assert_eq!(combo.register(0.1 + PickupComboConfig::WINDOW_SECS + 0.01), 1);
+    }
+
+    #[test]
+    fn progress_tracker_counts_crystals_apart_from_books() {
+        let mut tracker = CollectibleProgressTracker::default();
+        tracker.record(CollectibleType::PowerCrystal);
+        tracker.record(CollectibleType::PowerCrystal);
+        tracker.record(CollectibleType::Book);
+        assert_eq!(tracker.power_crystals, 2);
+        assert_eq!(tracker.books, 1);
+    }
+
+    /// The collision handler and the resources it reads
+    fn add_pickup_handler(app: &mut App) {
+        use crate::resources::difficulty::{ActiveDifficulty, Difficulty};
+        use crate::ui::inventory::InventoryFullEvent;
+
+        app.init_resource::<CoinStreamingManager>()
+            .init_resource::<CollectibleProgressTracker>()
+            .init_resource::<PlayerStats>()
+            .init_resource::<PickupCombo>()
+            .insert_resource(ActiveDifficulty(Difficulty::Normal))
+            .add_event::<CollisionStarted>()
+            .add_event::<PickupItemEvent>()
+            .add_event::<InventoryFullEvent>()
+            .add_systems(Update, handle_coin_collisions);
+    }
+
+    /// A player touching a lone coin, with the resources the collision handler reads
+    fn pickup_app() -> (App, Entity, Entity) {
+        let mut app = crate::test_support::headless_app();
+        add_pickup_handler(&mut app);
+        let player = app.world_mut().spawn(CharacterController).id();
+        let coin = app
+            .world_mut()
+            .spawn((Collectible, CollectibleType::Coin, Transform::from_xyz(1.0, 0.0, 2.0)))
+            .id();
+        (app, player, coin)
+    }
+
+    #[test]
+    fn collecting_a_coin_emits_exactly_one_pickup_event() {
+        let (mut app, player, coin) = pickup_app();
+        // Both contact orderings for the same pair arrive in one frame
+        app.world_mut().send_event(CollisionStarted(player, coin));
+        app.world_mut().send_event(CollisionStarted(coin, player));
+        app.update();
+
+        let events = app.world().resource::<Events<PickupItemEvent>>();
+        let pickups: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(pickups.len(), 1);
+        assert_eq!(pickups[0].item_type, CollectibleType::Coin);
+        assert_eq!(pickups[0].item_entity, coin);
+        assert_eq!(pickups[0].position, Vec3::new(1.0, 0.0, 2.0));
+        assert!(app.world().get_entity(coin).is_err());
+    }
+
+    #[test]
+    fn collisions_without_the_player_emit_nothing() {
+        let (mut app, _, coin) = pickup_app();
+        let rock = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(CollisionStarted(rock, coin));
+        app.update();
+        assert_eq!(crate::test_support::event_count::<PickupItemEvent>(&app), 0);
+    }
+
+    /// One coin close ahead, one further ahead and one behind the camera
+    fn streaming_candidates() -> Vec<(usize, Vec3)> {
+        vec![
+            (0, Vec3::new(0.0, 0.0, -10.0)),
+            (1, Vec3::new(0.0, 0.0, 12.0)),
+            (2, Vec3::new(0.0, 0.0, 5.0)),
+        ]
+    }
+
+    fn ids(candidates: &[(usize, Vec3)]) -> Vec<usize> {
+        candidates.iter().map(|(position_id, _)| *position_id).collect()
+    }
+
+    #[test]
+    fn without_a_camera_streaming_goes_nearest_first() {
+        let mut candidates = streaming_candidates();
+        sort_by_streaming_priority(&mut candidates, Vec3::ZERO, None);
+        assert_eq!(ids(&candidates), vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn coins_in_view_stream_before_closer_ones_behind() {
+        let mut candidates = streaming_candidates();
+        let view = (Vec3::new(0.0, 3.0, -5.0), Vec3::new(0.0, -0.3, 1.0));
+        sort_by_streaming_priority(&mut candidates, Vec3::ZERO, Some(view));
+        assert_eq!(ids(&candidates), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn coins_straight_ahead_rank_by_distance_alone() {
+        let view = Some((Vec3::ZERO, Vec3::Z));
+        let position = Vec3::new(0.0, 0.0, 8.0);
+        assert_eq!(streaming_priority(position, Vec3::ZERO, view), 8.0);
+        assert!(streaming_priority(-position, Vec3::ZERO, view) > 8.0);
+    }
+
+    #[test]
+    fn spawned_book_bobs_and_spins_like_a_book() {
+        let mut world = World::new();
+        let motion_config = CollectibleMotionConfig::default();
+        let book = {
+            let mut commands = world.commands();
+            spawn_collectible(
+                &mut commands,
+                &ModelAssets::default(),
+                &CoinColliderCache(Collider::sphere(0.5)),
+                &motion_config,
+                CollectibleType::Book,
+                Vec3::new(0.0, 1.5, 0.0),
+            )
+        };
+        world.flush();
+
+        let (hover_amplitude, hover_speed, rotation_speed, clockwise) = CollectibleMotionDefaults::BOOK;
+        let floating = world.get::<FloatingItem>(book).unwrap();
+        assert_eq!(floating.base_height, 1.5);
+        assert_eq!(floating.hover_amplitude, hover_amplitude);
+        assert_eq!(floating.hover_speed, hover_speed);
+        let rotation = world.get::<CollectibleRotation>(book).unwrap();
+        assert_eq!(rotation.speed, rotation_speed);
+        assert_eq!(rotation.clockwise, clockwise);
+    }
+
+    #[test]
+    fn coins_keep_their_original_motion() {
+        let coin = CollectibleMotionConfig::default().get(CollectibleType::Coin);
+        assert_eq!(coin, CollectibleMotion::from_defaults((0.2, 2.0, 1.0, true)));
+    }
+
+    #[test]
+    fn types_missing_from_the_config_fall_back_to_the_coin() {
+        let mut motion_config = CollectibleMotionConfig::default();
+        motion_config.by_type.remove(&CollectibleType::Book);
+        assert_eq!(
+            motion_config.get(CollectibleType::Book),
+            motion_config.get(CollectibleType::Coin)
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct PickedUp(Vec<Entity>);
+
+    fn record_pickups(mut pickup_events: EventReader<PickupItemEvent>, mut picked_up: ResMut<PickedUp>) {
+        picked_up.0.extend(pickup_events.read().map(|event| event.item_entity));
+    }
+
+    /// Real physics with a dynamic player body parked away from the coins, so
+    /// pickups only happen through contacts the shared coin collider produces
+    fn physics_pickup_app() -> (App, Entity) {
+        let mut app = crate::test_support::headless_physics_app();
+        add_pickup_handler(&mut app);
+        app.insert_resource(Gravity::ZERO)
+            .init_resource::<CoinColliderCache>()
+            .init_resource::<CollectibleMotionConfig>()
+            .init_resource::<PickedUp>()
+            .add_systems(Update, record_pickups.after(handle_coin_collisions));
+        let player = app
+            .world_mut()
+            .spawn((
+                CharacterController,
+                RigidBody::Dynamic,
+                Collider::capsule(0.4, 1.0),
+                Transform::from_xyz(50.0, 0.0, 50.0),
+            ))
+            .id();
+        (app, player)
+    }
+
+    fn spawn_test_coin(app: &mut App, position: Vec3, position_id: usize) -> Entity {
+        let world = app.world_mut();
+        let collider_cache = CoinColliderCache(world.resource::<CoinColliderCache>().0.clone());
+        let motion_config = world.resource::<CollectibleMotionConfig>().clone();
+        let impostor_assets = CoinImpostorAssets {
+            mesh: Handle::default(),
+            material: Handle::default(),
+        };
+        let coin = spawn_streaming_coin(
+            &mut world.commands(),
+            &ModelAssets::default(),
+            &impostor_assets,
+            &collider_cache,
+            &motion_config,
+            position,
+            position_id,
+        );
+        world.flush();
+        coin
+    }
+
+    fn move_to(app: &mut App, entity: Entity, position: Vec3) {
+        app.world_mut().get_mut::<Transform>(entity).unwrap().translation = position;
+    }
+
+    fn coin_position(app: &App, coin: Entity) -> Vec3 {
+        app.world().get::<Transform>(coin).unwrap().translation
+    }
+
+    fn run(app: &mut App, updates: usize) {
+        for _ in 0..updates {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn coins_sharing_the_cached_collider_still_collide() {
+        let (mut app, player) = physics_pickup_app();
+        let first = spawn_test_coin(&mut app, Vec3::new(0.0, 0.0, 0.0), 0);
+        let second = spawn_test_coin(&mut app, Vec3::new(10.0, 0.0, 0.0), 1);
+        run(&mut app, 3);
+        assert!(app.world().resource::<PickedUp>().0.is_empty());
+
+        let target = coin_position(&app, first);
+        move_to(&mut app, player, target);
+        run(&mut app, 3);
+        assert_eq!(app.world().resource::<PickedUp>().0, vec![first]);
+
+        let target = coin_position(&app, second);
+        move_to(&mut app, player, target);
+        run(&mut app, 3);
+        assert_eq!(app.world().resource::<PickedUp>().0, vec![first, second]);
+    }
+
+    #[test]
+    fn coin_reused_from_the_pool_collides_again() {
+        let (mut app, player) = physics_pickup_app();
+        let coin = spawn_test_coin(&mut app, Vec3::ZERO, 0);
+        run(&mut app, 2);
+
+        app.world_mut()
+            .resource_scope(|world, mut manager: Mut<CoinStreamingManager>| {
+                manager.release_coin(&mut world.commands(), coin, 0);
+                world.flush();
+            });
+        // Parked in the pool, the coin has no collider to touch
+        let target = coin_position(&app, coin);
+        move_to(&mut app, player, target);
+        run(&mut app, 3);
+        assert!(app.world().resource::<PickedUp>().0.is_empty());
+        assert!(app.world().get::<ColliderDisabled>(coin).is_some());
+
+        move_to(&mut app, player, Vec3::new(50.0, 0.0, 50.0));
+        run(&mut app, 2);
+        {
+            let world = app.world_mut();
+            let motion_config = world.resource::<CollectibleMotionConfig>().clone();
+            reuse_streaming_coin(&mut world.commands(), &motion_config, coin, Vec3::new(20.0, 0.0, 0.0), 1);
+            world.flush();
+        }
+        assert!(app.world().get::<ColliderDisabled>(coin).is_none());
+        run(&mut app, 2);
+
+        let target = coin_position(&app, coin);
+        move_to(&mut app, player, target);
+        run(&mut app, 3);
+        assert_eq!(app.world().resource::<PickedUp>().0, vec![coin]);
+    }
+
+    /// Five coins around the origin, streaming on every update
+    fn streaming_app() -> (App, Entity) {
+        let mut manager = CoinStreamingManager {
+            update_interval: 0.0,
+            ..default()
+        };
+        for x in 0..5 {
+            manager.add_position(Vec3::new(x as f32 * 3.0, 0.0, 0.0));
+        }
+        let mut app = crate::test_support::headless_app();
+        app.insert_resource(manager)
+            .init_resource::<ModelAssets>()
+            .insert_resource(CoinImpostorAssets {
+                mesh: Handle::default(),
+                material: Handle::default(),
+            })
+            .init_resource::<CoinColliderCache>()
+            .init_resource::<CollectibleMotionConfig>()
+            .add_systems(Update, update_coin_streaming);
+        let player = app
+            .world_mut()
+            .spawn((Transform::default(), CharacterController))
+            .id();
+        (app, player)
+    }
+
+    fn coin_entities(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query_filtered::<(), With<Collectible>>().iter(world).count()
+    }
+
+    #[test]
+    fn walking_out_and_back_reuses_pooled_coins() {
+        let (mut app, player) = streaming_app();
+        app.update();
+        assert_eq!(coin_entities(&mut app), 5);
+
+        let far = Vec3::X * COIN_STREAMING_RADIUS * 3.0;
+        for trip in 1..=3 {
+            move_to(&mut app, player, far);
+            app.update();
+            assert_eq!(app.world().resource::<CoinStreamingManager>().pooled_coins.len(), 5);
+
+            move_to(&mut app, player, Vec3::ZERO);
+            app.update();
+            let manager = app.world().resource::<CoinStreamingManager>();
+            assert_eq!(manager.spawned_coins.len(), 5);
+            assert!(manager.pooled_coins.is_empty());
+            // Re-entering only ever reuses; nothing new is spawned
+            assert_eq!(manager.pool_stats.spawned, 5);
+            assert_eq!(manager.pool_stats.reused, 5 * trip);
+            assert_eq!(manager.pool_stats.released, 5 * trip);
+            assert_eq!(coin_entities(&mut app), 5);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::constants::combat::CombatConfig;
+use crate::screens::Screen;
+use crate::systems::character_controller::{AnimationState, CharacterController};
+use crate::systems::destructibles::Destructible;
+use crate::systems::enemy_ai::Enemy;
+
+// ===== COMPONENTS & RESOURCES =====
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FightMoveKind {
+    FightMove1,
+    FightMove2,
+}
+
+impl FightMoveKind {
+    pub fn damage(self) -> u32 {
+        match self {
+            FightMoveKind::FightMove1 => CombatConfig::FIGHT_MOVE_1_DAMAGE,
+            FightMoveKind::FightMove2 => CombatConfig::FIGHT_MOVE_2_DAMAGE,
+        }
+    }
+}
+
+/// Sent once per enemy or destructible caught inside a fight move's hit window
+#[derive(Event, Debug)]
+pub struct AttackHitEvent {
+    #[allow(dead_code)] // For knockback once it lands
+    pub attacker: Entity,
+    pub target: Entity,
+    pub fight_move: FightMoveKind,
+}
+
+/// Tracks a fight move in progress so each target is only hit once per swing
+#[derive(Component)]
+pub struct ActiveAttack {
+    pub fight_move: FightMoveKind,
+    pub elapsed: f32,
+    pub hit_entities: Vec<Entity>,
+}
+
+// ===== PLUGIN =====
+
+pub struct CombatPlugin;
+
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AttackHitEvent>().add_systems(
+            Update,
+            (track_attack_windows, detect_attack_hits)
+                .chain()
+                // Destructible props live in the regular level, so swings connect there too
+                .run_if(in_state(Screen::FightScene).or(in_state(Screen::GamePlay))),
+        );
+    }
+}
+
+// ===== HELPERS =====
+
+/// Whether `target` is within `range` of `attacker` and inside the arc around
+/// `forward`. Only the ground plane counts, so height differences don't matter.
+pub fn in_attack_arc(attacker: Vec3, forward: Vec3, target: Vec3, range: f32, arc_degrees: f32) -> bool {
+    let offset = Vec2::new(target.x - attacker.x, target.z - attacker.z);
+    let distance = offset.length();
+    if distance > range {
+        return false;
+    }
+    if distance <= f32::EPSILON {
+        return true; // Standing on top of each other always connects
+    }
+
+    let facing = Vec2::new(forward.x, forward.z).normalize_or_zero();
+    let half_arc = (arc_degrees / 2.0).to_radians();
+    facing.dot(offset / distance) >= half_arc.cos()
+}
+
+// ===== SYSTEMS =====
+
+/// Starts/ends an `ActiveAttack` alongside the fight move animation flags
+fn track_attack_windows(
+    mut commands: Commands,
+    attackers: Query<(Entity, &AnimationState, Option<&ActiveAttack>), With<CharacterController>>,
+) {
+    for (entity, animation_state, active_attack) in &attackers {
+        let fight_move = if animation_state.fight_move_1 {
+            Some(FightMoveKind::FightMove1)
+        } else if animation_state.fight_move_2 {
+            Some(FightMoveKind::FightMove2)
+        } else {
+            None
+        };
+
+        match (fight_move, active_attack) {
+            (Some(fight_move), None) => {
+                commands.entity(entity).insert(ActiveAttack {
+                    fight_move,
+                    elapsed: 0.0,
+                    hit_entities: Vec::new(),
+                });
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<ActiveAttack>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Overlap query in front of the attacker during the active part of the move
+fn detect_attack_hits(
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    mut attackers: Query<(Entity, &Transform, &mut ActiveAttack), With<CharacterController>>,
+    targets: Query<
+        &Transform,
+        (Or<(With<Enemy>, With<Destructible>)>, Without<CharacterController>),
+    >,
+    mut hit_events: EventWriter<AttackHitEvent>,
+) {
+    for (attacker, transform, mut attack) in &mut attackers {
+        attack.elapsed += time.delta_secs();
+        if attack.elapsed < CombatConfig::ATTACK_WINDOW_START
+            || attack.elapsed > CombatConfig::ATTACK_WINDOW_END
+        {
+            continue;
+        }
+
+        // Characters face their local +Z (see movement in the character controller)
+        let forward = transform.rotation * Vec3::Z;
+        let candidates = spatial_query.shape_intersections(
+            &Collider::sphere(CombatConfig::ATTACK_RANGE),
+            transform.translation,
+            Quat::IDENTITY,
+            &SpatialQueryFilter::default().with_excluded_entities([attacker]),
+        );
+
+        for target in candidates {
+            if attack.hit_entities.contains(&target) {
+                continue;
+            }
+            let Ok(target_transform) = targets.get(target) else {
+                continue;
+            };
+            if in_attack_arc(
+                transform.translation,
+                forward,
+                target_transform.translation,
+                CombatConfig::ATTACK_RANGE,
+                CombatConfig::ATTACK_ARC_DEGREES,
+            ) {
+                attack.hit_entities.push(target);
+                hit_events.write(AttackHitEvent {
+                    attacker,
+                    target,
+                    fight_move: attack.fight_move,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hits(target: Vec3) -> bool {
+        in_attack_arc(
+            Vec3::ZERO,
+            Vec3::NEG_Z,
+            target,
+            CombatConfig::ATTACK_RANGE,
+            CombatConfig::ATTACK_ARC_DEGREES,
+        )
+    }
+
+    #[test]
+    fn target_straight_ahead_within_range_is_hit() {
+        assert!(hits(Vec3::new(0.0, 0.0, -2.0)));
+        assert!(hits(Vec3::new(0.0, 0.0, -CombatConfig::ATTACK_RANGE)));
+    }
+
+    #[test]
+    fn target_beyond_range_is_missed() {
+        assert!(!hits(Vec3::new(0.0, 0.0, -(CombatConfig::ATTACK_RANGE + 0.1))));
+    }
+
+    #[test]
+    fn targets_outside_the_arc_are_missed() {
+        // The arc is 100 degrees, 50 to either side of facing
+        let inside = (40.0f32).to_radians();
+        let outside = (60.0f32).to_radians();
+        assert!(hits(Vec3::new(inside.sin(), 0.0, -inside.cos()) * 2.0));
+        assert!(!hits(Vec3::new(outside.sin(), 0.0, -outside.cos()) * 2.0));
+        assert!(!hits(Vec3::new(0.0, 0.0, 2.0)));
+    }
+
+    #[test]
+    fn height_difference_is_ignored() {
+        assert!(hits(Vec3::new(0.0, 10.0, -2.0)));
+    }
+
+    #[test]
+    fn overlapping_target_is_always_hit() {
+        assert!(hits(Vec3::ZERO));
+    }
+}
@@ -0,0 +1,183 @@
+//! Breakable props such as crates and barrels. Fight moves wear their health
+//! down, and the hit that breaks one scatters its drops as regular
+//! collectibles, so picking them up goes through the usual collection pipeline.
+
+use bevy::prelude::*;
+
+use crate::assets::ModelAssets;
+use crate::constants::combat::DestructibleConfig;
+use crate::screens::Screen;
+use crate::systems::collectibles::{
+    CoinColliderCache, CollectibleMotionConfig, CollectibleType, spawn_collectible,
+};
+use crate::systems::combat::AttackHitEvent;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// A prop that breaks after taking `health` damage. It needs a collider for
+/// fight moves to find it.
+#[derive(Component, Debug, Clone)]
+pub struct Destructible {
+    pub health: u32,
+    pub drops: Vec<CollectibleType>,
+}
+
+impl Destructible {
+    /// Takes `damage` off the remaining health. Returns the drops on the hit
+    /// that breaks the prop, and None on every other hit, including ones
+    /// landing after it has already broken.
+    pub fn take_hit(&mut self, damage: u32) -> Option<Vec<CollectibleType>> {
+        if self.health == 0 {
+            return None;
+        }
+        self.health = self.health.saturating_sub(damage);
+        (self.health == 0).then(|| std::mem::take(&mut self.drops))
+    }
+}
+
+/// Sent when a destructible breaks, for effects
+#[derive(Event, Debug)]
+pub struct DestructibleDestroyed {
+    #[allow(dead_code)] // Already despawned; kept for logging and future listeners
+    pub entity: Entity,
+    pub position: Vec3,
+}
+
+/// Collectibles that came out of a destructible rather than the level layout
+#[derive(Component)]
+pub struct DroppedCollectible;
+
+/// Where drop `index` of `count` lands: a lone drop floats right above the
+/// prop, several are spaced evenly around it
+pub fn drop_position(origin: Vec3, index: usize, count: usize) -> Vec3 {
+    let above = origin + Vec3::Y * DestructibleConfig::DROP_HEIGHT;
+    if count <= 1 {
+        return above;
+    }
+    let angle = index as f32 / count as f32 * std::f32::consts::TAU;
+    above + Vec3::new(angle.cos(), 0.0, angle.sin()) * DestructibleConfig::DROP_SCATTER_RADIUS
+}
+
+// ===== PLUGIN =====
+
+pub struct DestructiblesPlugin;
+
+impl Plugin for DestructiblesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DestructibleDestroyed>()
+            .add_systems(
+                Update,
+                damage_destructibles.run_if(in_state(Screen::GamePlay)),
+            )
+            .add_observer(clear_drops_on_restart);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn damage_destructibles(
+    mut commands: Commands,
+    mut hit_events: EventReader<AttackHitEvent>,
+    mut destructibles: Query<(&mut Destructible, &Transform)>,
+    assets: Res<ModelAssets>,
+    collider_cache: Res<CoinColliderCache>,
+    motion_config: Res<CollectibleMotionConfig>,
+    mut destroyed_events: EventWriter<DestructibleDestroyed>,
+) {
+    for event in hit_events.read() {
+        let Ok((mut destructible, transform)) = destructibles.get_mut(event.target) else {
+            continue;
+        };
+        let Some(drops) = destructible.take_hit(event.fight_move.damage()) else {
+            continue;
+        };
+
+        let origin = transform.translation;
+        for (index, item_type) in drops.iter().enumerate() {
+            let position = drop_position(origin, index, drops.len());
+            let drop = spawn_collectible(
+                &mut commands,
+                &assets,
+                &collider_cache,
+                &motion_config,
+                *item_type,
+                position,
+            );
+            commands.entity(drop).insert(DroppedCollectible);
+        }
+
+        info!("Destructible broke, dropping {} items", drops.len());
+        commands.entity(event.target).despawn();
+        destroyed_events.write(DestructibleDestroyed {
+            entity: event.target,
+            position: origin,
+        });
+    }
+}
+
+/// Uncollected drops go with the restart; the props themselves are respawned
+/// with the rest of the level data
+fn clear_drops_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut commands: Commands,
+    drop_query: Query<Entity, With<DroppedCollectible>>,
+) {
+    if trigger.0 != ConfirmAction::RestartLevel {
+        return;
+    }
+    for entity in &drop_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crate_with_drops() -> Destructible {
+        Destructible {
+            health: 20,
+            drops: vec![CollectibleType::Coin, CollectibleType::HealthPotion],
+        }
+    }
+
+    #[test]
+    fn lethal_hit_releases_the_drops() {
+        let mut destructible = crate_with_drops();
+        assert_eq!(
+            destructible.take_hit(25),
+            Some(vec![CollectibleType::Coin, CollectibleType::HealthPotion])
+        );
+        assert_eq!(destructible.health, 0);
+    }
+
+    #[test]
+    fn non_lethal_hits_drop_nothing() {
+        let mut destructible = crate_with_drops();
+        assert_eq!(destructible.take_hit(5), None);
+        assert_eq!(destructible.health, 15);
+        // Exactly the remaining health still breaks it
+        assert!(destructible.take_hit(15).is_some());
+    }
+
+    #[test]
+    fn broken_props_never_drop_twice() {
+        let mut destructible = crate_with_drops();
+        destructible.take_hit(100);
+        assert_eq!(destructible.take_hit(100), None);
+    }
+
+    #[test]
+    fn several_drops_scatter_around_the_prop() {
+        let origin = Vec3::new(4.0, 1.0, -2.0);
+        let above = origin + Vec3::Y * DestructibleConfig::DROP_HEIGHT;
+        assert_eq!(drop_position(origin, 0, 1), above);
+
+        let first = drop_position(origin, 0, 2);
+        let second = drop_position(origin, 1, 2);
+        assert!((first.distance(above) - DestructibleConfig::DROP_SCATTER_RADIUS).abs() < 1e-4);
+        assert!((second.distance(above) - DestructibleConfig::DROP_SCATTER_RADIUS).abs() < 1e-4);
+        assert!(first.distance(second) > DestructibleConfig::DROP_SCATTER_RADIUS);
+    }
+}
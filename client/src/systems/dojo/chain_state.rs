@@ -0,0 +1,167 @@
+use crate::constants::dojo::{ChainPollConfig, UUID_SELECTOR};
+use bevy::prelude::*;
+use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use starknet::accounts::ConnectedAccount;
+use starknet::core::types::{BlockId, BlockTag, FunctionCall};
+use starknet::providers::Provider;
+
+use super::felt::first_felt_as;
+use super::{DojoRequestSettings, RetryPolicy, TaskPoll, TimedTask};
+
+/// Latest state read from the world contract, complementing `DojoSystemState`
+#[derive(Resource, Debug, Default)]
+pub struct ChainState {
+    /// Current value of the world's uuid counter
+    pub uuid: Option<u32>,
+    /// `Time::elapsed_secs` of the last successful read
+    pub last_updated: Option<f32>,
+    pub last_error: Option<String>,
+    pub consecutive_errors: u32,
+}
+
+/// How often `ChainState` is refreshed
+#[derive(Resource, Debug, Clone)]
+pub struct ChainPollSettings {
+    pub interval_secs: f32,
+}
+
+impl Default for ChainPollSettings {
+    fn default() -> Self {
+        Self {
+            interval_secs: ChainPollConfig::INTERVAL_SECS,
+        }
+    }
+}
+
+impl ChainPollSettings {
+    /// Wait before the next read; after errors this backs off along the retry policy
+    pub fn delay_after(&self, consecutive_errors: u32, retry_policy: &RetryPolicy) -> f32 {
+        match consecutive_errors {
+            0 => self.interval_secs,
+            errors => retry_policy.delay(errors - 1).max(self.interval_secs),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct ChainPoll {
+    timer: Timer,
+    task: Option<TimedTask<Result<u32, String>>>,
+}
+
+impl Default for ChainPoll {
+    fn default() -> Self {
+        // Read once straight after connecting
+        Self {
+            timer: Timer::from_seconds(0.0, TimerMode::Once),
+            task: None,
+        }
+    }
+}
+
+/// Polls the world contract into `ChainState`. Inert until `dojo::plugin` is enabled in `main.rs`
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ChainState>()
+        .init_resource::<ChainPollSettings>()
+        .init_resource::<ChainPoll>()
+        .add_systems(Update, (start_chain_poll, finish_chain_poll).chain());
+}
+
+/// Spawns a read of the world's uuid once the poll timer runs out and an account is connected
+fn start_chain_poll(
+    time: Res<Time<Real>>,
+    dojo: Res<DojoResource>,
+    dojo_state: Res<super::DojoSystemState>,
+    tokio: Res<TokioRuntime>,
+    request_settings: Res<DojoRequestSettings>,
+    mut poll: ResMut<ChainPoll>,
+) {
+    if poll.task.is_some() || !poll.timer.tick(time.delta()).finished() {
+        return;
+    }
+    let Some(account) = dojo.sn.account.clone() else {
+        return;
+    };
+
+    let request = FunctionCall {
+        contract_address: dojo_state.config.world_address,
+        entry_point_selector: UUID_SELECTOR,
+        calldata: vec![],
+    };
+    let handle = tokio.runtime.spawn(async move {
+        let values = account
+            .provider()
+            .call(request, BlockId::Tag(BlockTag::Latest))
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        first_felt_as::<u32>(&values)
+    });
+    poll.task = Some(TimedTask::new(handle, &request_settings));
+}
+
+/// Stores the finished read in `ChainState` and schedules the next one, backing off on errors
+fn finish_chain_poll(
+    time: Res<Time<Real>>,
+    settings: Res<ChainPollSettings>,
+    retry_policy: Res<RetryPolicy>,
+    mut poll: ResMut<ChainPoll>,
+    mut chain_state: ResMut<ChainState>,
+) {
+    let Some(task) = poll.task.as_mut() else {
+        return;
+    };
+    let result = match task.poll(&time) {
+        TaskPoll::Pending => return,
+        TaskPoll::TimedOut => Err("Request timed out".to_string()),
+        TaskPoll::Finished(Ok(result)) => result,
+        TaskPoll::Finished(Err(join_err)) => Err(format!("{:?}", join_err)),
+    };
+    poll.task = None;
+
+    match result {
+        Ok(uuid) => {
+            chain_state.uuid = Some(uuid);
+            chain_state.last_updated = Some(time.elapsed_secs());
+            chain_state.last_error = None;
+            chain_state.consecutive_errors = 0;
+        }
+        Err(error) => {
+            warn!("Chain state read failed: {}", error);
+            chain_state.last_error = Some(error);
+            chain_state.consecutive_errors += 1;
+        }
+    }
+
+    let delay = settings.delay_after(chain_state.consecutive_errors, &retry_policy);
+    poll.timer = Timer::from_seconds(delay, TimerMode::Once);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steady_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay_secs: 2.0,
+            max_delay_secs: 120.0,
+            jitter: 0.0,
+        }
+    }
+
+    #[test]
+    fn polls_on_the_interval_while_reads_succeed() {
+        let settings = ChainPollSettings { interval_secs: 10.0 };
+        assert_eq!(settings.delay_after(0, &steady_policy()), 10.0);
+    }
+
+    #[test]
+    fn repeated_errors_back_off_up_to_the_cap() {
+        let settings = ChainPollSettings { interval_secs: 10.0 };
+        let schedule: Vec<f32> = (1..=8)
+            .map(|errors| settings.delay_after(errors, &steady_policy()))
+            .collect();
+        // Never polls faster than the interval, then doubles until the policy's cap
+        assert_eq!(schedule, vec![10.0, 10.0, 10.0, 16.0, 32.0, 64.0, 120.0, 120.0]);
+    }
+}
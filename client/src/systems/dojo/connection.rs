@@ -0,0 +1,85 @@
+use crate::constants::dojo::ChainPollConfig;
+use bevy::prelude::*;
+use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+
+use super::chain_state::ChainState;
+use super::{DojoSystemState, RetryPolicy};
+
+/// Reconnect attempts made since the account was last known to be good
+#[derive(Resource, Default)]
+struct ReconnectState {
+    attempts: u32,
+    timer: Option<Timer>,
+}
+
+/// Tracks account connectivity and reconnects with backoff. Inert until `dojo::plugin` is enabled in `main.rs`
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ReconnectState>().add_systems(
+        Update,
+        (track_account_connection, reconnect_account).chain(),
+    );
+}
+
+/// Derives `account_connected` from measured state: an account exists and its reads succeed
+fn track_account_connection(
+    dojo: Res<DojoResource>,
+    chain_state: Res<ChainState>,
+    mut dojo_state: ResMut<DojoSystemState>,
+) {
+    let reads_failing = chain_state.consecutive_errors >= ChainPollConfig::DISCONNECT_AFTER_ERRORS;
+    let connected =
+        dojo.sn.account.is_some() && chain_state.last_updated.is_some() && !reads_failing;
+
+    // Only write on a real change, `log_dojo_status` runs whenever the state changes
+    if dojo_state.account_connected != connected {
+        dojo_state.account_connected = connected;
+        if connected {
+            dojo_state.last_error = None;
+        }
+    }
+    if reads_failing && dojo_state.last_error != chain_state.last_error {
+        dojo_state.last_error = chain_state.last_error.clone();
+    }
+}
+
+/// Reconnects the dev account once reads keep failing, backing off so a permanently
+/// offline node isn't hammered
+fn reconnect_account(
+    time: Res<Time<Real>>,
+    tokio: Res<TokioRuntime>,
+    retry_policy: Res<RetryPolicy>,
+    chain_state: Res<ChainState>,
+    mut dojo: ResMut<DojoResource>,
+    dojo_state: Res<DojoSystemState>,
+    mut reconnect: ResMut<ReconnectState>,
+) {
+    let config = &dojo_state.config;
+    if !config.use_dev_account
+        || chain_state.consecutive_errors < ChainPollConfig::DISCONNECT_AFTER_ERRORS
+    {
+        if reconnect.attempts > 0 || reconnect.timer.is_some() {
+            *reconnect = ReconnectState::default();
+        }
+        return;
+    }
+
+    let attempts = reconnect.attempts;
+    let timer = reconnect
+        .timer
+        .get_or_insert_with(|| Timer::from_seconds(retry_policy.delay(attempts), TimerMode::Once));
+    if !timer.tick(time.delta()).finished() {
+        return;
+    }
+
+    reconnect.attempts += 1;
+    reconnect.timer = None;
+    warn!(
+        "Blockchain account unreachable, reconnecting (attempt {})",
+        reconnect.attempts
+    );
+    dojo.connect_predeployed_account(
+        &tokio,
+        config.katana_url.clone(),
+        config.dev_account_index as usize,
+    );
+}
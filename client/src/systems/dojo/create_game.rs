@@ -1,5 +1,9 @@
+use crate::assets::FontAssets;
 use crate::constants::dojo::CREATE_GAME_SELECTOR;
 use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::ui::toast::{Toast, spawn_toast};
 use bevy::prelude::*;
 use dojo_bevy_plugin::{DojoEntityUpdated, DojoResource, TokioRuntime};
 use starknet::core::types::Call;
@@ -36,6 +40,10 @@ pub struct GameState {
     pub subscribed_to_entities: bool,
 }
 
+/// "Creating game..." text shown while the create_game transaction is in flight
+#[derive(Component)]
+pub struct CreatingGameIndicator;
+
 /// Represents a Game entity from the blockchain
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -53,6 +61,7 @@ pub struct GameDataReceivedEvent {
     pub game: GameEntity,
 }
 
+/// Handles the G create-game key. Inert until `dojo::plugin` is enabled in `main.rs`
 #[allow(dead_code)]
 pub(super) fn plugin(app: &mut App) {
     app.add_event::<CreateGameEvent>()
@@ -70,6 +79,7 @@ pub(super) fn plugin(app: &mut App) {
                 subscribe_to_game_entities,
                 handle_dojo_entity_updates,
                 fetch_game_data_after_creation,
+                update_creating_game_indicator,
             )
                 .run_if(in_state(Screen::GamePlay)),
         );
@@ -137,8 +147,11 @@ fn handle_create_game_events(
 /// System to handle successful game creation
 #[allow(dead_code)]
 fn handle_game_created_events(
+    mut commands: Commands,
     mut events: EventReader<GameCreatedEvent>,
     mut game_state: ResMut<GameState>,
+    toast_query: Query<Entity, With<Toast>>,
+    font_assets: Res<FontAssets>,
 ) {
     for event in events.read() {
         info!(
@@ -150,7 +163,13 @@ fn handle_game_created_events(
         game_state.player_address = Some(event.player_address.clone());
         game_state.is_creating_game = false;
 
-        // TODO: You could trigger UI updates here, or initialize level 1
+        spawn_toast(
+            &mut commands,
+            &font_assets,
+            &toast_query,
+            format!("Game #{} created on chain", event.game_id),
+            Color::SUCCESS_GREEN,
+        );
         info!("Game state updated - ready to start playing!");
     }
 }
@@ -158,14 +177,23 @@ fn handle_game_created_events(
 /// System to handle failed game creation
 #[allow(dead_code)]
 fn handle_game_creation_failed_events(
+    mut commands: Commands,
     mut events: EventReader<GameCreationFailedEvent>,
     mut game_state: ResMut<GameState>,
+    toast_query: Query<Entity, With<Toast>>,
+    font_assets: Res<FontAssets>,
 ) {
     for event in events.read() {
         error!("Game creation failed: {}", event.error);
         game_state.is_creating_game = false;
 
-        // TODO: Show error message to user
+        spawn_toast(
+            &mut commands,
+            &font_assets,
+            &toast_query,
+            "Could not create game on chain",
+            Color::DANGER_RED,
+        );
         // TODO: Optionally retry after a delay
     }
 }
@@ -267,3 +295,46 @@ fn fetch_game_data_after_creation(
         }
     }
 }
+
+/// Shows the in-flight indicator for as long as a game is being created
+fn update_creating_game_indicator(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    indicator_query: Query<Entity, With<CreatingGameIndicator>>,
+    font_assets: Res<FontAssets>,
+) {
+    if !game_state.is_changed() {
+        return;
+    }
+
+    match (game_state.is_creating_game, indicator_query.single()) {
+        (true, Err(_)) => {
+            commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(64.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                Pickable::IGNORE,
+                Name::new("Creating Game Indicator"),
+                CreatingGameIndicator,
+                PlayingScene,
+                children![(
+                    Text::new("Creating game on chain..."),
+                    TextFont {
+                        font: font_assets.rajdhani_medium.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::ELYSIUM_GOLD),
+                )],
+            ));
+        }
+        (false, Ok(entity)) => {
+            commands.entity(entity).despawn();
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,84 @@
+use starknet::core::types::Felt;
+
+/// Parses a `0x`-prefixed hex felt, rejecting anything that isn't one
+#[allow(dead_code)]
+pub fn parse_felt(value: &str) -> Result<Felt, String> {
+    let value = value.trim();
+    let Some(digits) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    else {
+        return Err(format!("Felt `{}` is missing the 0x prefix", value));
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Felt `{}` is not valid hex", value));
+    }
+    Felt::from_hex(value).map_err(|e| format!("Felt `{}` is out of range: {:?}", value, e))
+}
+
+/// Parses a hex felt into a primitive integer, e.g. a game id or score
+#[allow(dead_code)]
+pub fn parse_felt_as<T: TryFrom<Felt>>(value: &str) -> Result<T, String> {
+    let felt = parse_felt(value)?;
+    T::try_from(felt).map_err(|_| {
+        format!(
+            "Felt `{}` does not fit in {}",
+            value,
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+/// First value of a contract call result, for reads that return a single felt
+#[allow(dead_code)]
+pub fn first_felt_as<T: TryFrom<Felt>>(values: &[Felt]) -> Result<T, String> {
+    let Some(felt) = values.first() else {
+        return Err("Contract call returned no values".to_string());
+    };
+    T::try_from(*felt).map_err(|_| {
+        format!(
+            "Felt {:#x} does not fit in {}",
+            felt,
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_hex_felts_parse() {
+        assert_eq!(parse_felt("0x2a"), Ok(Felt::from(42u32)));
+        assert_eq!(parse_felt(" 0X2A "), Ok(Felt::from(42u32)));
+        assert_eq!(parse_felt_as::<u128>("0xff"), Ok(255));
+    }
+
+    #[test]
+    fn zero_parses() {
+        assert_eq!(parse_felt("0x0"), Ok(Felt::ZERO));
+        assert_eq!(parse_felt_as::<u32>("0x0"), Ok(0));
+    }
+
+    #[test]
+    fn malformed_felts_are_rejected() {
+        assert!(parse_felt("42").is_err());
+        assert!(parse_felt("0x").is_err());
+        assert!(parse_felt("0xzz").is_err());
+        assert!(parse_felt("Unknown").is_err());
+        // More than 252 bits
+        assert!(parse_felt(&format!("0x{}", "f".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn values_too_large_for_the_target_type_are_rejected() {
+        assert!(parse_felt_as::<u8>("0x100").is_err());
+    }
+
+    #[test]
+    fn first_felt_needs_a_value() {
+        assert!(first_felt_as::<u32>(&[]).is_err());
+        assert_eq!(first_felt_as::<u32>(&[Felt::from(7u32), Felt::ONE]), Ok(7));
+    }
+}
@@ -1,12 +1,22 @@
-use crate::constants::dojo::DojoConfig;
+use crate::constants::dojo::{DOJO_REQUEST_TIMEOUT_SECS, DojoConfig, RetryConfig};
 use bevy::prelude::*;
 use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use futures::FutureExt;
+use rand::Rng;
+use starknet::accounts::{Account, ConnectedAccount};
+use starknet::core::types::Call;
+use tokio::task::{JoinError, JoinHandle};
 
+pub mod chain_state;
+pub mod connection;
 pub mod create_game;
+pub mod felt;
 pub mod pickup_item;
+pub mod submit_score;
 
 pub use create_game::CreateGameEvent;
 pub use pickup_item::PickupItemEvent;
+pub use submit_score::SubmitScoreEvent;
 
 /// Resource to track overall Dojo system state
 #[derive(Resource, Debug, Default)]
@@ -21,20 +31,136 @@ pub struct DojoSystemState {
     pub config: DojoConfig,
 }
 
+/// Limits for the background tasks that talk to the chain; only inserted by `plugin`,
+/// so `TimedTask` timeouts stay inert until it is enabled
+#[derive(Resource, Debug, Clone)]
+pub struct DojoRequestSettings {
+    pub timeout_secs: f32,
+}
+
+impl Default for DojoRequestSettings {
+    fn default() -> Self {
+        Self {
+            timeout_secs: DOJO_REQUEST_TIMEOUT_SECS,
+        }
+    }
+}
+
+/// How failed network calls are retried, shared by every system that retries
+#[derive(Resource, Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay_secs: f32,
+    pub max_delay_secs: f32,
+    /// Fraction of each delay added or removed at random, so clients don't retry in lockstep
+    pub jitter: f32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: RetryConfig::MAX_RETRIES,
+            base_delay_secs: RetryConfig::BASE_DELAY_SECS,
+            max_delay_secs: RetryConfig::MAX_DELAY_SECS,
+            jitter: RetryConfig::JITTER,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry `attempt` (starting at 0) before jitter: exponential, capped
+    pub fn base_delay(&self, attempt: u32) -> f32 {
+        let factor = 2f32.powi(attempt.min(16) as i32);
+        (self.base_delay_secs * factor).min(self.max_delay_secs)
+    }
+
+    /// `base_delay` spread by the jitter fraction, never above the cap
+    pub fn delay(&self, attempt: u32) -> f32 {
+        let delay = self.base_delay(attempt);
+        let spread = delay * self.jitter.clamp(0.0, 1.0);
+        let jittered = delay + rand::rng().random_range(-spread..=spread);
+        jittered.clamp(0.0, self.max_delay_secs)
+    }
+
+    pub fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_retries
+    }
+}
+
+/// A spawned blockchain task that gets aborted once it outlives the request timeout,
+/// so a stalled RPC can't leave tasks and connections piling up
+pub struct TimedTask<T> {
+    handle: JoinHandle<T>,
+    timer: Timer,
+}
+
+pub enum TaskPoll<T> {
+    Pending,
+    Finished(Result<T, JoinError>),
+    TimedOut,
+}
+
+impl<T> TimedTask<T> {
+    pub fn new(handle: JoinHandle<T>, settings: &DojoRequestSettings) -> Self {
+        Self {
+            handle,
+            timer: Timer::from_seconds(settings.timeout_secs, TimerMode::Once),
+        }
+    }
+
+    pub fn poll(&mut self, time: &Time<Real>) -> TaskPoll<T> {
+        if let Some(result) = (&mut self.handle).now_or_never() {
+            return TaskPoll::Finished(result);
+        }
+        if self.timer.tick(time.delta()).finished() {
+            self.handle.abort();
+            return TaskPoll::TimedOut;
+        }
+        TaskPoll::Pending
+    }
+}
+
+/// Signs and sends `calls` with the connected account, returning the transaction hash.
+/// Every transaction the client sends goes through here so they fail the same way.
+pub async fn execute_calls<A>(account: Option<&A>, calls: Vec<Call>) -> Result<String, String>
+where
+    A: ConnectedAccount + Sync,
+{
+    let Some(account) = account else {
+        return Err("No account available".to_string());
+    };
+    match account.execute_v3(calls).send().await {
+        Ok(result) => Ok(format!("{:#x}", result.transaction_hash)),
+        Err(e) => Err(format!("{:?}", e)),
+    }
+}
+
+/// Everything that talks to the chain: connecting, polling, create-game, pickups and
+/// score submission. Not added in `main.rs` while Dojo is disabled, so none of these
+/// systems run; only the connection badge (added separately) shows the offline state.
 #[allow(dead_code)]
 pub fn plugin(app: &mut App) {
     app.init_resource::<DojoSystemState>()
+        .init_resource::<DojoRequestSettings>()
+        .init_resource::<RetryPolicy>()
         .add_systems(Startup, (setup_dojo_config, handle_dojo_setup).chain())
         .add_systems(
             Update,
             log_dojo_status.run_if(resource_changed::<DojoSystemState>),
         )
-        .add_plugins((create_game::plugin, pickup_item::plugin));
+        .add_plugins((
+            chain_state::plugin,
+            connection::plugin,
+            create_game::plugin,
+            pickup_item::plugin,
+            submit_score::plugin,
+        ));
 }
 
 #[allow(dead_code)]
 fn setup_dojo_config(mut dojo_state: ResMut<DojoSystemState>) {
     dojo_state.config = DojoConfig::default();
+    dojo_state.config.validate();
     info!("Dojo configuration loaded: {:?}", dojo_state.config);
 
     // Warn about development account usage
@@ -72,22 +198,14 @@ fn handle_dojo_setup(
             config.katana_url.clone(),
             config.dev_account_index as usize,
         );
-        // Note: Connection success is assumed for now as the dojo plugin doesn't provide error feedback
-        info!("Katana account connection initiated successfully");
-        dojo_state.account_connected = true;
+        // `connection::track_account_connection` flips this once the account answers reads
+        info!("Katana account connection initiated");
     } else {
         info!("Development account disabled - manual account connection required");
     }
 
-    // Log overall connection status
-    if dojo_state.torii_connected && (dojo_state.account_connected || !config.use_dev_account) {
-        info!("Dojo blockchain integration initialized successfully");
-        info!("🎮 Press 'G' to create a new game on the blockchain!");
-    } else {
-        warn!(
-            "Dojo blockchain integration has connection issues - game may have limited functionality"
-        );
-    }
+    info!("Dojo blockchain integration initialized, waiting for the account to respond");
+    info!("🎮 Press 'G' to create a new game on the blockchain once connected!");
 }
 
 /// System to log Dojo status changes for user feedback
@@ -106,3 +224,66 @@ fn log_dojo_status(dojo_state: Res<DojoSystemState>) {
 
     info!("🔗 Dojo Status: {}", connection_status);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sending_without_an_account_fails_with_a_readable_error() {
+        use starknet::accounts::SingleOwnerAccount;
+        use starknet::providers::JsonRpcClient;
+        use starknet::providers::jsonrpc::HttpTransport;
+        use starknet::signers::LocalWallet;
+
+        type DevAccount = SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>;
+        let result = futures::executor::block_on(execute_calls::<DevAccount>(None, Vec::new()));
+        assert_eq!(result, Err("No account available".to_string()));
+    }
+
+    fn test_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay_secs: 1.0,
+            max_delay_secs: 10.0,
+            jitter: 0.25,
+        }
+    }
+
+    #[test]
+    fn base_delay_doubles_until_the_cap() {
+        let policy = test_policy();
+        let delays: Vec<f32> = (0..6).map(|attempt| policy.base_delay(attempt)).collect();
+        assert_eq!(delays, vec![1.0, 2.0, 4.0, 8.0, 10.0, 10.0]);
+        assert_eq!(policy.base_delay(u32::MAX), 10.0);
+    }
+
+    #[test]
+    fn jittered_delay_stays_within_bounds() {
+        let policy = test_policy();
+        for attempt in 0..6 {
+            let base = policy.base_delay(attempt);
+            for _ in 0..100 {
+                let delay = policy.delay(attempt);
+                assert!(delay >= base * 0.75 - 1e-4, "{} below {}", delay, base * 0.75);
+                assert!(delay <= (base * 1.25).min(policy.max_delay_secs) + 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn no_jitter_gives_the_base_delay() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..test_policy()
+        };
+        assert_eq!(policy.delay(2), 4.0);
+    }
+
+    #[test]
+    fn retries_stop_at_max_retries() {
+        let policy = test_policy();
+        assert!(policy.should_retry(2));
+        assert!(!policy.should_retry(3));
+    }
+}
@@ -4,19 +4,24 @@ use crate::systems::collectibles::CollectibleType;
 use bevy::prelude::*;
 use dojo_bevy_plugin::TokioRuntime;
 use dojo_bevy_plugin::{DojoEntityUpdated, DojoResource};
-use futures::FutureExt;
-use starknet::accounts::Account;
 use starknet::core::types::Call;
-use tokio::task::JoinHandle;
 
-/// Event to trigger item pickup on the blockchain
+use super::{DojoRequestSettings, TaskPoll, TimedTask, execute_calls};
+
+/// Sent once per collected item. Besides triggering the pickup on the blockchain, this is
+/// the hook for anything reacting to pickups (objectives, sfx, achievements), so the
+/// collision handler doesn't need to know about them.
 #[derive(Event, Debug)]
 #[allow(dead_code)]
 pub struct PickupItemEvent {
     #[allow(dead_code)]
     pub item_type: CollectibleType,
+    /// May already be despawned or back in the coin pool by the time consumers run
     #[allow(dead_code)]
     pub item_entity: Entity,
+    /// Where the item was when it was picked up
+    #[allow(dead_code)]
+    pub position: Vec3,
 }
 
 /// Event emitted when an item pickup is successfully processed on blockchain
@@ -49,9 +54,10 @@ pub struct PickupTransactionState {
 #[derive(Resource, Default)]
 pub struct PendingPickupTasks(
     #[allow(dead_code)]
-    pub  Vec<
-        JoinHandle<Result<(Entity, CollectibleType, String), (Entity, CollectibleType, String)>>,
-    >,
+    pub  Vec<(
+        CollectibleType,
+        TimedTask<Result<(Entity, CollectibleType, String), (Entity, CollectibleType, String)>>,
+    )>,
 );
 
 #[allow(dead_code)]
@@ -81,6 +87,7 @@ fn handle_pickup_item_events(
     dojo: Res<DojoResource>,
     dojo_config: Res<super::DojoSystemState>,
     tokio: Res<TokioRuntime>,
+    request_settings: Res<DojoRequestSettings>,
     mut pending_tasks: ResMut<PendingPickupTasks>,
 ) {
     let account = dojo.sn.account.clone();
@@ -94,19 +101,14 @@ fn handle_pickup_item_events(
         let item_type = event.item_type;
         let account = account.clone();
         let handle = tokio.runtime.spawn(async move {
-            if let Some(account) = account {
-                let tx = account.execute_v3(vec![call]);
-                match tx.send().await {
-                    Ok(result) => {
-                        Ok((entity, item_type, format!("{:#x}", result.transaction_hash)))
-                    }
-                    Err(e) => Err((entity, item_type, format!("{:?}", e))),
-                }
-            } else {
-                Err((entity, item_type, "No account available".to_string()))
-            }
+            execute_calls(account.as_deref(), vec![call])
+                .await
+                .map(|tx_hash| (entity, item_type, tx_hash))
+                .map_err(|e| (entity, item_type, e))
         });
-        pending_tasks.0.push(handle);
+        pending_tasks
+            .0
+            .push((item_type, TimedTask::new(handle, &request_settings)));
     }
 }
 
@@ -184,38 +186,46 @@ fn handle_pickup_entity_updates(
 // Poll background tasks and emit events when done
 #[allow(dead_code)]
 fn poll_pickup_tasks(
+    time: Res<Time<Real>>,
     mut pending_tasks: ResMut<PendingPickupTasks>,
     mut item_picked_up_events: EventWriter<ItemPickedUpEvent>,
     mut item_pickup_failed_events: EventWriter<ItemPickupFailedEvent>,
 ) {
-    pending_tasks.0.retain_mut(|handle| {
-        if let Some(result) = handle.now_or_never() {
-            match result {
-                Ok(Ok((entity, item_type, tx_hash))) => {
-                    let _entity = entity;
-                    info!(
-                        "Blockchain pickup tx completed: {} for {:?}",
-                        tx_hash, item_type
-                    );
-                    item_picked_up_events.write(ItemPickedUpEvent {
-                        item_type,
-                        transaction_hash: tx_hash,
-                    });
-                }
-                Ok(Err((entity, item_type, err))) => {
-                    let _entity = entity;
-                    item_pickup_failed_events.write(ItemPickupFailedEvent {
-                        item_type,
-                        error: err,
-                    });
-                }
-                Err(join_err) => {
-                    error!("JoinHandle error in pickup task: {:?}", join_err);
-                }
+    pending_tasks.0.retain_mut(|(pending_item_type, task)| {
+        let result = match task.poll(&time) {
+            TaskPoll::Pending => return true, // Keep unfinished task
+            TaskPoll::TimedOut => {
+                item_pickup_failed_events.write(ItemPickupFailedEvent {
+                    item_type: *pending_item_type,
+                    error: "Request timed out".to_string(),
+                });
+                return false;
+            }
+            TaskPoll::Finished(result) => result,
+        };
+        match result {
+            Ok(Ok((entity, item_type, tx_hash))) => {
+                let _entity = entity;
+                info!(
+                    "Blockchain pickup tx completed: {} for {:?}",
+                    tx_hash, item_type
+                );
+                item_picked_up_events.write(ItemPickedUpEvent {
+                    item_type,
+                    transaction_hash: tx_hash,
+                });
+            }
+            Ok(Err((entity, item_type, err))) => {
+                let _entity = entity;
+                item_pickup_failed_events.write(ItemPickupFailedEvent {
+                    item_type,
+                    error: err,
+                });
+            }
+            Err(join_err) => {
+                error!("JoinHandle error in pickup task: {:?}", join_err);
             }
-            false // Remove finished handle
-        } else {
-            true // Keep unfinished handle
         }
+        false // Remove finished task
     });
 }
@@ -0,0 +1,260 @@
+use crate::assets::FontAssets;
+use crate::constants::dojo::SUBMIT_SCORE_SELECTOR;
+use crate::screens::Screen;
+use crate::systems::collectibles::CollectibleProgressTracker;
+use crate::systems::level_data::LevelData;
+use crate::systems::level_exit::LevelCompleteEvent;
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::ui::toast::{Toast, spawn_toast};
+use bevy::prelude::*;
+use dojo_bevy_plugin::{DojoResource, TokioRuntime};
+use starknet::accounts::ConnectedAccount;
+use starknet::core::types::{Call, Felt};
+
+use super::{DojoRequestSettings, RetryPolicy, TaskPoll, TimedTask, execute_calls};
+
+/// Event to submit a final score to the leaderboard on the blockchain
+#[derive(Event, Debug)]
+pub struct SubmitScoreEvent {
+    pub score: u32,
+    pub level: u32,
+}
+
+/// Event emitted when the score transaction was accepted by the RPC
+#[derive(Event, Debug)]
+pub struct ScoreSubmittedEvent {
+    pub score: u32,
+    pub transaction_hash: String,
+}
+
+/// Event emitted when the score could not be submitted
+#[derive(Event, Debug)]
+pub struct ScoreSubmissionFailedEvent {
+    pub score: u32,
+    pub error: String,
+}
+
+/// Resource to track whether this run's score has already gone out
+#[derive(Resource, Debug, Default)]
+pub struct ScoreSubmissionState {
+    pub submitted: bool,
+}
+
+/// One try at submitting a score; `attempt` counts the retries so far
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreAttempt {
+    pub score: u32,
+    pub level: u32,
+    pub attempt: u32,
+}
+
+#[derive(Resource, Default)]
+pub struct PendingScoreTasks(pub Vec<(ScoreAttempt, TimedTask<Result<String, String>>)>);
+
+/// Failed submissions waiting out their retry delay
+#[derive(Resource, Default)]
+pub struct ScoreRetryQueue(pub Vec<(ScoreAttempt, Timer)>);
+
+/// Submits the final score on level completion. Inert until `dojo::plugin` is enabled in `main.rs`
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<SubmitScoreEvent>()
+        .add_event::<ScoreSubmittedEvent>()
+        .add_event::<ScoreSubmissionFailedEvent>()
+        .init_resource::<ScoreSubmissionState>()
+        .init_resource::<PendingScoreTasks>()
+        .init_resource::<ScoreRetryQueue>()
+        .add_systems(OnEnter(Screen::GamePlay), reset_score_submission)
+        .add_systems(
+            Update,
+            (
+                submit_score_on_level_complete,
+                handle_submit_score_events,
+                retry_score_submissions,
+                poll_score_tasks,
+                show_score_submission_result,
+            )
+                .chain()
+                .run_if(in_state(Screen::GamePlay)),
+        );
+}
+
+/// Leaderboard call for `submit_score(score, level)` on the actions contract
+pub fn submit_score_call(action_address: Felt, score: u32, level: u32) -> Call {
+    Call {
+        to: action_address,
+        selector: SUBMIT_SCORE_SELECTOR,
+        calldata: vec![Felt::from(score), Felt::from(level)],
+    }
+}
+
+/// Signs the leaderboard call with the connected account and sends it, returning the tx hash.
+/// Signing stays inside the account, so the private key never passes through here.
+pub async fn submit_score<A>(
+    account: Option<&A>,
+    action_address: Felt,
+    score: u32,
+    level: u32,
+) -> Result<String, String>
+where
+    A: ConnectedAccount + Sync,
+{
+    execute_calls(account, vec![submit_score_call(action_address, score, level)]).await
+}
+
+fn reset_score_submission(mut state: ResMut<ScoreSubmissionState>) {
+    state.submitted = false;
+}
+
+/// Submits the final coin count once the player leaves through the level exit
+fn submit_score_on_level_complete(
+    mut complete_events: EventReader<LevelCompleteEvent>,
+    progress: Res<CollectibleProgressTracker>,
+    level: Res<LevelData>,
+    mut state: ResMut<ScoreSubmissionState>,
+    mut submit_events: EventWriter<SubmitScoreEvent>,
+) {
+    if complete_events.read().last().is_none() || state.submitted {
+        return;
+    }
+
+    state.submitted = true;
+    submit_events.write(SubmitScoreEvent {
+        score: progress.coins,
+        level: level.number,
+    });
+}
+
+/// System to handle SubmitScoreEvent and send the signed transaction
+fn handle_submit_score_events(
+    mut events: EventReader<SubmitScoreEvent>,
+    dojo: Res<DojoResource>,
+    dojo_config: Res<super::DojoSystemState>,
+    tokio: Res<TokioRuntime>,
+    request_settings: Res<DojoRequestSettings>,
+    mut pending_tasks: ResMut<PendingScoreTasks>,
+) {
+    for event in events.read() {
+        let attempt = ScoreAttempt {
+            score: event.score,
+            level: event.level,
+            attempt: 0,
+        };
+        spawn_score_task(attempt, &dojo, &dojo_config, &tokio, &request_settings, &mut pending_tasks);
+    }
+}
+
+fn spawn_score_task(
+    attempt: ScoreAttempt,
+    dojo: &DojoResource,
+    dojo_config: &super::DojoSystemState,
+    tokio: &TokioRuntime,
+    request_settings: &DojoRequestSettings,
+    pending_tasks: &mut PendingScoreTasks,
+) {
+    info!(
+        "Submitting score {} for level {} (attempt {})",
+        attempt.score,
+        attempt.level,
+        attempt.attempt + 1
+    );
+    let account = dojo.sn.account.clone();
+    let action_address = dojo_config.config.action_address;
+    let handle = tokio.runtime.spawn(async move {
+        submit_score(account.as_deref(), action_address, attempt.score, attempt.level).await
+    });
+    pending_tasks
+        .0
+        .push((attempt, TimedTask::new(handle, request_settings)));
+}
+
+/// Re-sends submissions whose retry delay has run out
+fn retry_score_submissions(
+    time: Res<Time<Real>>,
+    dojo: Res<DojoResource>,
+    dojo_config: Res<super::DojoSystemState>,
+    tokio: Res<TokioRuntime>,
+    request_settings: Res<DojoRequestSettings>,
+    mut retry_queue: ResMut<ScoreRetryQueue>,
+    mut pending_tasks: ResMut<PendingScoreTasks>,
+) {
+    retry_queue.0.retain_mut(|(attempt, timer)| {
+        if !timer.tick(time.delta()).finished() {
+            return true;
+        }
+        spawn_score_task(*attempt, &dojo, &dojo_config, &tokio, &request_settings, &mut pending_tasks);
+        false
+    });
+}
+
+// Poll background tasks and emit events when done, queueing retries while the policy allows
+fn poll_score_tasks(
+    time: Res<Time<Real>>,
+    retry_policy: Res<RetryPolicy>,
+    mut pending_tasks: ResMut<PendingScoreTasks>,
+    mut retry_queue: ResMut<ScoreRetryQueue>,
+    mut submitted_events: EventWriter<ScoreSubmittedEvent>,
+    mut failed_events: EventWriter<ScoreSubmissionFailedEvent>,
+) {
+    pending_tasks.0.retain_mut(|(attempt, task)| {
+        // A timed out transaction may still land, so only explicit errors are retried
+        let (result, retryable) = match task.poll(&time) {
+            TaskPoll::Pending => return true, // Keep unfinished task
+            TaskPoll::TimedOut => (Err("Request timed out".to_string()), false),
+            TaskPoll::Finished(Ok(result)) => (result, true),
+            TaskPoll::Finished(Err(join_err)) => (Err(format!("{:?}", join_err)), false),
+        };
+        match result {
+            Ok(transaction_hash) => {
+                info!("Score {} submitted (TX: {})", attempt.score, transaction_hash);
+                submitted_events.write(ScoreSubmittedEvent {
+                    score: attempt.score,
+                    transaction_hash,
+                });
+            }
+            Err(error) if retryable && retry_policy.should_retry(attempt.attempt) => {
+                let delay = retry_policy.delay(attempt.attempt);
+                warn!("Score submission failed, retrying in {:.1}s: {}", delay, error);
+                let retry = ScoreAttempt {
+                    attempt: attempt.attempt + 1,
+                    ..*attempt
+                };
+                retry_queue
+                    .0
+                    .push((retry, Timer::from_seconds(delay, TimerMode::Once)));
+            }
+            Err(error) => {
+                error!("Score submission failed: {}", error);
+                failed_events.write(ScoreSubmissionFailedEvent {
+                    score: attempt.score,
+                    error,
+                });
+            }
+        }
+        false // Remove finished task
+    });
+}
+
+/// Shows whether the leaderboard accepted the score at the top of the screen
+fn show_score_submission_result(
+    mut commands: Commands,
+    mut submitted_events: EventReader<ScoreSubmittedEvent>,
+    mut failed_events: EventReader<ScoreSubmissionFailedEvent>,
+    toast_query: Query<Entity, With<Toast>>,
+    font_assets: Res<FontAssets>,
+) {
+    let (message, color) = if let Some(event) = submitted_events.read().last() {
+        (
+            format!("Score {} submitted to the leaderboard", event.score),
+            Color::SUCCESS_GREEN,
+        )
+    } else if let Some(event) = failed_events.read().last() {
+        (
+            format!("Could not submit score {}", event.score),
+            Color::DANGER_RED,
+        )
+    } else {
+        return;
+    };
+
+    spawn_toast(&mut commands, &font_assets, &toast_query, message, color);
+}
@@ -0,0 +1,152 @@
+//! Catches the player if a physics glitch drops them through the environment
+//! or past the boundary walls, and puts them back on the last ground they
+//! safely stood on instead of letting them fall forever.
+
+use std::collections::VecDeque;
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::constants::boundary::RecoveryConfig;
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::character_controller::{CharacterController, FallState, Grounded};
+use crate::systems::checkpoints::{CheckpointState, respawn_position};
+use crate::systems::level_data::PlayerSpawn;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Recent spots where the player stood on the ground inside the boundary, oldest first
+#[derive(Resource)]
+pub struct SafePositionHistory {
+    pub positions: VecDeque<Vec3>,
+    timer: Timer,
+}
+
+impl Default for SafePositionHistory {
+    fn default() -> Self {
+        Self {
+            positions: VecDeque::with_capacity(RecoveryConfig::HISTORY_LEN),
+            timer: Timer::from_seconds(RecoveryConfig::SAMPLE_INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl SafePositionHistory {
+    pub fn record(&mut self, position: Vec3) {
+        if self.positions.len() == RecoveryConfig::HISTORY_LEN {
+            self.positions.pop_front();
+        }
+        self.positions.push_back(position);
+    }
+
+    /// The newest safe position, removed so that falling again from the same
+    /// spot sends the player further back
+    pub fn take_latest(&mut self) -> Option<Vec3> {
+        self.positions.pop_back()
+    }
+}
+
+/// Whether the player has fallen below the kill plane or got well outside the walkable area
+pub fn needs_recovery(position: Vec3, boundary: &BoundaryConstraint) -> bool {
+    let margin = RecoveryConfig::BOUNDARY_ESCAPE_MARGIN;
+    position.y < RecoveryConfig::KILL_PLANE_Y
+        || position.x < boundary.min_x - margin
+        || position.x > boundary.max_x + margin
+        || position.z < boundary.min_z - margin
+        || position.z > boundary.max_z + margin
+}
+
+// ===== PLUGIN =====
+
+pub struct FallRecoveryPlugin;
+
+impl Plugin for FallRecoveryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SafePositionHistory>()
+            .add_systems(OnEnter(Screen::GamePlay), reset_safe_positions)
+            .add_systems(
+                Update,
+                (record_safe_position, recover_fallen_player)
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay).and(crate::systems::health::player_alive)),
+            )
+            .add_observer(reset_safe_positions_on_restart);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_safe_positions(mut history: ResMut<SafePositionHistory>) {
+    *history = SafePositionHistory::default();
+}
+
+/// The restart moves the player, so positions from before it no longer apply
+fn reset_safe_positions_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut history: ResMut<SafePositionHistory>,
+) {
+    if trigger.0 == ConfirmAction::RestartLevel {
+        *history = SafePositionHistory::default();
+    }
+}
+
+fn record_safe_position(
+    time: Res<Time>,
+    boundary: Res<BoundaryConstraint>,
+    player_query: Query<&Transform, (With<CharacterController>, With<Grounded>)>,
+    mut history: ResMut<SafePositionHistory>,
+) {
+    if !history.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let Ok(transform) = player_query.single() else {
+        return;
+    };
+    let position = transform.translation;
+    if boundary.contains(position) && position.y > RecoveryConfig::KILL_PLANE_Y {
+        history.record(position);
+    }
+}
+
+fn recover_fallen_player(
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut LinearVelocity,
+            &mut Health,
+            Option<&mut FallState>,
+        ),
+        With<CharacterController>,
+    >,
+    boundary: Res<BoundaryConstraint>,
+    mut history: ResMut<SafePositionHistory>,
+    checkpoints: Res<CheckpointState>,
+    player_spawn: Res<PlayerSpawn>,
+) {
+    let Ok((mut transform, mut velocity, mut health, fall_state)) = player_query.single_mut()
+    else {
+        return;
+    };
+    if !needs_recovery(transform.translation, &boundary) {
+        return;
+    }
+
+    // Without any safe ground on record yet, fall back to where a restart would go
+    let target = history
+        .take_latest()
+        .unwrap_or_else(|| respawn_position(&checkpoints, &player_spawn));
+    warn!(
+        "Player out of bounds at {}, recovering to {}",
+        transform.translation, target
+    );
+    transform.translation = target;
+    velocity.0 = Vec3::ZERO;
+    // The fall that got them here shouldn't also count as fall damage on landing
+    if let Some(mut fall_state) = fall_state {
+        fall_state.peak_fall_speed = 0.0;
+    }
+    health.take_damage(RecoveryConfig::DAMAGE);
+}
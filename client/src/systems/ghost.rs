@@ -0,0 +1,367 @@
+//! Time-trial ghost. [`GhostRecorder`] samples the player's full transform during
+//! gameplay, and [`GhostPlayer`] replays the best stored run for the level on a
+//! translucent copy of the player model. Works like the navigation recorder, but
+//! keeps rotation and scale too.
+
+use bevy::pbr::NotShadowCaster;
+use bevy::prelude::*;
+use bevy_gltf_animation::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::ModelAssets;
+use crate::constants::ghost::{GHOST_REPLAY_PATH_PREFIX, GhostConfig};
+use crate::constants::movement::CharacterAnimationConfig;
+use crate::resources::platform::{PlatformCapabilities, read_file, write_file};
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::level_data::LevelData;
+use crate::systems::level_exit::LevelCompleteEvent;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+
+// ===== COMPONENTS & RESOURCES =====
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct GhostSample {
+    pub time: f32,
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl GhostSample {
+    fn from_transform(time: f32, transform: &Transform) -> Self {
+        Self {
+            time,
+            translation: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+            scale: transform.scale.to_array(),
+        }
+    }
+
+    fn to_transform(self) -> Transform {
+        Transform {
+            translation: Vec3::from_array(self.translation),
+            rotation: Quat::from_array(self.rotation).normalize(),
+            scale: Vec3::from_array(self.scale),
+        }
+    }
+}
+
+/// One recorded run of a level
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GhostReplay {
+    pub level: u32,
+    /// Whether the run finished every objective; finished runs always beat unfinished ones
+    pub completed: bool,
+    pub duration: f32,
+    pub samples: Vec<GhostSample>,
+}
+
+impl GhostReplay {
+    fn path(level: u32) -> String {
+        format!("{}{}.json", GHOST_REPLAY_PATH_PREFIX, level)
+    }
+
+    fn load(level: u32) -> Option<Self> {
+        let contents = read_file(&Self::path(level)).ok()?;
+        match serde_json::from_str::<Self>(&contents) {
+            Ok(replay) if replay.samples.len() >= 2 => Some(replay),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Ignoring unreadable ghost replay for level {}: {}", level, e);
+                None
+            }
+        }
+    }
+
+    fn save(&self) {
+        let path = Self::path(self.level);
+        match serde_json::to_string(self) {
+            Ok(contents) => match write_file(&path, &contents) {
+                Ok(()) => info!("Saved ghost replay to {} ({:.1}s)", path, self.duration),
+                Err(e) => error!("Failed to write {}: {}", path, e),
+            },
+            Err(e) => error!("Failed to serialize ghost replay: {}", e),
+        }
+    }
+
+    /// Whether this run should replace `stored` as the level's ghost
+    pub fn beats(&self, stored: Option<&GhostReplay>) -> bool {
+        if self.samples.len() < 2 {
+            return false;
+        }
+        match stored {
+            None => true,
+            Some(stored) => match (self.completed, stored.completed) {
+                (true, true) => self.duration < stored.duration,
+                (true, false) => true,
+                (false, _) => false,
+            },
+        }
+    }
+
+    /// Pose `time` seconds into the run, interpolated between the surrounding samples.
+    /// `None` once the run is over, so a shorter replay simply ends early.
+    pub fn sample_at(&self, time: f32) -> Option<Transform> {
+        let last = self.samples.last()?;
+        if time > last.time {
+            return None;
+        }
+        let next_index = self.samples.partition_point(|sample| sample.time < time);
+        let Some(previous) = next_index.checked_sub(1).map(|index| self.samples[index]) else {
+            return Some(self.samples[0].to_transform());
+        };
+        let next = self.samples[next_index];
+        let span = next.time - previous.time;
+        let t = if span > 0.0 { (time - previous.time) / span } else { 1.0 };
+
+        let (from, to) = (previous.to_transform(), next.to_transform());
+        Some(Transform {
+            translation: from.translation.lerp(to.translation, t),
+            rotation: from.rotation.slerp(to.rotation, t),
+            scale: from.scale.lerp(to.scale, t),
+        })
+    }
+}
+
+/// Samples the current run
+#[derive(Resource)]
+pub struct GhostRecorder {
+    pub recording: bool,
+    pub sample_timer: Timer,
+    pub run: GhostReplay,
+}
+
+impl Default for GhostRecorder {
+    fn default() -> Self {
+        Self {
+            recording: false,
+            sample_timer: Timer::from_seconds(GhostConfig::SAMPLE_INTERVAL_SECS, TimerMode::Repeating),
+            run: GhostReplay::default(),
+        }
+    }
+}
+
+impl GhostRecorder {
+    fn start(&mut self, level: u32) {
+        self.run = GhostReplay {
+            level,
+            ..default()
+        };
+        self.sample_timer.reset();
+        self.recording = true;
+    }
+}
+
+/// Plays back the stored replay for the current level
+#[derive(Resource, Default)]
+pub struct GhostPlayer {
+    pub replay: Option<GhostReplay>,
+    pub time: f32,
+}
+
+#[derive(Component)]
+pub struct Ghost;
+
+/// Put on ghost meshes once their material has been swapped for a translucent copy
+#[derive(Component)]
+struct GhostMaterialApplied;
+
+// ===== PLUGIN =====
+
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GhostRecorder>()
+            .init_resource::<GhostPlayer>()
+            .add_systems(OnEnter(Screen::GamePlay), start_ghost_run)
+            .add_systems(
+                Update,
+                (
+                    record_ghost_sample.run_if(crate::systems::health::player_alive),
+                    stop_recording_on_completion,
+                    play_ghost,
+                    apply_ghost_material,
+                )
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_systems(OnExit(Screen::GamePlay), finish_ghost_run)
+            .add_observer(restart_ghost_run);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn start_ghost_run(
+    mut commands: Commands,
+    mut recorder: ResMut<GhostRecorder>,
+    mut ghost_player: ResMut<GhostPlayer>,
+    model_assets: Res<ModelAssets>,
+    level: Res<LevelData>,
+) {
+    recorder.start(level.number);
+    ghost_player.replay = GhostReplay::load(level.number);
+    ghost_player.time = 0.0;
+
+    let Some(replay) = &ghost_player.replay else {
+        return;
+    };
+    info!("Loaded ghost replay for level {} ({:.1}s)", level.number, replay.duration);
+
+    commands
+        .spawn((
+            Name::new("Ghost"),
+            GltfSceneRoot::new(model_assets.player.clone()),
+            replay.samples[0].to_transform(),
+            Visibility::Visible,
+            Ghost,
+            PlayingScene,
+        ))
+        .observe(start_ghost_animation);
+}
+
+/// Keeps the best run and resets both recorder and ghost when the level restarts in place
+fn restart_ghost_run(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut ghost_player: ResMut<GhostPlayer>,
+    capabilities: Res<PlatformCapabilities>,
+    level: Res<LevelData>,
+) {
+    if trigger.0 != ConfirmAction::RestartLevel {
+        return;
+    }
+    save_if_best(&recorder, &ghost_player, &capabilities);
+    recorder.start(level.number);
+    ghost_player.time = 0.0;
+}
+
+fn finish_ghost_run(
+    mut recorder: ResMut<GhostRecorder>,
+    ghost_player: Res<GhostPlayer>,
+    capabilities: Res<PlatformCapabilities>,
+) {
+    save_if_best(&recorder, &ghost_player, &capabilities);
+    recorder.recording = false;
+}
+
+/// Stores the finished run if it beats the current ghost. The ghost on screen keeps
+/// replaying the old run until the level is entered again.
+fn save_if_best(
+    recorder: &GhostRecorder,
+    ghost_player: &GhostPlayer,
+    capabilities: &PlatformCapabilities,
+) {
+    let stored = ghost_player.replay.as_ref();
+    if !capabilities.has_filesystem || !recorder.run.beats(stored) {
+        return;
+    }
+    recorder.run.save();
+}
+
+fn record_ghost_sample(
+    mut recorder: ResMut<GhostRecorder>,
+    player_query: Query<&Transform, With<CharacterController>>,
+    time: Res<Time>,
+) {
+    if !recorder.recording {
+        return;
+    }
+
+    recorder.run.duration += time.delta_secs();
+    if !recorder.sample_timer.tick(time.delta()).just_finished()
+        || recorder.run.samples.len() >= GhostConfig::MAX_SAMPLES
+    {
+        return;
+    }
+
+    if let Ok(player_transform) = player_query.single() {
+        let sample = GhostSample::from_transform(recorder.run.duration, player_transform);
+        recorder.run.samples.push(sample);
+    }
+}
+
+/// A finished level ends the run, so time spent afterwards doesn't count against it
+fn stop_recording_on_completion(
+    mut complete_events: EventReader<LevelCompleteEvent>,
+    mut recorder: ResMut<GhostRecorder>,
+) {
+    if complete_events.read().last().is_none() || !recorder.recording {
+        return;
+    }
+    recorder.recording = false;
+    recorder.run.completed = true;
+    info!("Level finished in {:.1}s", recorder.run.duration);
+}
+
+fn play_ghost(
+    time: Res<Time>,
+    mut ghost_player: ResMut<GhostPlayer>,
+    mut ghost_query: Query<(&mut Transform, &mut Visibility), With<Ghost>>,
+) {
+    ghost_player.time += time.delta_secs();
+    let pose = ghost_player
+        .replay
+        .as_ref()
+        .and_then(|replay| replay.sample_at(ghost_player.time));
+
+    for (mut transform, mut visibility) in ghost_query.iter_mut() {
+        match pose {
+            Some(pose) => {
+                *transform = pose;
+                visibility.set_if_neq(Visibility::Visible);
+            }
+            // The ghost's run is over; hide it instead of freezing it in place
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
+fn start_ghost_animation(
+    trigger: Trigger<OnAdd, GltfAnimations>,
+    mut ghosts: Query<&mut GltfAnimations, With<Ghost>>,
+    mut animation_players: Query<&mut AnimationPlayer>,
+) {
+    let Ok(mut gltf_animations) = ghosts.get_mut(trigger.target()) else {
+        return;
+    };
+    let Some(animation) = gltf_animations.get_by_number(CharacterAnimationConfig::WALKING) else {
+        return;
+    };
+    if let Ok(mut player) = animation_players.get_mut(gltf_animations.animation_player) {
+        player.play(animation).repeat();
+    }
+}
+
+/// Swaps every mesh under the ghost to a translucent copy of its material as the model loads
+fn apply_ghost_material(
+    mut commands: Commands,
+    ghost_query: Query<Entity, With<Ghost>>,
+    children_query: Query<&Children>,
+    mesh_query: Query<&MeshMaterial3d<StandardMaterial>, Without<GhostMaterialApplied>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for ghost in &ghost_query {
+        for descendant in children_query.iter_descendants(ghost) {
+            let Ok(material) = mesh_query.get(descendant) else {
+                continue;
+            };
+            let Some(original) = materials.get(&material.0) else {
+                continue;
+            };
+            let mut translucent = original.clone();
+            translucent.base_color = translucent.base_color.with_alpha(GhostConfig::ALPHA);
+            translucent.alpha_mode = AlphaMode::Blend;
+            let handle = materials.add(translucent);
+            commands.entity(descendant).insert((
+                MeshMaterial3d(handle),
+                NotShadowCaster,
+                GhostMaterialApplied,
+            ));
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::constants::graphics::GroundShadowConfig;
+use crate::resources::graphics::GraphicsSettings;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::{Collectible, Sensor};
+use crate::systems::enemy_ai::Enemy;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Flat blob under a character, placed where its ground cast hits
+#[derive(Component)]
+pub struct GroundShadow {
+    pub owner: Entity,
+}
+
+/// Put on a character once its `GroundShadow` has been spawned
+#[derive(Component)]
+pub struct GroundShadowCaster {
+    pub shadow: Entity,
+}
+
+#[derive(Resource)]
+struct GroundShadowMesh(Handle<Mesh>);
+
+// ===== PLUGIN =====
+
+pub struct GroundShadowPlugin;
+
+impl Plugin for GroundShadowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_ground_shadow_mesh)
+            .add_systems(
+                Update,
+                (spawn_ground_shadows, update_ground_shadows)
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn setup_ground_shadow_mesh(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    let mesh = meshes.add(Circle::new(GroundShadowConfig::RADIUS));
+    commands.insert_resource(GroundShadowMesh(mesh));
+}
+
+/// Gives every grounded character (player and enemies) its own shadow
+fn spawn_ground_shadows(
+    mut commands: Commands,
+    mesh: Res<GroundShadowMesh>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    casters: Query<
+        Entity,
+        (
+            With<ShapeCaster>,
+            Or<(With<CharacterController>, With<Enemy>)>,
+            Without<GroundShadowCaster>,
+        ),
+    >,
+) {
+    for owner in &casters {
+        // Each shadow fades on its own, so the material isn't shared
+        let material = materials.add(StandardMaterial {
+            base_color: Color::BLACK.with_alpha(GroundShadowConfig::OPACITY),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        let shadow = commands
+            .spawn((
+                Name::new("Ground Shadow"),
+                Mesh3d(mesh.0.clone()),
+                MeshMaterial3d(material),
+                Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                Visibility::Hidden,
+                bevy::pbr::NotShadowCaster,
+                GroundShadow { owner },
+                PlayingScene,
+            ))
+            .id();
+        commands.entity(owner).insert(GroundShadowCaster { shadow });
+    }
+}
+
+/// Follows each character, snapping to its ground hit and fading out with height
+fn update_ground_shadows(
+    mut commands: Commands,
+    settings: Res<GraphicsSettings>,
+    spatial_query: SpatialQuery,
+    owners: Query<(&GlobalTransform, Option<&ShapeHits>)>,
+    ignored_query: Query<(), Or<(With<Collectible>, With<Sensor>, With<GroundShadow>)>>,
+    mut shadows: Query<(
+        Entity,
+        &GroundShadow,
+        &mut Transform,
+        &mut Visibility,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, shadow, mut transform, mut visibility, material) in &mut shadows {
+        let Ok((owner_transform, ground_hits)) = owners.get(shadow.owner) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if !settings.ground_shadows {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+
+        let origin = owner_transform.translation();
+        // Standing characters already have a ground hit; only airborne ones need a longer ray
+        let height = match ground_hits.and_then(|hits| hits.iter().next()) {
+            Some(hit) => Some(hit.distance),
+            None => {
+                let filter = SpatialQueryFilter::default().with_excluded_entities([shadow.owner]);
+                spatial_query
+                    .cast_ray_predicate(
+                        origin,
+                        Dir3::NEG_Y,
+                        GroundShadowConfig::MAX_HEIGHT,
+                        true,
+                        &filter,
+                        &|entity| !ignored_query.contains(entity),
+                    )
+                    .map(|hit| hit.distance)
+            }
+        };
+        let Some(height) = height else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        let fade = (height / GroundShadowConfig::MAX_HEIGHT).clamp(0.0, 1.0);
+        transform.translation = Vec3::new(
+            origin.x,
+            origin.y - height + GroundShadowConfig::GROUND_OFFSET,
+            origin.z,
+        );
+        transform.scale = Vec3::splat(1.0 - fade * (1.0 - GroundShadowConfig::MIN_SCALE));
+        visibility.set_if_neq(Visibility::Inherited);
+
+        // Only touch the asset when the opacity moved, or it gets re-uploaded every frame
+        let alpha = GroundShadowConfig::OPACITY * (1.0 - fade);
+        let unchanged = materials
+            .get(&material.0)
+            .is_none_or(|current| (current.base_color.alpha() - alpha).abs() <= 0.01);
+        if unchanged {
+            continue;
+        }
+        if let Some(current) = materials.get_mut(&material.0) {
+            current.base_color = Color::BLACK.with_alpha(alpha);
+        }
+    }
+}
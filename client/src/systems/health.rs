@@ -0,0 +1,156 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::constants::combat::CombatConfig;
+use crate::game::Health;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+use crate::ui::widgets::{HudHealthBar, HudHealthText, ProgressBar};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Sent once when the player's health reaches zero
+#[derive(Event, Debug)]
+pub struct PlayerDied {
+    #[allow(dead_code)]
+    pub player: Entity,
+}
+
+/// Where the run is between the player dying and the game-over screen
+#[derive(Resource, Default)]
+pub enum GameOverState {
+    #[default]
+    Alive,
+    /// Death animation window before the game-over screen comes up
+    Dying(Timer),
+    Shown,
+}
+
+impl GameOverState {
+    pub fn is_active(&self) -> bool {
+        !matches!(self, GameOverState::Alive)
+    }
+}
+
+impl crate::screens::SceneState for GameOverState {
+    fn reset_on_teardown(&mut self) {
+        *self = GameOverState::Alive;
+    }
+}
+
+/// Run condition for everything that should stop once the player has died:
+/// movement, enemies, waves and coin streaming
+pub fn player_alive(state: Res<GameOverState>) -> bool {
+    !state.is_active()
+}
+
+// ===== PLUGIN =====
+
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameOverState>()
+            .add_event::<PlayerDied>()
+            .add_systems(
+                Update,
+                (
+                    (detect_player_death, begin_game_over, tick_death_transition).chain(),
+                    update_hud_health,
+                )
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_systems(
+                OnExit(Screen::GamePlay),
+                (
+                    resume_physics,
+                    crate::screens::reset_scene_state::<GameOverState>,
+                ),
+            )
+            .add_observer(revive_on_restart);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn detect_player_death(
+    state: Res<GameOverState>,
+    player_query: Query<(Entity, &Health), (With<CharacterController>, Changed<Health>)>,
+    mut died_events: EventWriter<PlayerDied>,
+) {
+    if state.is_active() {
+        return;
+    }
+    for (player, health) in &player_query {
+        if health.current == 0 {
+            died_events.write(PlayerDied { player });
+        }
+    }
+}
+
+/// Points the HUD health bar at the player's health; the bar animates the change itself
+fn update_hud_health(
+    player_query: Query<Ref<Health>, With<CharacterController>>,
+    added_bars: Query<(), Added<HudHealthBar>>,
+    mut bar_query: Query<&mut ProgressBar, With<HudHealthBar>>,
+    mut text_query: Query<&mut Text, With<HudHealthText>>,
+) {
+    let Ok(health) = player_query.single() else {
+        return;
+    };
+    if !health.is_changed() && added_bars.is_empty() {
+        return;
+    }
+    // The bar clamps, so a 0 maximum just shows empty
+    let progress = health.current as f32 / health.max as f32;
+    for mut bar in &mut bar_query {
+        bar.progress = progress;
+    }
+    for mut text in &mut text_query {
+        text.0 = format!("{}/{}", health.current, health.max);
+    }
+}
+
+/// Freezes physics and starts the death window; the rest of gameplay stops on `player_alive`
+fn begin_game_over(
+    mut died_events: EventReader<PlayerDied>,
+    mut state: ResMut<GameOverState>,
+    mut physics_time: ResMut<Time<Physics>>,
+) {
+    if died_events.read().last().is_none() || state.is_active() {
+        return;
+    }
+    info!("Player died");
+    physics_time.pause();
+    *state = GameOverState::Dying(Timer::from_seconds(
+        CombatConfig::DEATH_TRANSITION_SECS,
+        TimerMode::Once,
+    ));
+}
+
+fn tick_death_transition(time: Res<Time>, mut state: ResMut<GameOverState>) {
+    let GameOverState::Dying(timer) = state.as_mut() else {
+        return;
+    };
+    if timer.tick(time.delta()).finished() {
+        *state = GameOverState::Shown;
+    }
+}
+
+/// Retry goes through the normal restart, which refills health; this just lifts the pause
+fn revive_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut state: ResMut<GameOverState>,
+    mut physics_time: ResMut<Time<Physics>>,
+) {
+    if trigger.0 != ConfirmAction::RestartLevel {
+        return;
+    }
+    *state = GameOverState::Alive;
+    physics_time.unpause();
+}
+
+fn resume_physics(mut physics_time: ResMut<Time<Physics>>) {
+    physics_time.unpause();
+}
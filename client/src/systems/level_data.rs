@@ -0,0 +1,396 @@
+//! Per-level content read from `assets/levels/*.json`: where the player
+//! spawns, its checkpoints and exit, and the props placed on top of the base
+//! environment, decorative or breakable. Designers change these there without
+//! touching code.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::assets::ModelAssets;
+use crate::constants::level::{CheckpointConfig, SpawnConfig};
+use crate::constants::objectives::LevelExitConfig;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::destructibles::Destructible;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+
+/// Only one level exists so far, so its data is compiled in like the locale tables
+const LEVEL_1: &str = include_str!("../../assets/levels/level_1.json");
+
+// ===== DATA =====
+
+#[derive(Resource, Deserialize, Debug)]
+#[serde(default)]
+pub struct LevelData {
+    /// Counts from 1; scores, ghost replays and the game-over summary are keyed by it
+    pub number: u32,
+    pub spawn_point: SpawnPoint,
+    /// In the order the player is expected to reach them
+    pub checkpoints: Vec<CheckpointData>,
+    pub exit: ExitData,
+    pub props: Vec<PropData>,
+}
+
+impl Default for LevelData {
+    fn default() -> Self {
+        Self {
+            number: 1,
+            spawn_point: SpawnPoint::default(),
+            checkpoints: Vec::new(),
+            exit: ExitData::default(),
+            props: Vec::new(),
+        }
+    }
+}
+
+/// Where the level exit portal stands and how close counts as stepping into it
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct ExitData {
+    pub position: [f32; 3],
+    pub radius: f32,
+}
+
+impl Default for ExitData {
+    fn default() -> Self {
+        Self {
+            position: LevelExitConfig::DEFAULT_POSITION,
+            radius: LevelExitConfig::DEFAULT_RADIUS,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct CheckpointData {
+    pub position: [f32; 3],
+    #[serde(default = "default_checkpoint_radius")]
+    pub radius: f32,
+}
+
+fn default_checkpoint_radius() -> f32 {
+    CheckpointConfig::DEFAULT_RADIUS
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct SpawnPoint {
+    /// Where the player's feet go; corrected on load if it's out of bounds or underground
+    pub position: [f32; 3],
+    /// Facing in degrees around Y; 0 faces +Z
+    pub rotation: f32,
+}
+
+impl Default for SpawnPoint {
+    fn default() -> Self {
+        Self {
+            position: SpawnConfig::DEFAULT_SPAWN_POSITION,
+            rotation: 0.0,
+        }
+    }
+}
+
+impl SpawnPoint {
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.rotation.to_radians())
+    }
+}
+
+/// The validated spawn for the current level, used on entering gameplay and on restart
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct PlayerSpawn {
+    pub position: Vec3,
+    pub rotation: Quat,
+}
+
+impl Default for PlayerSpawn {
+    fn default() -> Self {
+        Self {
+            position: Vec3::from_array(SpawnConfig::DEFAULT_SPAWN_POSITION),
+            rotation: Quat::IDENTITY,
+        }
+    }
+}
+
+/// Pulls `position` back inside `boundary` and, when the ground below it is
+/// known, up out of it. Points that are already valid come back unchanged.
+pub fn correct_spawn_position(
+    position: Vec3,
+    boundary: &BoundaryConstraint,
+    ground_height: Option<f32>,
+) -> Vec3 {
+    let margin = SpawnConfig::BOUNDARY_MARGIN;
+    let mut corrected = position;
+    if !boundary.contains(position) {
+        // A boundary narrower than the margin just pins to its center
+        let center = boundary.center();
+        corrected.x = if boundary.max_x - boundary.min_x > margin * 2.0 {
+            position.x.clamp(boundary.min_x + margin, boundary.max_x - margin)
+        } else {
+            center.x
+        };
+        corrected.z = if boundary.max_z - boundary.min_z > margin * 2.0 {
+            position.z.clamp(boundary.min_z + margin, boundary.max_z - margin)
+        } else {
+            center.y
+        };
+    }
+    if let Some(ground) = ground_height {
+        corrected.y = corrected.y.max(ground + SpawnConfig::GROUND_CLEARANCE);
+    }
+    corrected
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PropData {
+    /// Name of a scene in `ModelAssets`, e.g. "mystery_box"
+    pub model: String,
+    pub position: [f32; 3],
+    /// Euler angles in degrees, applied Y then X then Z
+    #[serde(default)]
+    pub rotation: [f32; 3],
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    /// Props with a collider become static bodies the player can't walk through.
+    /// Sizes are in the prop's local units, so `scale` applies to them too.
+    #[serde(default)]
+    pub collider: Option<PropCollider>,
+    /// Makes the prop breakable by fight moves; it needs a collider too
+    #[serde(default)]
+    pub destructible: Option<PropDestructible>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PropDestructible {
+    pub health: u32,
+    /// Collectible type names, e.g. "Coin"
+    #[serde(default)]
+    pub drops: Vec<CollectibleType>,
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(tag = "shape", rename_all = "snake_case")]
+pub enum PropCollider {
+    /// Full size, not half extents
+    Cuboid {
+        size: [f32; 3],
+    },
+    Sphere {
+        radius: f32,
+    },
+    Cylinder {
+        radius: f32,
+        height: f32,
+    },
+}
+
+impl PropCollider {
+    pub fn collider(self) -> Collider {
+        match self {
+            PropCollider::Cuboid { size: [x, y, z] } => Collider::cuboid(x, y, z),
+            PropCollider::Sphere { radius } => Collider::sphere(radius),
+            PropCollider::Cylinder { radius, height } => Collider::cylinder(radius, height),
+        }
+    }
+}
+
+impl PropData {
+    pub fn transform(&self) -> Transform {
+        let [x, y, z] = self.rotation.map(f32::to_radians);
+        Transform {
+            translation: Vec3::from_array(self.position),
+            rotation: Quat::from_euler(EulerRot::YXZ, y, x, z),
+            scale: Vec3::splat(self.scale),
+        }
+    }
+}
+
+/// Scene for a prop's model id, or None for ids with no matching model
+pub fn prop_scene(assets: &ModelAssets, model: &str) -> Option<Handle<Scene>> {
+    match model {
+        "mystery_box" => Some(assets.mystery_box.clone()),
+        "book" => Some(assets.book.clone()),
+        "coin" => Some(assets.coin.clone()),
+        "dungeon" => Some(assets.dungeon.clone()),
+        _ => None,
+    }
+}
+
+#[derive(Component)]
+pub struct LevelProp;
+
+// ===== PLUGIN =====
+
+pub struct LevelDataPlugin;
+
+impl Plugin for LevelDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(load_level_data())
+            .init_resource::<PlayerSpawn>()
+            .add_systems(OnEnter(Screen::GamePlay), (resolve_player_spawn, spawn_level_props))
+            .add_observer(respawn_level_props_on_restart);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn load_level_data() -> LevelData {
+    serde_json::from_str(LEVEL_1).unwrap_or_else(|e| {
+        error!("Failed to parse level data: {}", e);
+        LevelData::default()
+    })
+}
+
+/// Checks the level's spawn point against the boundary and the ground before
+/// the player is placed there
+pub fn resolve_player_spawn(
+    level: Res<LevelData>,
+    boundary: Res<BoundaryConstraint>,
+    spatial_query: SpatialQuery,
+    mut player_spawn: ResMut<PlayerSpawn>,
+) {
+    let requested = Vec3::from_array(level.spawn_point.position);
+    let in_bounds = correct_spawn_position(requested, &boundary, None);
+    let origin = in_bounds + Vec3::Y * SpawnConfig::GROUND_PROBE_HEIGHT;
+    let ground_height = spatial_query
+        .cast_ray(
+            origin,
+            Dir3::NEG_Y,
+            SpawnConfig::GROUND_PROBE_HEIGHT * 2.0,
+            true,
+            &SpatialQueryFilter::default(),
+        )
+        .map(|hit| origin.y - hit.distance);
+
+    let position = correct_spawn_position(requested, &boundary, ground_height);
+    if position != requested {
+        warn!("Level spawn point {} corrected to {}", requested, position);
+    }
+    *player_spawn = PlayerSpawn {
+        position,
+        rotation: level.spawn_point.rotation(),
+    };
+}
+
+/// Props carry `PlayingScene`, so they go with the rest of the level on exit
+fn spawn_level_props(mut commands: Commands, assets: Res<ModelAssets>, level: Res<LevelData>) {
+    spawn_props(&mut commands, &assets, &level);
+}
+
+/// Brings broken props back so a restart starts from the full layout
+fn respawn_level_props_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut commands: Commands,
+    assets: Res<ModelAssets>,
+    level: Res<LevelData>,
+    prop_query: Query<Entity, With<LevelProp>>,
+) {
+    if trigger.0 != ConfirmAction::RestartLevel {
+        return;
+    }
+    for entity in &prop_query {
+        commands.entity(entity).despawn();
+    }
+    spawn_props(&mut commands, &assets, &level);
+}
+
+fn spawn_props(commands: &mut Commands, assets: &ModelAssets, level: &LevelData) {
+    for prop in &level.props {
+        let Some(scene) = prop_scene(assets, &prop.model) else {
+            warn!("Skipping prop with unknown model '{}'", prop.model);
+            continue;
+        };
+        let mut entity = commands.spawn((
+            Name::new(format!("Prop: {}", prop.model)),
+            SceneRoot(scene),
+            prop.transform(),
+            LevelProp,
+            PlayingScene,
+        ));
+        if let Some(collider) = prop.collider {
+            entity.insert((RigidBody::Static, collider.collider()));
+        }
+        if let Some(destructible) = &prop.destructible {
+            if prop.collider.is_none() {
+                warn!("Destructible prop '{}' has no collider, so it can't be hit", prop.model);
+            }
+            entity.insert(Destructible {
+                health: destructible.health,
+                drops: destructible.drops.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_boundary() -> BoundaryConstraint {
+        BoundaryConstraint {
+            min_x: -50.0,
+            max_x: 50.0,
+            min_z: -50.0,
+            max_z: 50.0,
+        }
+    }
+
+    #[test]
+    fn out_of_bounds_spawn_is_pulled_inside_the_boundary() {
+        let boundary = test_boundary();
+        let corrected = correct_spawn_position(Vec3::new(80.0, 2.0, -75.0), &boundary, None);
+        let margin = SpawnConfig::BOUNDARY_MARGIN;
+        assert_eq!(corrected, Vec3::new(50.0 - margin, 2.0, -50.0 + margin));
+        assert!(boundary.contains(corrected));
+    }
+
+    #[test]
+    fn buried_spawn_is_nudged_above_the_ground() {
+        let corrected = correct_spawn_position(Vec3::new(0.0, -3.0, 0.0), &test_boundary(), Some(1.0));
+        assert_eq!(corrected.y, 1.0 + SpawnConfig::GROUND_CLEARANCE);
+    }
+
+    #[test]
+    fn valid_spawn_is_left_alone() {
+        let position = Vec3::new(10.0, 5.0, -10.0);
+        assert_eq!(correct_spawn_position(position, &test_boundary(), Some(0.0)), position);
+    }
+
+    #[test]
+    fn spawn_in_a_too_narrow_boundary_pins_to_its_center() {
+        let boundary = BoundaryConstraint {
+            min_x: 4.0,
+            max_x: 5.0,
+            min_z: -1.0,
+            max_z: 0.0,
+        };
+        let corrected = correct_spawn_position(Vec3::new(20.0, 2.0, 20.0), &boundary, None);
+        assert_eq!(corrected, Vec3::new(4.5, 2.0, -0.5));
+    }
+
+    #[test]
+    fn missing_spawn_point_falls_back_to_the_default() {
+        let level: LevelData = serde_json::from_str("{}").unwrap();
+        assert_eq!(level.spawn_point.position, SpawnConfig::DEFAULT_SPAWN_POSITION);
+        assert_eq!(level.number, 1);
+    }
+
+    #[test]
+    fn missing_exit_fields_fall_back_to_the_defaults() {
+        let level: LevelData = serde_json::from_str(r#"{ "exit": { "radius": 5.0 } }"#).unwrap();
+        assert_eq!(level.exit.position, LevelExitConfig::DEFAULT_POSITION);
+        assert_eq!(level.exit.radius, 5.0);
+    }
+
+    #[test]
+    fn bundled_level_knows_its_number() {
+        let level: LevelData = serde_json::from_str(LEVEL_1).unwrap();
+        assert_eq!(level.number, 1);
+    }
+}
@@ -0,0 +1,263 @@
+use bevy::prelude::*;
+
+use crate::constants::objectives::LevelExitConfig;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::level_data::LevelData;
+use crate::systems::objectives::ObjectiveManager;
+use crate::ui::dialog::Interactable;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Sent once when the player walks into the open level exit
+#[derive(Event, Debug)]
+pub struct LevelCompleteEvent;
+
+/// The portal marking the level exit; its `Interactable` prompt explains why it's closed
+#[derive(Component)]
+pub struct LevelExitPortal;
+
+#[derive(Resource, Default)]
+pub struct LevelExitState {
+    pub completed: bool,
+}
+
+// ===== PLUGIN =====
+
+pub struct LevelExitPlugin;
+
+impl Plugin for LevelExitPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelExitState>()
+            .add_event::<LevelCompleteEvent>()
+            .add_systems(OnEnter(Screen::GamePlay), (reset_level_exit, spawn_level_exit_portal))
+            .add_systems(
+                Update,
+                check_level_exit
+                    .run_if(in_state(Screen::GamePlay).and(crate::systems::health::player_alive)),
+            )
+            .add_observer(reset_level_exit_on_restart);
+    }
+}
+
+// ===== HELPERS =====
+
+/// The exit only opens once every required objective is done, and only counts while the player
+/// stands within `radius` of it on the ground plane
+pub fn can_exit_level(objectives_complete: bool, player_pos: Vec3, exit_pos: Vec3, radius: f32) -> bool {
+    objectives_complete && player_pos.xz().distance(exit_pos.xz()) <= radius
+}
+
+fn exit_prompt(objective_manager: &ObjectiveManager) -> String {
+    let required_failed = objective_manager
+        .objectives
+        .iter()
+        .any(|objective| objective.required && objective.failed);
+    if required_failed {
+        return "The exit is sealed: restart the level to retry the failed objective".to_string();
+    }
+    match objective_manager.remaining_required() {
+        0 => "Step into the portal to finish the level".to_string(),
+        1 => "The exit is sealed: 1 required objective remains".to_string(),
+        remaining => format!("The exit is sealed: {} required objectives remain", remaining),
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_level_exit(mut state: ResMut<LevelExitState>) {
+    state.completed = false;
+}
+
+fn reset_level_exit_on_restart(trigger: Trigger<ConfirmDialogAccepted>, mut state: ResMut<LevelExitState>) {
+    if trigger.0 == ConfirmAction::RestartLevel {
+        state.completed = false;
+    }
+}
+
+fn spawn_level_exit_portal(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    level: Res<LevelData>,
+) {
+    let position = Vec3::from_array(level.exit.position);
+    let radius = level.exit.radius;
+    commands.spawn((
+        Name::new("Level Exit Portal"),
+        Mesh3d(meshes.add(Torus::new(radius * 0.6, radius * 0.8))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::ELYSIUM_GOLD,
+            emissive: Color::ELYSIUM_GOLD.to_linear() * 4.0,
+            ..default()
+        })),
+        // Stand the ring upright so it reads as a doorway
+        Transform::from_translation(position + Vec3::Y * radius)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        Interactable {
+            action: String::new(),
+            range: LevelExitConfig::PROMPT_RANGE,
+        },
+        LevelExitPortal,
+        PlayingScene,
+    ));
+}
+
+/// Keeps the portal prompt current and completes the level once the gate opens
+fn check_level_exit(
+    player_query: Query<&Transform, With<CharacterController>>,
+    mut portal_query: Query<(&GlobalTransform, &mut Interactable), With<LevelExitPortal>>,
+    objective_manager: Res<ObjectiveManager>,
+    level: Res<LevelData>,
+    mut state: ResMut<LevelExitState>,
+    mut complete_events: EventWriter<LevelCompleteEvent>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let Ok((portal_transform, mut interactable)) = portal_query.single_mut() else {
+        return;
+    };
+
+    let prompt = if state.completed {
+        "Level complete".to_string()
+    } else {
+        exit_prompt(&objective_manager)
+    };
+    if interactable.action != prompt {
+        interactable.action = prompt;
+    }
+
+    if state.completed {
+        return;
+    }
+    if can_exit_level(
+        objective_manager.required_completed(),
+        player_transform.translation,
+        portal_transform.translation(),
+        level.exit.radius,
+    ) {
+        info!("Level exit reached");
+        state.completed = true;
+        complete_events.write(LevelCompleteEvent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::collectibles::CollectibleType;
+    use crate::systems::objectives::Objective;
+    use crate::test_support::{event_count, headless_app};
+
+    /// The player standing in the portal with one coin objective left to collect
+    fn level_exit_app() -> App {
+        let mut manager = ObjectiveManager::default();
+        manager.add_objective(Objective::new(
+            0,
+            "Coins".to_string(),
+            String::new(),
+            CollectibleType::Coin,
+            1,
+        ));
+        let level = LevelData::default();
+        let exit = Vec3::from_array(level.exit.position);
+
+        let mut app = headless_app();
+        app.insert_resource(manager)
+            .insert_resource(level)
+            .init_resource::<LevelExitState>()
+            .add_event::<LevelCompleteEvent>()
+            .add_systems(Update, check_level_exit)
+            .add_observer(reset_level_exit_on_restart);
+        app.world_mut()
+            .spawn((Transform::from_translation(exit), CharacterController));
+        app.world_mut().spawn((
+            GlobalTransform::from_translation(exit),
+            Interactable {
+                action: String::new(),
+                range: LevelExitConfig::PROMPT_RANGE,
+            },
+            LevelExitPortal,
+        ));
+        app
+    }
+
+    fn level_completed(app: &App) -> bool {
+        app.world().resource::<LevelExitState>().completed
+    }
+
+    #[test]
+    fn exit_stays_sealed_until_objectives_are_done() {
+        let mut app = level_exit_app();
+        app.update();
+        assert!(!level_completed(&app));
+        assert_eq!(event_count::<LevelCompleteEvent>(&app), 0);
+    }
+
+    #[test]
+    fn completing_all_objectives_completes_the_level_once() {
+        let mut app = level_exit_app();
+        app.update();
+        app.world_mut()
+            .resource_mut::<ObjectiveManager>()
+            .record_pickup(CollectibleType::Coin);
+
+        app.update();
+        assert!(level_completed(&app));
+        assert_eq!(event_count::<LevelCompleteEvent>(&app), 1);
+
+        // Standing in the portal afterwards doesn't complete it again
+        app.update();
+        assert_eq!(event_count::<LevelCompleteEvent>(&app), 1);
+    }
+
+    #[test]
+    fn restarting_reopens_a_completed_level() {
+        let mut app = level_exit_app();
+        app.world_mut().resource_mut::<LevelExitState>().completed = true;
+
+        app.world_mut()
+            .trigger(ConfirmDialogAccepted(ConfirmAction::RestartLevel));
+        app.world_mut().flush();
+        assert!(!level_completed(&app));
+    }
+
+    #[test]
+    fn exit_zone_uses_the_radius_from_level_data() {
+        let mut app = level_exit_app();
+        app.world_mut()
+            .resource_mut::<ObjectiveManager>()
+            .record_pickup(CollectibleType::Coin);
+        let mut player = app
+            .world_mut()
+            .query_filtered::<&mut Transform, With<CharacterController>>();
+        player.single_mut(app.world_mut()).unwrap().translation += Vec3::X * 4.0;
+
+        app.update();
+        assert!(!level_completed(&app));
+
+        app.world_mut().resource_mut::<LevelData>().exit.radius = 5.0;
+        app.update();
+        assert!(level_completed(&app));
+    }
+
+    #[test]
+    fn exit_needs_both_objectives_and_the_zone() {
+        let exit = Vec3::new(10.0, 0.0, 10.0);
+        assert!(can_exit_level(true, exit, exit, 3.0));
+        assert!(!can_exit_level(false, exit, exit, 3.0));
+        assert!(!can_exit_level(true, Vec3::ZERO, exit, 3.0));
+    }
+
+    #[test]
+    fn exit_zone_ignores_height_and_includes_its_edge() {
+        let exit = Vec3::new(10.0, 0.0, 10.0);
+        assert!(can_exit_level(true, exit + Vec3::Y * 50.0, exit, 3.0));
+        assert!(can_exit_level(true, exit + Vec3::X * 3.0, exit, 3.0));
+        assert!(!can_exit_level(true, exit + Vec3::X * 3.1, exit, 3.0));
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_3, FRAC_PI_4, TAU};
+
+use crate::constants::lighting::{
+    DAY_AMBIENT_BRIGHTNESS, DAY_AMBIENT_COLOR, DAY_CYCLE_PERIOD_SECS, NIGHT_AMBIENT_BRIGHTNESS,
+    NIGHT_AMBIENT_COLOR, SUN_ILLUMINANCE,
+};
+use crate::resources::graphics::GraphicsSettings;
+use crate::screens::Screen;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Optional day/night cycle for gameplay; off by default to keep lighting static.
+/// `enabled` follows `GraphicsSettings::day_night_cycle`.
+#[derive(Resource)]
+pub struct LightingCycle {
+    pub enabled: bool,
+    /// Seconds for a full day and night
+    pub period: f32,
+    pub elapsed: f32,
+}
+
+impl Default for LightingCycle {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period: DAY_CYCLE_PERIOD_SECS,
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl LightingCycle {
+    /// Sun angle above the horizon; starts at the static light's 60° elevation
+    pub fn sun_angle(&self) -> f32 {
+        FRAC_PI_3 + TAU * (self.elapsed / self.period.max(f32::EPSILON))
+    }
+
+    /// 1.0 at midday, 0.0 whenever the sun is below the horizon
+    pub fn daylight(&self) -> f32 {
+        self.sun_angle().sin().max(0.0)
+    }
+}
+
+/// The directional light the cycle moves through the sky
+#[derive(Component)]
+pub struct SunLight;
+
+// ===== PLUGIN =====
+
+pub struct LightingCyclePlugin;
+
+impl Plugin for LightingCyclePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LightingCycle>()
+            .add_systems(
+                Update,
+                (
+                    sync_lighting_cycle.run_if(resource_changed::<GraphicsSettings>),
+                    update_lighting_cycle.run_if(
+                        in_state(Screen::GamePlay).and(|cycle: Res<LightingCycle>| cycle.enabled),
+                    ),
+                )
+                    .chain(),
+            )
+            .add_systems(OnExit(Screen::GamePlay), restore_day_ambient);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn update_lighting_cycle(
+    mut cycle: ResMut<LightingCycle>,
+    mut sun_query: Query<(&mut Transform, &mut DirectionalLight), With<SunLight>>,
+    mut ambient: ResMut<AmbientLight>,
+    time: Res<Time>,
+) {
+    cycle.elapsed = (cycle.elapsed + time.delta_secs()) % cycle.period.max(f32::EPSILON);
+    let daylight = cycle.daylight();
+
+    for (mut transform, mut light) in sun_query.iter_mut() {
+        // Same axes as the static setup, only the elevation sweeps around.
+        // Shadow cascades follow the light's rotation, so moving it is enough.
+        transform.rotation = Quat::from_euler(EulerRot::XYZ, -cycle.sun_angle(), FRAC_PI_4, 0.0);
+        light.illuminance = SUN_ILLUMINANCE * daylight;
+    }
+
+    ambient.color = NIGHT_AMBIENT_COLOR.mix(&DAY_AMBIENT_COLOR, daylight);
+    ambient.brightness = NIGHT_AMBIENT_BRIGHTNESS.lerp(DAY_AMBIENT_BRIGHTNESS, daylight);
+}
+
+/// Applies the Graphics setting; switching off mid-level puts the sun and
+/// ambient light back where the static setup has them
+fn sync_lighting_cycle(
+    settings: Res<GraphicsSettings>,
+    mut cycle: ResMut<LightingCycle>,
+    mut sun_query: Query<(&mut Transform, &mut DirectionalLight), With<SunLight>>,
+    mut ambient: ResMut<AmbientLight>,
+) {
+    if cycle.enabled == settings.day_night_cycle {
+        return;
+    }
+    cycle.enabled = settings.day_night_cycle;
+    if cycle.enabled {
+        return;
+    }
+
+    cycle.elapsed = 0.0;
+    for (mut transform, mut light) in sun_query.iter_mut() {
+        transform.rotation = Quat::from_euler(EulerRot::XYZ, -cycle.sun_angle(), FRAC_PI_4, 0.0);
+        light.illuminance = SUN_ILLUMINANCE;
+    }
+    ambient.color = DAY_AMBIENT_COLOR;
+    ambient.brightness = DAY_AMBIENT_BRIGHTNESS;
+}
+
+/// Leaves the other screens with the usual warm ambient light
+fn restore_day_ambient(cycle: Res<LightingCycle>, mut ambient: ResMut<AmbientLight>) {
+    if !cycle.enabled {
+        return;
+    }
+    ambient.color = DAY_AMBIENT_COLOR;
+    ambient.brightness = DAY_AMBIENT_BRIGHTNESS;
+}
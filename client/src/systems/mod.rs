@@ -1,8 +1,26 @@
+pub mod achievements;
 pub mod book_interaction;
 pub mod character_controller;
+pub mod checkpoints;
 pub mod collectibles;
 pub mod collectibles_config;
+pub mod destructibles;
 pub mod dojo;
 pub mod enemy_ai;
+pub mod fall_recovery;
+pub mod ghost;
+pub mod ground_shadow;
+pub mod health;
+pub mod level_data;
+pub mod level_exit;
+pub mod objective_timer;
 pub mod objectives;
+pub mod pickup_effects;
 pub mod boundary;
+pub mod navigation_recorder;
+pub mod navigation_debug;
+pub mod combat;
+pub mod waves;
+pub mod lighting;
+pub mod save;
+pub mod statistics;
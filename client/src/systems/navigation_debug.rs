@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::NavigationBasedSpawner;
+use crate::systems::enemy_ai::{Enemy, EnemyAI};
+use crate::systems::navigation_recorder::NavigationRecorder;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+/// Recorded points further apart than this are treated as separate walks, not one connection
+const MAX_CONNECTION_DISTANCE: f32 = 6.0;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Debug toggle for drawing navigation data in the world; off by default
+#[derive(Resource, Default)]
+pub struct NavigationDebugSettings {
+    pub enabled: bool,
+}
+
+// ===== PLUGIN =====
+
+pub struct NavigationDebugPlugin;
+
+impl Plugin for NavigationDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavigationDebugSettings>().add_systems(
+            Update,
+            (draw_navigation_points, draw_enemy_pursuit, draw_recorded_path)
+                .run_if(|settings: Res<NavigationDebugSettings>| settings.enabled),
+        );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Draws loaded nav points, connecting points that were recorded back to back
+fn draw_navigation_points(nav_spawner: Res<NavigationBasedSpawner>, mut gizmos: Gizmos) {
+    for position in &nav_spawner.nav_positions {
+        gizmos.sphere(Isometry3d::from_translation(*position), 0.3, Color::ENERGY_BLUE);
+    }
+
+    for pair in nav_spawner.nav_positions.windows(2) {
+        if pair[0].distance(pair[1]) <= MAX_CONNECTION_DISTANCE {
+            gizmos.line(pair[0], pair[1], Color::ENERGY_BLUE.with_alpha(0.5));
+        }
+    }
+}
+
+/// Highlights where each enemy is currently heading
+fn draw_enemy_pursuit(
+    enemy_query: Query<(&Transform, &EnemyAI), With<Enemy>>,
+    player_query: Query<&Transform, (With<CharacterController>, Without<Enemy>)>,
+    mut gizmos: Gizmos,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    for (enemy_transform, enemy_ai) in &enemy_query {
+        let color = if enemy_ai.state.is_moving() {
+            Color::WARNING_ORANGE
+        } else {
+            Color::SUCCESS_GREEN
+        };
+        gizmos.line(enemy_transform.translation, player_transform.translation, color);
+        gizmos.circle(
+            Isometry3d::new(enemy_transform.translation, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            enemy_ai.attack_range,
+            color,
+        );
+    }
+}
+
+/// Shows the path captured so far while the navigation recorder is running
+fn draw_recorded_path(recorder: Res<NavigationRecorder>, mut gizmos: Gizmos) {
+    if !recorder.recording {
+        return;
+    }
+    let Some(data) = recorder.data.as_ref() else {
+        return;
+    };
+
+    gizmos.linestrip(
+        data.positions
+            .iter()
+            .map(|point| Vec3::from_array(point.position)),
+        Color::DANGER_RED,
+    );
+}
@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use crate::constants::navigation::{NAV_DATA_PATH, NAV_RECORDING_SAMPLE_INTERVAL_SECS};
+use crate::resources::platform::write_file;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::NavigationData;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Samples the player's position while walking the level so designers can
+/// regenerate `nav.json` without hand-editing it
+#[derive(Resource)]
+pub struct NavigationRecorder {
+    pub recording: bool,
+    pub sample_timer: Timer,
+    pub session_time: f32,
+    /// Created when a recording starts; `NavigationData::default` reads the system
+    /// clock, which panics on wasm, so it can't be built with the resource
+    pub data: Option<NavigationData>,
+}
+
+impl Default for NavigationRecorder {
+    fn default() -> Self {
+        Self::with_sample_interval(NAV_RECORDING_SAMPLE_INTERVAL_SECS)
+    }
+}
+
+impl NavigationRecorder {
+    pub fn with_sample_interval(seconds: f32) -> Self {
+        Self {
+            recording: false,
+            sample_timer: Timer::from_seconds(seconds, TimerMode::Repeating),
+            session_time: 0.0,
+            data: None,
+        }
+    }
+
+    /// Points captured in the current (or last) recording
+    pub fn points(&self) -> usize {
+        self.data
+            .as_ref()
+            .map_or(0, |data| data.statistics.total_points)
+    }
+
+    pub fn start(&mut self) {
+        // Each recording starts from a clean session
+        self.data = Some(NavigationData::default());
+        self.session_time = 0.0;
+        self.sample_timer.reset();
+        self.recording = true;
+        info!("Navigation recording started");
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+        info!(
+            "Navigation recording stopped with {} points",
+            self.points()
+        );
+        self.save();
+    }
+
+    pub fn toggle(&mut self) {
+        if self.recording {
+            self.stop();
+        } else {
+            self.start();
+        }
+    }
+
+    fn save(&self) {
+        let Some(data) = self.data.as_ref() else {
+            return;
+        };
+        if data.positions.is_empty() {
+            return; // Don't replace existing data with an empty session
+        }
+
+        match serde_json::to_string_pretty(data) {
+            Ok(contents) => match write_file(NAV_DATA_PATH, &contents) {
+                Ok(()) => info!("Wrote navigation data to {}", NAV_DATA_PATH),
+                Err(e) => error!("Failed to write {}: {}", NAV_DATA_PATH, e),
+            },
+            Err(e) => error!("Failed to serialize navigation data: {}", e),
+        }
+    }
+}
+
+#[derive(Component)]
+struct NavRecordingIndicator;
+
+// ===== PLUGIN =====
+
+pub struct NavigationRecorderPlugin;
+
+impl Plugin for NavigationRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavigationRecorder>()
+            .add_systems(OnEnter(Screen::GamePlay), spawn_recording_indicator)
+            .add_systems(
+                Update,
+                (sample_player_position, update_recording_indicator)
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_systems(OnExit(Screen::GamePlay), (stop_recording_on_exit, despawn_recording_indicator));
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn sample_player_position(
+    mut recorder: ResMut<NavigationRecorder>,
+    player_query: Query<&Transform, With<CharacterController>>,
+    time: Res<Time>,
+) {
+    if !recorder.recording {
+        return;
+    }
+
+    recorder.session_time += time.delta_secs();
+    if !recorder.sample_timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if let Ok(player_transform) = player_query.single() {
+        let session_time = recorder.session_time;
+        if let Some(data) = recorder.data.as_mut() {
+            data.record_point(player_transform.translation, session_time);
+        }
+    }
+}
+
+fn stop_recording_on_exit(mut recorder: ResMut<NavigationRecorder>) {
+    if recorder.recording {
+        recorder.stop();
+    }
+}
+
+fn spawn_recording_indicator(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(16.0),
+            left: Val::Percent(50.0),
+            ..default()
+        },
+        Text::new("● REC"),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(Color::DANGER_RED),
+        Visibility::Hidden,
+        NavRecordingIndicator,
+    ));
+}
+
+fn update_recording_indicator(
+    recorder: Res<NavigationRecorder>,
+    mut indicator_query: Query<(&mut Visibility, &mut Text), With<NavRecordingIndicator>>,
+) {
+    if !recorder.is_changed() {
+        return;
+    }
+
+    for (mut visibility, mut text) in indicator_query.iter_mut() {
+        *visibility = if recorder.recording {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        **text = format!("● REC {} pts", recorder.points());
+    }
+}
+
+fn despawn_recording_indicator(
+    mut commands: Commands,
+    query: Query<Entity, With<NavRecordingIndicator>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
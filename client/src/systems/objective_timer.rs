@@ -0,0 +1,257 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::screens::Screen;
+use crate::systems::health::GameOverState;
+use crate::systems::objectives::{ObjectiveCountdownText, ObjectiveManager, countdown_label};
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted, ConfirmDialogState, ModalState};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Sent once when a timed objective runs out of time
+#[derive(Event, Debug)]
+pub struct ObjectiveFailed {
+    pub objective_id: usize,
+}
+
+/// Countdowns for the level's timed objectives, keyed by objective id
+#[derive(Resource, Default)]
+pub struct ObjectiveTimer {
+    timers: HashMap<usize, Timer>,
+}
+
+impl ObjectiveTimer {
+    /// Starts a countdown for `objective_id` unless one is already running
+    pub fn track(&mut self, objective_id: usize, time_limit: f32) {
+        self.timers
+            .entry(objective_id)
+            .or_insert_with(|| Timer::from_seconds(time_limit, TimerMode::Once));
+    }
+
+    pub fn stop(&mut self, objective_id: usize) {
+        self.timers.remove(&objective_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.timers.clear();
+    }
+
+    pub fn remaining_secs(&self, objective_id: usize) -> Option<f32> {
+        self.timers.get(&objective_id).map(Timer::remaining_secs)
+    }
+
+    /// Advances every countdown, returning the objectives that just ran out. Expired
+    /// timers are dropped, so each objective is reported exactly once.
+    pub fn tick(&mut self, delta: std::time::Duration) -> Vec<usize> {
+        let mut expired = Vec::new();
+        self.timers.retain(|objective_id, timer| {
+            if timer.tick(delta).finished() {
+                expired.push(*objective_id);
+                false
+            } else {
+                true
+            }
+        });
+        expired
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct ObjectiveTimerPlugin;
+
+impl Plugin for ObjectiveTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObjectiveTimer>()
+            .add_event::<ObjectiveFailed>()
+            .add_systems(OnEnter(Screen::GamePlay), reset_objective_timers)
+            .add_systems(
+                Update,
+                (
+                    sync_objective_timers,
+                    tick_objective_timers,
+                    fail_timed_out_objectives,
+                    update_countdown_texts,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_observer(reset_objective_timers_on_restart);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_objective_timers(mut objective_timer: ResMut<ObjectiveTimer>) {
+    objective_timer.clear();
+}
+
+/// The restarted level's objectives reuse the same ids, so their countdowns start over
+fn reset_objective_timers_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut objective_timer: ResMut<ObjectiveTimer>,
+) {
+    if trigger.0 == ConfirmAction::RestartLevel {
+        objective_timer.clear();
+    }
+}
+
+/// Starts countdowns for timed objectives as they unlock and stops the ones that got completed
+fn sync_objective_timers(objective_manager: Res<ObjectiveManager>, mut objective_timer: ResMut<ObjectiveTimer>) {
+    if !objective_manager.is_changed() {
+        return;
+    }
+    for objective in &objective_manager.objectives {
+        match objective.time_limit {
+            Some(time_limit) if objective_manager.is_trackable(objective) => {
+                objective_timer.track(objective.id, time_limit)
+            }
+            _ => objective_timer.stop(objective.id),
+        }
+    }
+}
+
+/// Counts down while playing; the objectives modal, a confirm dialog or the
+/// game-over screen hold the clock
+fn tick_objective_timers(
+    time: Res<Time>,
+    modal_state: Option<Res<ModalState>>,
+    confirm_state: Res<ConfirmDialogState>,
+    game_over: Res<GameOverState>,
+    mut objective_timer: ResMut<ObjectiveTimer>,
+    mut failed_events: EventWriter<ObjectiveFailed>,
+) {
+    let paused = modal_state.is_some_and(|modal_state| modal_state.visible)
+        || confirm_state.is_open()
+        || game_over.is_active();
+    if paused {
+        return;
+    }
+    for objective_id in objective_timer.tick(time.delta()) {
+        failed_events.write(ObjectiveFailed { objective_id });
+    }
+}
+
+fn fail_timed_out_objectives(
+    mut failed_events: EventReader<ObjectiveFailed>,
+    mut objective_manager: ResMut<ObjectiveManager>,
+) {
+    for event in failed_events.read() {
+        let Some(objective) = objective_manager
+            .objectives
+            .iter_mut()
+            .find(|objective| objective.id == event.objective_id && objective.is_active())
+        else {
+            continue;
+        };
+        objective.failed = true;
+        info!("Objective failed, out of time: {}", objective.title);
+    }
+}
+
+fn update_countdown_texts(
+    objective_manager: Res<ObjectiveManager>,
+    objective_timer: Res<ObjectiveTimer>,
+    mut text_query: Query<(&ObjectiveCountdownText, &mut Text)>,
+) {
+    for (countdown, mut text) in text_query.iter_mut() {
+        let Some(objective) = objective_manager
+            .objectives
+            .iter()
+            .find(|objective| objective.id == countdown.objective_id)
+        else {
+            continue;
+        };
+        let label = countdown_label(objective, objective_timer.remaining_secs(objective.id));
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::systems::collectibles::CollectibleType;
+    use crate::systems::objectives::Objective;
+    use crate::test_support::headless_app;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct FailedCount(usize);
+
+    fn count_failures(mut failed_events: EventReader<ObjectiveFailed>, mut count: ResMut<FailedCount>) {
+        count.0 += failed_events.read().count();
+    }
+
+    /// A single coin objective with a one second limit, ticking 400ms a frame
+    fn timer_app() -> App {
+        let mut manager = ObjectiveManager::default();
+        manager.add_objective(
+            Objective::new(0, "Coins".to_string(), String::new(), CollectibleType::Coin, 1).timed(1.0),
+        );
+        let mut app = headless_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(400)))
+            .insert_resource(manager)
+            .init_resource::<ObjectiveTimer>()
+            .init_resource::<ConfirmDialogState>()
+            .init_resource::<GameOverState>()
+            .init_resource::<FailedCount>()
+            .add_event::<ObjectiveFailed>()
+            .add_systems(
+                Update,
+                (sync_objective_timers, tick_objective_timers, fail_timed_out_objectives, count_failures).chain(),
+            );
+        app
+    }
+
+    fn failed_count(app: &App) -> usize {
+        app.world().resource::<FailedCount>().0
+    }
+
+    #[test]
+    fn expiry_fails_the_objective_exactly_once() {
+        let mut app = timer_app();
+        // The first update has no delta, then 400ms, 800ms
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(failed_count(&app), 0);
+
+        app.update();
+        assert_eq!(failed_count(&app), 1);
+        assert!(app.world().resource::<ObjectiveManager>().objectives[0].failed);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(failed_count(&app), 1);
+    }
+
+    #[test]
+    fn confirm_dialog_holds_the_clock() {
+        let mut app = timer_app();
+        app.world_mut().resource_mut::<ConfirmDialogState>().pending = Some(ConfirmAction::QuitGame);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(failed_count(&app), 0);
+        assert!(app.world().resource::<ObjectiveTimer>().remaining_secs(0).is_some());
+    }
+
+    #[test]
+    fn completed_objectives_stop_their_countdown() {
+        let mut app = timer_app();
+        app.update();
+        app.world_mut()
+            .resource_mut::<ObjectiveManager>()
+            .record_pickup(CollectibleType::Coin);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(failed_count(&app), 0);
+        assert!(!app.world().resource::<ObjectiveManager>().objectives[0].failed);
+    }
+}
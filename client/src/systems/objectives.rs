@@ -1,9 +1,18 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::constants::objectives::{DEFAULT_OBJECTIVE_COMPLETION_RADIUS, TIMED_CRYSTAL_OBJECTIVE_SECS};
+use crate::resources::hud::HudSettings;
+use crate::resources::locale::Locale;
 use crate::screens::Screen;
+use crate::systems::boundary::BoundaryConstraint;
+use crate::systems::character_controller::CharacterController;
 use crate::systems::collectibles::CollectibleType;
-use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::systems::dojo::PickupItemEvent;
+use crate::ui::styles::{
+    ElysiumDescentColorPalette, PaletteBackground, PaletteBorder, PaletteRole, PaletteText,
+};
+use crate::ui::widgets::{ProgressBar, ProgressBarColors, progress_bar_widget};
 
 // ===== COMPONENTS & RESOURCES =====
 
@@ -18,6 +27,22 @@ pub struct ObjectiveSlot {
 #[derive(Component)]
 pub struct ObjectiveCheckmark;
 
+/// An objective's `ProgressBar`, updated in place for location objectives
+#[derive(Component)]
+pub struct ObjectiveProgressBar {
+    pub objective_id: usize,
+}
+
+/// The "View More" button kept at the end of the objectives list
+#[derive(Component)]
+pub struct ObjectiveViewMoreButton;
+
+/// Time left on a timed objective, kept current by the objective timer between panel rebuilds
+#[derive(Component)]
+pub struct ObjectiveCountdownText {
+    pub objective_id: usize,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Objective {
     pub id: usize,
@@ -27,6 +52,29 @@ pub struct Objective {
     pub required_count: u32,
     pub current_count: u32,
     pub completed: bool,
+    /// World position the player has to reach; `None` for collection objectives
+    pub target_position: Option<[f32; 3]>,
+    pub completion_radius: Option<f32>,
+    /// Distance to the target when tracking started; location progress is measured against it
+    #[serde(default)]
+    pub initial_distance: Option<f32>,
+    /// Optional (bonus) objectives still count for rewards but never block the level exit
+    #[serde(default = "default_required")]
+    pub required: bool,
+    /// Seconds the objective can be worked on before it fails; `None` for untimed ones
+    #[serde(default)]
+    pub time_limit: Option<f32>,
+    /// Ran out of time; failed objectives no longer make progress
+    #[serde(default)]
+    pub failed: bool,
+    /// Ids of objectives that have to be completed before this one unlocks
+    #[serde(default)]
+    pub prerequisites: Vec<usize>,
+}
+
+// Saves from before optional objectives existed had only required ones
+fn default_required() -> bool {
+    true
 }
 
 impl Objective {
@@ -39,10 +87,117 @@ impl Objective {
             required_count,
             current_count: 0,
             completed: false,
+            target_position: None,
+            completion_radius: None,
+            initial_distance: None,
+            required: true,
+            time_limit: None,
+            failed: false,
+            prerequisites: Vec::new(),
         }
     }
 
-    // Removed unused is_completed and add_progress methods
+    /// Keeps the objective locked until `prerequisite` is completed
+    pub fn after(mut self, prerequisite: usize) -> Self {
+        self.prerequisites.push(prerequisite);
+        self
+    }
+
+    /// Gives the objective `seconds` from the start of the level before it fails
+    pub fn timed(mut self, seconds: f32) -> Self {
+        self.time_limit = Some(seconds);
+        self
+    }
+
+    /// Still counting towards completion, i.e. neither completed nor failed
+    pub fn is_active(&self) -> bool {
+        !self.completed && !self.failed
+    }
+
+    /// Marks the objective as a bonus that doesn't have to be done to finish the level
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Title as shown in the HUD, tagging bonus objectives
+    pub fn display_title(&self) -> String {
+        if self.required {
+            self.title.clone()
+        } else {
+            format!("{} (OPTIONAL)", self.title)
+        }
+    }
+
+    pub fn reach_location(id: usize, title: String, description: String, position: Vec3, completion_radius: Option<f32>) -> Self {
+        Self {
+            target_position: Some(position.to_array()),
+            completion_radius,
+            ..Self::new(id, title, description, CollectibleType::Book, 1)
+        }
+    }
+
+    pub fn is_location(&self) -> bool {
+        self.target_position.is_some()
+    }
+
+    /// Makes a location objective reachable: non-positive radii fall back to the
+    /// default and targets outside the playable area are pulled back inside it.
+    pub fn validate_location(&mut self, boundary: &BoundaryConstraint) {
+        let Some(position) = self.target_position else {
+            return;
+        };
+
+        if let Some(radius) = self.completion_radius {
+            if radius <= 0.0 || !radius.is_finite() {
+                warn!(
+                    "Objective '{}' has invalid completion radius {}, using {}",
+                    self.title, radius, DEFAULT_OBJECTIVE_COMPLETION_RADIUS
+                );
+                self.completion_radius = Some(DEFAULT_OBJECTIVE_COMPLETION_RADIUS);
+            }
+        }
+
+        let clamped = [
+            position[0].clamp(boundary.min_x, boundary.max_x),
+            position[1],
+            position[2].clamp(boundary.min_z, boundary.max_z),
+        ];
+        if clamped != position {
+            warn!(
+                "Objective '{}' target {:?} is outside the level boundary, clamped to {:?}",
+                self.title, position, clamped
+            );
+            self.target_position = Some(clamped);
+        }
+    }
+
+    pub fn completion_radius(&self) -> f32 {
+        self.completion_radius.unwrap_or(DEFAULT_OBJECTIVE_COMPLETION_RADIUS)
+    }
+
+    /// Fraction of the objective done, from 0 to 1. Location objectives fill up as the
+    /// player closes in on the target and never overfill when they walk away.
+    pub fn progress(&self, player_pos: Option<Vec3>) -> f32 {
+        if self.completed {
+            return 1.0;
+        }
+        if let Some(target) = self.target_position {
+            let (Some(player_pos), Some(initial_distance)) = (player_pos, self.initial_distance) else {
+                return 0.0;
+            };
+            if initial_distance <= 0.0 {
+                return 0.0;
+            }
+            let distance = player_pos.distance(Vec3::from_array(target));
+            return 1.0 - (distance / initial_distance).clamp(0.0, 1.0);
+        }
+        if self.required_count > 0 {
+            self.current_count as f32 / self.required_count as f32
+        } else {
+            1.0
+        }
+    }
 }
 
 #[derive(Resource, Default)]
@@ -59,7 +214,58 @@ impl ObjectiveManager {
         self.next_id += 1;
     }
 
-    // Removed unused update_progress, get_objective, and are_all_completed methods
+    /// Advance every incomplete objective that tracks the picked up item type
+    pub fn record_pickup(&mut self, item_type: CollectibleType) {
+        // Decided up front, so a pickup that completes a prerequisite doesn't also count
+        // towards the objective it unlocks
+        let unlocked = self.unlocked_flags();
+        for (objective, _) in self
+            .objectives
+            .iter_mut()
+            .zip(unlocked)
+            .filter(|(objective, unlocked)| {
+                *unlocked && objective.is_active() && !objective.is_location() && objective.item_type == item_type
+            })
+        {
+            objective.current_count = (objective.current_count + 1).min(objective.required_count);
+            objective.completed = objective.current_count >= objective.required_count;
+        }
+    }
+
+    /// Whether every prerequisite of `objective` has been completed
+    pub fn is_unlocked(&self, objective: &Objective) -> bool {
+        objective.prerequisites.iter().all(|prerequisite| {
+            self.objectives
+                .iter()
+                .any(|other| other.id == *prerequisite && other.completed)
+        })
+    }
+
+    /// `is_unlocked` for every objective, in order
+    pub fn unlocked_flags(&self) -> Vec<bool> {
+        self.objectives.iter().map(|objective| self.is_unlocked(objective)).collect()
+    }
+
+    /// Unlocked and neither completed nor failed, i.e. the objective can make progress
+    pub fn is_trackable(&self, objective: &Objective) -> bool {
+        objective.is_active() && self.is_unlocked(objective)
+    }
+
+    /// Whether every required objective is done, which opens the level exit; optional
+    /// ones are ignored
+    pub fn required_completed(&self) -> bool {
+        !self.objectives.is_empty() && self.remaining_required() == 0
+    }
+
+    /// Required objectives still standing between the player and the level exit
+    pub fn remaining_required(&self) -> usize {
+        self.objectives
+            .iter()
+            .filter(|objective| objective.required && !objective.completed)
+            .count()
+    }
+
+    // Removed unused update_progress and get_objective methods
 }
 
 // ===== PLUGIN =====
@@ -72,14 +278,32 @@ impl Plugin for ObjectivesPlugin {
             .add_systems(OnEnter(Screen::GamePlay), setup_initial_objectives)
             .add_systems(
                 Update,
-                (update_objective_ui,).run_if(in_state(Screen::GamePlay)),
+                (
+                    update_objective_progress,
+                    check_location_objectives,
+                    update_objective_ui,
+                    update_location_progress_bars,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
             );
     }
 }
 
 // ===== SYSTEMS =====
 
-fn setup_initial_objectives(mut objective_manager: ResMut<ObjectiveManager>) {
+fn setup_initial_objectives(
+    mut objective_manager: ResMut<ObjectiveManager>,
+    boundary_constraint: Res<BoundaryConstraint>,
+) {
+    apply_level_objectives(&mut objective_manager, &boundary_constraint);
+}
+
+/// Replaces the objectives with the current level's starting set, e.g. on entry or restart
+pub fn apply_level_objectives(
+    objective_manager: &mut ObjectiveManager,
+    boundary_constraint: &BoundaryConstraint,
+) {
     // Clear any existing objectives
     objective_manager.objectives.clear();
     objective_manager.next_id = 0;
@@ -106,25 +330,94 @@ fn setup_initial_objectives(mut objective_manager: ResMut<ObjectiveManager>) {
     objective_manager.add_objective(coin_objective);
 
     let exploration_id = objective_manager.next_id;
-    let mut exploration_objective = Objective::new(exploration_id, "Explore Ancient Ruins".to_string(), "Visit 3 Ancient Ruins".to_string(), CollectibleType::Book, 3);
-    exploration_objective.current_count = 3; // 3/3 completed (equivalent to 5/5)
-    exploration_objective.completed = true; // Mark as completed
+    let exploration_objective = Objective::reach_location(exploration_id, "Explore Ancient Ruins".to_string(), "Find the ancient tome in the ruins".to_string(), Vec3::new(90.0, 22.0, -54.0), None)
+        .after(book_id);
     objective_manager.add_objective(exploration_objective);
+
+    let crystal_id = objective_manager.next_id;
+    let crystal_objective = Objective::new(crystal_id, "Harness Power Crystals".to_string(), "Collect 3 Power Crystals".to_string(), CollectibleType::PowerCrystal, 3)
+        .optional()
+        .timed(TIMED_CRYSTAL_OBJECTIVE_SECS);
+    objective_manager.add_objective(crystal_objective);
+
+    // Validate location objectives up front so none of them are silently unreachable
+    for objective in objective_manager.objectives.iter_mut() {
+        objective.validate_location(boundary_constraint);
+    }
+}
+
+fn check_location_objectives(
+    player_query: Query<&Transform, With<CharacterController>>,
+    mut objective_manager: ResMut<ObjectiveManager>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+
+    // Locked objectives are left alone until their prerequisites are done
+    let unlocked = objective_manager.unlocked_flags();
+
+    // Location objectives measure progress from where the player was when they unlocked
+    let needs_initial_distance = objective_manager
+        .objectives
+        .iter()
+        .zip(&unlocked)
+        .any(|(objective, unlocked)| *unlocked && objective.is_location() && objective.initial_distance.is_none());
+    if needs_initial_distance {
+        for (objective, _) in objective_manager.objectives.iter_mut().zip(&unlocked).filter(|(_, unlocked)| **unlocked) {
+            if let (Some(target), None) = (objective.target_position, objective.initial_distance) {
+                objective.initial_distance = Some(player_pos.distance(Vec3::from_array(target)));
+            }
+        }
+    }
+
+    // Find reached objectives first so the manager is only marked changed when something completes
+    let reached: Vec<usize> = objective_manager
+        .objectives
+        .iter()
+        .enumerate()
+        .filter(|(index, objective)| unlocked[*index] && objective.is_active())
+        .filter_map(|(index, objective)| {
+            let target = Vec3::from_array(objective.target_position?);
+            (player_pos.distance(target) <= objective.completion_radius()).then_some(index)
+        })
+        .collect();
+
+    for index in reached {
+        let objective = &mut objective_manager.objectives[index];
+        objective.current_count = objective.required_count;
+        objective.completed = true;
+        info!("Objective completed: {}", objective.title);
+    }
+}
+
+fn update_objective_progress(
+    mut pickup_events: EventReader<PickupItemEvent>,
+    mut objective_manager: ResMut<ObjectiveManager>,
+) {
+    for event in pickup_events.read() {
+        objective_manager.record_pickup(event.item_type);
+    }
 }
 
 fn update_objective_ui(
     mut commands: Commands,
     objective_manager: Res<ObjectiveManager>,
+    hud_settings: Res<HudSettings>,
+    player_query: Query<&Transform, With<CharacterController>>,
     font_assets: Option<Res<crate::assets::FontAssets>>,
     ui_assets: Option<Res<crate::assets::UiAssets>>,
     _objectives_ui_query: Query<Entity, With<ObjectiveUI>>,
     objectives_list_query: Query<Entity, (With<Node>, With<Name>)>,
-    existing_slots: Query<Entity, With<ObjectiveSlot>>,
+    existing_slots: Query<Entity, Or<(With<ObjectiveSlot>, With<ObjectiveViewMoreButton>)>>,
     _children: Query<&Children>,
     names: Query<&Name>,
+    locale: Res<Locale>,
 ) {
-    if !objective_manager.is_changed() {
-        return; // Only update when objectives change
+    // Only update when objectives or the completed filter change
+    if !objective_manager.is_changed() && !hud_settings.is_changed() {
+        return;
     }
 
     let Some(font_assets) = font_assets else { return; };
@@ -146,7 +439,7 @@ fn update_objective_ui(
         return;
     };
 
-    // Clear existing objective slots
+    // Clear existing objective slots and the "View More" button
     for slot_entity in existing_slots.iter() {
         commands.entity(slot_entity).despawn();
     }
@@ -155,28 +448,97 @@ fn update_objective_ui(
     let font = font_assets.rajdhani_bold.clone();
     let coin_image = ui_assets.coin.clone(); // Using coin as placeholder for all items
 
-    for objective in &objective_manager.objectives {
-        let slot_entity = commands.spawn(create_objective_slot(objective, font.clone(), coin_image.clone(), ui_assets.green_check_icon.clone())).id();
+    // Active objectives first, required before optional, locked ones last; the stable sort
+    // keeps each group in insertion order
+    let mut objectives: Vec<(&Objective, bool)> = objective_manager
+        .objectives
+        .iter()
+        .filter(|objective| hud_settings.show_completed_objectives || !objective.completed)
+        .map(|objective| (objective, !objective_manager.is_unlocked(objective)))
+        .collect();
+    objectives.sort_by_key(|(objective, locked)| (objective.completed, *locked, !objective.required));
+
+    let player_pos = player_query.single().ok().map(|transform| transform.translation);
+    for (objective, locked) in objectives {
+        let progress = objective.progress(player_pos);
+        let lock_note = locked.then(|| locked_description(&objective_manager, objective));
+        let slot_entity = commands.spawn(create_objective_slot(objective, progress, lock_note, font.clone(), coin_image.clone(), ui_assets.green_check_icon.clone())).id();
         commands.entity(list_entity).add_child(slot_entity);
     }
 
     // Add "View More" button after objectives
-    let view_more_entity = commands.spawn(create_view_more_button(font.clone())).id();
+    let view_more_entity = commands.spawn(create_view_more_button(font.clone(), locale.t("objectives.view_more"))).id();
     commands.entity(list_entity).add_child(view_more_entity);
 }
 
+/// Keeps location objectives' bars following the player between panel rebuilds
+fn update_location_progress_bars(
+    player_query: Query<&Transform, With<CharacterController>>,
+    objective_manager: Res<ObjectiveManager>,
+    mut bar_query: Query<(&ObjectiveProgressBar, &mut ProgressBar)>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+
+    for (objective_bar, mut bar) in bar_query.iter_mut() {
+        let Some(objective) = objective_manager
+            .objectives
+            .iter()
+            .find(|objective| objective.id == objective_bar.objective_id && objective.is_location())
+        else {
+            continue;
+        };
+        let progress = objective.progress(Some(player_pos));
+        if bar.progress != progress {
+            bar.progress = progress;
+        }
+    }
+}
+
+/// Countdown shown next to an objective's progress; empty for untimed or finished ones
+pub fn countdown_label(objective: &Objective, remaining_secs: Option<f32>) -> String {
+    if objective.failed {
+        return "FAILED".to_string();
+    }
+    match remaining_secs {
+        Some(secs) if !objective.completed => {
+            let secs = secs.max(0.0).ceil() as u32;
+            format!("{}:{:02}", secs / 60, secs % 60)
+        }
+        _ => String::new(),
+    }
+}
+
+/// Stands in for a locked objective's description, naming what unlocks it
+fn locked_description(objective_manager: &ObjectiveManager, objective: &Objective) -> String {
+    let pending: Vec<&str> = objective
+        .prerequisites
+        .iter()
+        .filter_map(|id| objective_manager.objectives.iter().find(|other| other.id == *id))
+        .filter(|prerequisite| !prerequisite.completed)
+        .map(|prerequisite| prerequisite.title.as_str())
+        .collect();
+    format!("LOCKED: {} first", pending.join(", "))
+}
+
+/// `lock_note` replaces the description while the objective is still locked
 fn create_objective_slot(
     objective: &Objective,
+    progress_percent: f32,
+    lock_note: Option<String>,
     font: Handle<Font>,
     item_image: Handle<Image>,
     check_icon: Handle<Image>,
 ) -> impl Bundle {
-    let progress_percent = if objective.required_count > 0 {
-        objective.current_count as f32 / objective.required_count as f32
+    // Bonus and locked objectives are dimmed so the ones in play stand out
+    let title_color = if objective.required && lock_note.is_none() {
+        Color::WHITE
     } else {
-        1.0
+        Color::WHITE.with_alpha(0.7)
     };
-
+    let description = lock_note.unwrap_or_else(|| objective.description.clone());
     (
         Node {
             width: Val::Percent(100.0),
@@ -248,6 +610,8 @@ fn create_objective_slot(
                         },
                         BackgroundColor(Color::SUCCESS_GREEN),
                         BorderColor(Color::SUCCESS_GREEN),
+                        PaletteBackground(PaletteRole::Success),
+                        PaletteBorder(PaletteRole::Success),
                         BorderRadius::MAX,
                         Name::new("CompletionCheckmark"),
                         children![(
@@ -276,13 +640,13 @@ fn create_objective_slot(
                 children![
                     // Objective Title
                     (
-                        Text::new(&objective.title),
+                        Text::new(objective.display_title()),
                         TextFont {
                             font: font.clone(),
                             font_size: 21.0,
                             ..default()
                         },
-                        TextColor(Color::WHITE),
+                        TextColor(title_color),
                         Node {
                             margin: UiRect::bottom(Val::Px(6.0)),
                             ..default()
@@ -290,7 +654,7 @@ fn create_objective_slot(
                     ),
                     // Objective Description
                     (
-                        Text::new(&objective.description),
+                        Text::new(description),
                         TextFont {
                             font: font.clone(),
                             font_size: 18.0,
@@ -302,43 +666,54 @@ fn create_objective_slot(
                             ..default()
                         },
                     ),
-                    // Progress Text
+                    // Progress Text, with the countdown beside it for timed objectives
                     (
-                        Text::new(format!("{}/{}", objective.current_count, objective.required_count)),
-                        TextFont {
-                            font: font.clone(),
-                            font_size: 18.0,
-                            ..default()
-                        },
-                        TextColor(Color::ELYSIUM_GOLD),
                         Node {
+                            column_gap: Val::Px(12.0),
                             margin: UiRect::bottom(Val::Px(6.0)),
                             ..default()
                         },
-                    ),
-                    // Progress Bar
-                    (
-                        Node {
-                            width: Val::Px(270.0),
-                            height: Val::Px(12.0),
-                            border: UiRect::all(Val::Px(1.5)),
-                            ..default()
-                        },
-                        BackgroundColor(Color::DARKER_GLASS),
-                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.4)),
-                        BorderRadius::all(Val::Px(6.0)),
                         children![
                             (
-                                Node {
-                                    width: Val::Px(267.0 * progress_percent),
-                                    height: Val::Px(9.0),
-                                    margin: UiRect::all(Val::Px(1.5)),
+                                Text::new(format!("{}/{}", objective.current_count, objective.required_count)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 18.0,
                                     ..default()
                                 },
-                                BackgroundColor(Color::ELYSIUM_GOLD),
-                                BorderRadius::all(Val::Px(4.5)),
-                            )
+                                TextColor(Color::ELYSIUM_GOLD),
+                            ),
+                            (
+                                Text::new(countdown_label(objective, objective.time_limit)),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 18.0,
+                                    ..default()
+                                },
+                                TextColor(Color::DANGER_RED),
+                                PaletteText(PaletteRole::Danger),
+                                ObjectiveCountdownText {
+                                    objective_id: objective.id,
+                                },
+                            ),
                         ]
+                    ),
+                    // Progress Bar
+                    (
+                        progress_bar_widget(
+                            270.0,
+                            12.0,
+                            progress_percent,
+                            ProgressBarColors {
+                                track: Color::DARKER_GLASS,
+                                border: Color::ELYSIUM_GOLD.with_alpha(0.4),
+                                fill: Color::ELYSIUM_GOLD,
+                                chip: None,
+                            },
+                        ),
+                        ObjectiveProgressBar {
+                            objective_id: objective.id,
+                        },
                     )
                 ]
             )
@@ -346,7 +721,7 @@ fn create_objective_slot(
     )
 }
 
-fn create_view_more_button(font: Handle<Font>) -> impl Bundle {
+fn create_view_more_button(font: Handle<Font>, label: String) -> impl Bundle {
     (
         Node {
             width: Val::Percent(100.0),
@@ -357,9 +732,10 @@ fn create_view_more_button(font: Handle<Font>) -> impl Bundle {
             ..default()
         },
         Name::new("View More Button"),
+        ObjectiveViewMoreButton,
         Interaction::None,
         children![(
-            Text::new("VIEW MORE"),
+            Text::new(label),
             TextFont {
                 font,
                 font_size: 20.0,
@@ -370,4 +746,187 @@ fn create_view_more_button(font: Handle<Font>) -> impl Bundle {
     )
 }
 
- 
\ No newline at end of file
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::headless_app;
+
+    fn pickup(item_type: CollectibleType) -> PickupItemEvent {
+        PickupItemEvent {
+            item_type,
+            item_entity: Entity::PLACEHOLDER,
+            position: Vec3::ZERO,
+        }
+    }
+
+    fn objectives_app(objectives: Vec<Objective>) -> App {
+        let mut manager = ObjectiveManager::default();
+        for objective in objectives {
+            manager.add_objective(objective);
+        }
+        let mut app = headless_app();
+        app.insert_resource(manager)
+            .add_event::<PickupItemEvent>()
+            .add_systems(Update, update_objective_progress);
+        app
+    }
+
+    #[test]
+    fn pickup_events_complete_every_objective() {
+        let mut app = objectives_app(vec![
+            Objective::new(0, "Coins".to_string(), String::new(), CollectibleType::Coin, 2),
+            Objective::new(1, "Books".to_string(), String::new(), CollectibleType::Book, 1),
+        ]);
+
+        app.world_mut().send_event(pickup(CollectibleType::Coin));
+        app.world_mut().send_event(pickup(CollectibleType::Book));
+        app.update();
+        let manager = app.world().resource::<ObjectiveManager>();
+        assert!(manager.objectives[1].completed);
+        assert!(!manager.required_completed());
+
+        app.world_mut().send_event(pickup(CollectibleType::Coin));
+        app.update();
+        assert!(app.world().resource::<ObjectiveManager>().required_completed());
+    }
+
+    #[test]
+    fn pickups_of_other_types_make_no_progress() {
+        let mut app = objectives_app(vec![Objective::new(
+            0,
+            "Coins".to_string(),
+            String::new(),
+            CollectibleType::Coin,
+            1,
+        )]);
+
+        app.world_mut().send_event(pickup(CollectibleType::HealthPotion));
+        app.update();
+        let manager = app.world().resource::<ObjectiveManager>();
+        assert_eq!(manager.objectives[0].current_count, 0);
+        assert!(!manager.required_completed());
+    }
+
+    #[test]
+    fn crystal_objectives_progress_on_crystals_but_not_books() {
+        let mut app = objectives_app(vec![Objective::new(
+            0,
+            "Crystals".to_string(),
+            String::new(),
+            CollectibleType::PowerCrystal,
+            2,
+        )]);
+
+        app.world_mut().send_event(pickup(CollectibleType::Book));
+        app.update();
+        assert_eq!(app.world().resource::<ObjectiveManager>().objectives[0].current_count, 0);
+
+        app.world_mut().send_event(pickup(CollectibleType::PowerCrystal));
+        app.update();
+        assert_eq!(app.world().resource::<ObjectiveManager>().objectives[0].current_count, 1);
+    }
+
+    fn test_boundary() -> BoundaryConstraint {
+        BoundaryConstraint {
+            min_x: -50.0,
+            max_x: 50.0,
+            min_z: -50.0,
+            max_z: 50.0,
+        }
+    }
+
+    #[test]
+    fn negative_completion_radius_falls_back_to_the_default() {
+        let mut objective = Objective::reach_location(
+            0,
+            "Ruins".to_string(),
+            String::new(),
+            Vec3::new(10.0, 2.0, 10.0),
+            Some(-3.0),
+        );
+        objective.validate_location(&test_boundary());
+        assert_eq!(objective.completion_radius, Some(DEFAULT_OBJECTIVE_COMPLETION_RADIUS));
+        assert_eq!(objective.target_position, Some([10.0, 2.0, 10.0]));
+    }
+
+    #[test]
+    fn out_of_bounds_target_is_clamped_into_the_boundary() {
+        let mut objective = Objective::reach_location(
+            0,
+            "Ruins".to_string(),
+            String::new(),
+            Vec3::new(80.0, 2.0, -70.0),
+            Some(4.0),
+        );
+        objective.validate_location(&test_boundary());
+        assert_eq!(objective.target_position, Some([50.0, 2.0, -50.0]));
+        assert_eq!(objective.completion_radius, Some(4.0));
+    }
+
+    #[test]
+    fn missing_completion_radius_uses_the_default() {
+        let objective = Objective::reach_location(0, "Ruins".to_string(), String::new(), Vec3::ZERO, None);
+        assert_eq!(objective.completion_radius(), DEFAULT_OBJECTIVE_COMPLETION_RADIUS);
+    }
+
+    #[test]
+    fn incomplete_optional_objective_still_allows_completion() {
+        let mut manager = ObjectiveManager::default();
+        manager.add_objective(Objective::new(0, "Coins".to_string(), String::new(), CollectibleType::Coin, 1));
+        manager.add_objective(
+            Objective::new(1, "Books".to_string(), String::new(), CollectibleType::Book, 1).optional(),
+        );
+        assert_eq!(manager.remaining_required(), 1);
+
+        manager.record_pickup(CollectibleType::Coin);
+        assert_eq!(manager.remaining_required(), 0);
+        assert!(manager.required_completed());
+        assert!(!manager.objectives[1].completed);
+    }
+
+    #[test]
+    fn optional_objectives_alone_never_complete_the_level() {
+        let mut manager = ObjectiveManager::default();
+        manager.add_objective(
+            Objective::new(0, "Books".to_string(), String::new(), CollectibleType::Book, 1).optional(),
+        );
+        manager.add_objective(Objective::new(1, "Coins".to_string(), String::new(), CollectibleType::Coin, 1));
+        manager.record_pickup(CollectibleType::Book);
+        assert!(manager.objectives[0].completed);
+        assert!(!manager.required_completed());
+    }
+
+    #[test]
+    fn dependent_objective_waits_for_its_prerequisite() {
+        let mut app = objectives_app(vec![
+            Objective::new(0, "Coins".to_string(), String::new(), CollectibleType::Coin, 1),
+            Objective::new(1, "More coins".to_string(), String::new(), CollectibleType::Coin, 1).after(0),
+        ]);
+        {
+            let manager = app.world().resource::<ObjectiveManager>();
+            assert!(!manager.is_unlocked(&manager.objectives[1]));
+        }
+
+        // The pickup that finishes the prerequisite doesn't spill into the chained objective
+        app.world_mut().send_event(pickup(CollectibleType::Coin));
+        app.update();
+        let manager = app.world().resource::<ObjectiveManager>();
+        assert!(manager.objectives[0].completed);
+        assert!(manager.is_unlocked(&manager.objectives[1]));
+        assert_eq!(manager.objectives[1].current_count, 0);
+
+        app.world_mut().send_event(pickup(CollectibleType::Coin));
+        app.update();
+        assert!(app.world().resource::<ObjectiveManager>().objectives[1].completed);
+    }
+
+    #[test]
+    fn objectives_without_prerequisites_start_unlocked() {
+        let manager = ObjectiveManager::default();
+        let objective = Objective::new(0, "Coins".to_string(), String::new(), CollectibleType::Coin, 1);
+        assert!(manager.is_unlocked(&objective));
+        // A prerequisite that doesn't exist can never complete, so it stays locked
+        assert!(!manager.is_unlocked(&objective.clone().after(7)));
+    }
+}
@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+
+use crate::constants::collectibles::PickupBurstConfig;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::CollectibleType;
+use crate::systems::destructibles::DestructibleDestroyed;
+use crate::systems::dojo::PickupItemEvent;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// One spark of a pickup burst; shrinks away over its lifetime
+#[derive(Component)]
+pub struct PickupParticle {
+    pub velocity: Vec3,
+    pub timer: Timer,
+}
+
+/// Mesh shared by every particle and one unlit material per collectible type, so a
+/// burst spawns no new assets
+#[derive(Resource)]
+pub struct PickupEffectAssets {
+    pub mesh: Handle<Mesh>,
+    pub coin: Handle<StandardMaterial>,
+    pub health_potion: Handle<StandardMaterial>,
+    pub survival_kit: Handle<StandardMaterial>,
+    pub book: Handle<StandardMaterial>,
+    pub power_crystal: Handle<StandardMaterial>,
+    /// Splinters from a broken destructible
+    pub debris: Handle<StandardMaterial>,
+}
+
+impl PickupEffectAssets {
+    pub fn material(&self, item_type: CollectibleType) -> Handle<StandardMaterial> {
+        match item_type {
+            CollectibleType::Coin => self.coin.clone(),
+            CollectibleType::HealthPotion => self.health_potion.clone(),
+            CollectibleType::SurvivalKit => self.survival_kit.clone(),
+            CollectibleType::Book => self.book.clone(),
+            CollectibleType::PowerCrystal => self.power_crystal.clone(),
+        }
+    }
+}
+
+pub fn burst_color(item_type: CollectibleType) -> Color {
+    match item_type {
+        CollectibleType::Coin => Color::srgb(1.0, 0.8, 0.25),
+        CollectibleType::HealthPotion => Color::srgb(1.0, 0.3, 0.35),
+        CollectibleType::SurvivalKit => Color::srgb(0.4, 0.9, 0.45),
+        CollectibleType::Book => Color::srgb(0.95, 0.9, 0.75),
+        CollectibleType::PowerCrystal => Color::srgb(0.45, 0.75, 1.0),
+    }
+}
+
+const DEBRIS_COLOR: Color = Color::srgb(0.6, 0.42, 0.25);
+
+// ===== PLUGIN =====
+
+pub struct PickupEffectsPlugin;
+
+impl Plugin for PickupEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_pickup_effect_assets)
+            .add_systems(
+                Update,
+                (spawn_pickup_bursts, spawn_break_bursts, update_pickup_particles)
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn setup_pickup_effect_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(
+        Sphere::new(PickupBurstConfig::PARTICLE_RADIUS)
+            .mesh()
+            .ico(1)
+            .unwrap(),
+    );
+    let mut material = |color: Color| {
+        materials.add(StandardMaterial {
+            base_color: color,
+            emissive: color.to_linear() * 2.0,
+            unlit: true,
+            ..default()
+        })
+    };
+    commands.insert_resource(PickupEffectAssets {
+        mesh,
+        coin: material(burst_color(CollectibleType::Coin)),
+        health_potion: material(burst_color(CollectibleType::HealthPotion)),
+        survival_kit: material(burst_color(CollectibleType::SurvivalKit)),
+        book: material(burst_color(CollectibleType::Book)),
+        power_crystal: material(burst_color(CollectibleType::PowerCrystal)),
+        debris: material(DEBRIS_COLOR),
+    });
+}
+
+/// Direction of particle `index` out of `count`: evenly spaced around the pickup and
+/// tilted upward, so every burst looks the same without any randomness
+pub fn burst_direction(index: usize, count: usize) -> Vec3 {
+    let angle = index as f32 / count.max(1) as f32 * std::f32::consts::TAU;
+    let horizontal =
+        Vec3::new(angle.cos(), 0.0, angle.sin()) * (1.0 - PickupBurstConfig::UPWARD_BIAS);
+    (horizontal + Vec3::Y * PickupBurstConfig::UPWARD_BIAS).normalize()
+}
+
+fn spawn_pickup_bursts(
+    mut commands: Commands,
+    mut pickup_events: EventReader<PickupItemEvent>,
+    assets: Res<PickupEffectAssets>,
+    particle_query: Query<(), With<PickupParticle>>,
+) {
+    let mut live_particles = particle_query.iter().count();
+    for event in pickup_events.read() {
+        if live_particles + PickupBurstConfig::PARTICLES > PickupBurstConfig::MAX_PARTICLES {
+            continue;
+        }
+        live_particles += PickupBurstConfig::PARTICLES;
+        spawn_burst(&mut commands, &assets, assets.material(event.item_type), event.position);
+    }
+}
+
+/// Broken props always get their burst; there are only ever a handful of them
+fn spawn_break_bursts(
+    mut commands: Commands,
+    mut destroyed_events: EventReader<DestructibleDestroyed>,
+    assets: Res<PickupEffectAssets>,
+) {
+    for event in destroyed_events.read() {
+        spawn_burst(&mut commands, &assets, assets.debris.clone(), event.position);
+    }
+}
+
+fn spawn_burst(
+    commands: &mut Commands,
+    assets: &PickupEffectAssets,
+    material: Handle<StandardMaterial>,
+    position: Vec3,
+) {
+    for index in 0..PickupBurstConfig::PARTICLES {
+        commands.spawn((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(position),
+            PickupParticle {
+                velocity: burst_direction(index, PickupBurstConfig::PARTICLES)
+                    * PickupBurstConfig::SPEED,
+                timer: Timer::from_seconds(PickupBurstConfig::LIFETIME_SECS, TimerMode::Once),
+            },
+            bevy::pbr::NotShadowCaster,
+            Name::new("Pickup Particle"),
+            PlayingScene,
+        ));
+    }
+}
+
+fn update_pickup_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particle_query: Query<(Entity, &mut PickupParticle, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, mut transform) in &mut particle_query {
+        if particle.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        particle.velocity.y -= PickupBurstConfig::GRAVITY * delta;
+        transform.translation += particle.velocity * delta;
+        // Shrinking instead of fading keeps the material shared
+        transform.scale = Vec3::splat(1.0 - particle.timer.fraction());
+    }
+}
@@ -0,0 +1,387 @@
+//! Saving game progress. Every save, automatic or manual, is requested with a
+//! [`SaveGameEvent`] and written by [`write_save_game`], so there's a single
+//! serialization routine. Saves go through the platform file helpers, which use
+//! `localStorage` on the web build; only without any storage is the write skipped.
+//! Continuing from the
+//! main menu loads the file into [`ResumeSave`], applied once gameplay starts.
+//! Quitting mid-level writes one last save from the shutdown observer, which
+//! can't wait for the event to be read next frame.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::FontAssets;
+use crate::constants::save::{AUTOSAVE_SETTINGS_PATH, AutosaveConfig, SAVE_GAME_PATH};
+use crate::game::Health;
+use crate::resources::platform::{
+    PersistedSettings, PlatformCapabilities, read_file, save_on_change, write_file,
+};
+use crate::resources::shutdown::ShutdownSave;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::collectibles::{CoinStreamingManager, PlayerStats};
+use crate::systems::objectives::{Objective, ObjectiveManager};
+use crate::ui::modal::{ConfirmDialogState, ModalState};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Everything written to the save file
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SaveData {
+    pub player_position: [f32; 3],
+    pub currency: u32,
+    pub xp: u32,
+    /// Coin positions already picked up, so they don't respawn
+    pub collected_coins: Vec<usize>,
+    pub objectives: Vec<Objective>,
+}
+
+impl SaveData {
+    /// The save on disk, or None when there isn't one or it doesn't parse
+    pub fn load() -> Option<Self> {
+        let contents = read_file(SAVE_GAME_PATH).ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(data) => Some(data),
+            Err(e) => {
+                warn!("Ignoring unreadable {}: {}", SAVE_GAME_PATH, e);
+                None
+            }
+        }
+    }
+}
+
+/// A save picked with Continue, waiting for gameplay to start so it can be applied
+#[derive(Resource)]
+pub struct ResumeSave(pub SaveData);
+
+/// Why a save was requested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveReason {
+    Interval,
+    ObjectiveCompleted,
+    Shutdown,
+}
+
+#[derive(Event, Debug)]
+pub struct SaveGameEvent(pub SaveReason);
+
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutosaveSettings {
+    pub enabled: bool,
+    pub interval_secs: f32,
+}
+
+impl Default for AutosaveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: AutosaveConfig::DEFAULT_INTERVAL_SECS,
+        }
+    }
+}
+
+impl AutosaveSettings {
+    pub fn set_interval_secs(&mut self, secs: f32) {
+        self.interval_secs = secs.clamp(
+            AutosaveConfig::MIN_INTERVAL_SECS,
+            AutosaveConfig::MAX_INTERVAL_SECS,
+        );
+    }
+}
+
+impl PersistedSettings for AutosaveSettings {
+    const PATH: &'static str = AUTOSAVE_SETTINGS_PATH;
+
+    fn sanitize(&mut self) {
+        self.set_interval_secs(self.interval_secs);
+    }
+}
+
+/// Counts down to the next interval autosave; reset whenever any save happens
+#[derive(Resource)]
+pub struct AutosaveTimer(pub Timer);
+
+impl Default for AutosaveTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(
+            AutosaveConfig::DEFAULT_INTERVAL_SECS,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+/// Brief "Saving..." text shown in the corner after a save
+#[derive(Component)]
+pub struct SaveIndicator {
+    pub timer: Timer,
+}
+
+// ===== PLUGIN =====
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AutosaveSettings::load())
+            .init_resource::<AutosaveTimer>()
+            .add_event::<SaveGameEvent>()
+            .add_systems(OnEnter(Screen::GamePlay), reset_autosave_timer)
+            .add_systems(
+                OnExit(Screen::GamePlay),
+                (despawn_save_indicator, remove_resume_save),
+            )
+            .add_observer(save_on_shutdown)
+            .add_systems(
+                Update,
+                save_on_change::<AutosaveSettings>.run_if(resource_changed::<AutosaveSettings>),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_resume_save.run_if(resource_exists::<ResumeSave>),
+                    sync_autosave_interval.run_if(resource_changed::<AutosaveSettings>),
+                    request_autosave.run_if(crate::systems::health::player_alive),
+                    write_save_game,
+                    update_save_indicator,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Puts the player, stats, collected coins and objectives back as saved, once the
+/// player exists. Runs after the level's own setup so it isn't overwritten.
+fn apply_resume_save(
+    mut commands: Commands,
+    resume: Res<ResumeSave>,
+    mut player_query: Query<&mut Transform, With<CharacterController>>,
+    mut player_stats: ResMut<PlayerStats>,
+    mut streaming_manager: ResMut<CoinStreamingManager>,
+    mut objective_manager: ResMut<ObjectiveManager>,
+) {
+    let Ok(mut transform) = player_query.single_mut() else {
+        return; // Not spawned yet
+    };
+    let save = &resume.0;
+    transform.translation = Vec3::from_array(save.player_position);
+    player_stats.currency = save.currency;
+    player_stats.xp = save.xp;
+    for &position_id in &save.collected_coins {
+        streaming_manager.collected_positions.insert(position_id);
+        if let Some(entity) = streaming_manager.spawned_coins.get(&position_id).copied() {
+            streaming_manager.release_coin(&mut commands, entity, position_id);
+        }
+    }
+    objective_manager.objectives = save.objectives.clone();
+    info!("Continued from {}", SAVE_GAME_PATH);
+    commands.remove_resource::<ResumeSave>();
+}
+
+fn remove_resume_save(mut commands: Commands) {
+    commands.remove_resource::<ResumeSave>();
+}
+
+fn reset_autosave_timer(mut timer: ResMut<AutosaveTimer>) {
+    timer.0.reset();
+}
+
+fn sync_autosave_interval(settings: Res<AutosaveSettings>, mut timer: ResMut<AutosaveTimer>) {
+    timer
+        .0
+        .set_duration(std::time::Duration::from_secs_f32(settings.interval_secs));
+}
+
+/// Sends an autosave request on the interval and whenever an objective completes,
+/// but never while the modal is open or a screen transition is pending
+fn request_autosave(
+    time: Res<Time>,
+    settings: Res<AutosaveSettings>,
+    mut timer: ResMut<AutosaveTimer>,
+    objective_manager: Res<ObjectiveManager>,
+    modal_state: Option<Res<ModalState>>,
+    confirm_state: Res<ConfirmDialogState>,
+    next_screen: Res<NextState<Screen>>,
+    mut completed_objectives: Local<Option<usize>>,
+    mut save_events: EventWriter<SaveGameEvent>,
+) {
+    let completed = objective_manager
+        .objectives
+        .iter()
+        .filter(|objective| objective.completed)
+        .count();
+    // The first frame only records the starting count
+    let objective_completed = completed_objectives.is_some_and(|previous| completed > previous);
+    *completed_objectives = Some(completed);
+
+    if !settings.enabled {
+        return;
+    }
+    let paused = modal_state.is_some_and(|modal_state| modal_state.visible) || confirm_state.is_open();
+    let transitioning = matches!(*next_screen, NextState::Pending(_));
+    if paused || transitioning {
+        return;
+    }
+
+    if objective_completed {
+        save_events.write(SaveGameEvent(SaveReason::ObjectiveCompleted));
+    } else if timer.0.tick(time.delta()).just_finished() {
+        save_events.write(SaveGameEvent(SaveReason::Interval));
+    }
+}
+
+/// The one place saves are serialized and written
+fn write_save_game(
+    mut commands: Commands,
+    mut save_events: EventReader<SaveGameEvent>,
+    mut timer: ResMut<AutosaveTimer>,
+    capabilities: Res<PlatformCapabilities>,
+    player_query: Query<&Transform, With<CharacterController>>,
+    player_stats: Res<PlayerStats>,
+    streaming_manager: Res<CoinStreamingManager>,
+    objective_manager: Res<ObjectiveManager>,
+    indicator_query: Query<Entity, With<SaveIndicator>>,
+    font_assets: Res<FontAssets>,
+) {
+    // Several requests in one frame still make a single save
+    let Some(event) = save_events.read().last() else {
+        return;
+    };
+    timer.0.reset();
+
+    if !capabilities.has_storage {
+        debug!("Skipping save ({:?}), no save storage on this platform", event.0);
+        return;
+    }
+
+    let data = collect_save_data(
+        player_query.single().ok(),
+        &player_stats,
+        &streaming_manager,
+        &objective_manager,
+    );
+    if !write_save_data(&data, event.0) {
+        return;
+    }
+
+    // Restart the existing indicator rather than stacking another one
+    for entity in &indicator_query {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(32.0),
+            bottom: Val::Px(32.0),
+            ..default()
+        },
+        Text::new("Saving..."),
+        TextFont {
+            font_size: 24.0,
+            font: font_assets.rajdhani_bold.clone(),
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Pickable::IGNORE,
+        Name::new("Save Indicator"),
+        SaveIndicator {
+            timer: Timer::from_seconds(AutosaveConfig::INDICATOR_SECS, TimerMode::Once),
+        },
+    ));
+}
+
+/// Fades the indicator out over its lifetime, then removes it
+fn update_save_indicator(
+    mut commands: Commands,
+    mut indicator_query: Query<(Entity, &mut SaveIndicator, &mut TextColor)>,
+    time: Res<Time>,
+) {
+    for (entity, mut indicator, mut color) in indicator_query.iter_mut() {
+        if indicator.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        } else {
+            color.0.set_alpha(1.0 - indicator.timer.fraction());
+        }
+    }
+}
+
+fn collect_save_data(
+    player_transform: Option<&Transform>,
+    player_stats: &PlayerStats,
+    streaming_manager: &CoinStreamingManager,
+    objective_manager: &ObjectiveManager,
+) -> SaveData {
+    let mut collected_coins: Vec<usize> =
+        streaming_manager.collected_positions.iter().copied().collect();
+    collected_coins.sort_unstable();
+
+    SaveData {
+        player_position: player_transform
+            .map(|transform| transform.translation.to_array())
+            .unwrap_or_default(),
+        currency: player_stats.currency,
+        xp: player_stats.xp,
+        collected_coins,
+        objectives: objective_manager.objectives.clone(),
+    }
+}
+
+/// Serializes and writes `data`; false if either step failed
+fn write_save_data(data: &SaveData, reason: SaveReason) -> bool {
+    match serde_json::to_string_pretty(data) {
+        Ok(contents) => match write_file(SAVE_GAME_PATH, &contents) {
+            Ok(()) => {
+                info!("Saved game to {} ({:?})", SAVE_GAME_PATH, reason);
+                true
+            }
+            Err(e) => {
+                error!("Failed to write {}: {}", SAVE_GAME_PATH, e);
+                false
+            }
+        },
+        Err(e) => {
+            error!("Failed to serialize save game: {}", e);
+            false
+        }
+    }
+}
+
+/// Writes the run straight away when quitting mid-level. Skipped outside gameplay,
+/// after dying (autosaves stop then too) and while a Continue is still being
+/// applied, any of which would overwrite a good save with a worse one.
+fn save_on_shutdown(
+    _: Trigger<ShutdownSave>,
+    screen: Res<State<Screen>>,
+    capabilities: Res<PlatformCapabilities>,
+    resume: Option<Res<ResumeSave>>,
+    player_query: Query<(&Transform, &Health), With<CharacterController>>,
+    player_stats: Res<PlayerStats>,
+    streaming_manager: Res<CoinStreamingManager>,
+    objective_manager: Res<ObjectiveManager>,
+) {
+    if *screen.get() != Screen::GamePlay || !capabilities.has_storage || resume.is_some() {
+        return;
+    }
+    let Ok((transform, health)) = player_query.single() else {
+        return;
+    };
+    if health.current == 0 {
+        return;
+    }
+    let data = collect_save_data(
+        Some(transform),
+        &player_stats,
+        &streaming_manager,
+        &objective_manager,
+    );
+    write_save_data(&data, SaveReason::Shutdown);
+}
+
+fn despawn_save_indicator(mut commands: Commands, indicator_query: Query<Entity, With<SaveIndicator>>) {
+    for entity in &indicator_query {
+        commands.entity(entity).despawn();
+    }
+}
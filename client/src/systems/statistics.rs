@@ -0,0 +1,289 @@
+//! Player statistics for the objectives modal's STATS tab. Every counter is
+//! kept twice: for the current run, reset whenever a level starts or restarts,
+//! and for the player's lifetime, which is saved next to the save game.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::statistics::{STATISTICS_PATH, StatisticsConfig};
+use crate::resources::platform::{PlatformCapabilities, read_file, write_file};
+use crate::resources::shutdown::ShutdownSave;
+use crate::screens::Screen;
+use crate::systems::collectibles::{CollectibleType, PlayerMovementTracker, track_player_movement};
+use crate::systems::dojo::PickupItemEvent;
+use crate::systems::enemy_ai::EnemyDefeatedEvent;
+use crate::systems::health::{PlayerDied, player_alive};
+use crate::systems::level_exit::LevelCompleteEvent;
+use crate::systems::save::SaveGameEvent;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+
+// ===== COMPONENTS & RESOURCES =====
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatTotals {
+    pub coins_collected: u32,
+    pub enemies_defeated: u32,
+    /// World units, roughly meters
+    pub distance_traveled: f32,
+    pub time_played_secs: f32,
+    pub levels_completed: u32,
+    pub deaths: u32,
+}
+
+/// Only the lifetime totals are saved; a run never outlives the session
+#[derive(Resource, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStatistics {
+    #[serde(skip)]
+    pub run: StatTotals,
+    pub lifetime: StatTotals,
+}
+
+impl PlayerStatistics {
+    /// Applies the same change to the run and lifetime totals
+    pub fn record(&mut self, change: impl Fn(&mut StatTotals)) {
+        change(&mut self.run);
+        change(&mut self.lifetime);
+    }
+
+    pub fn reset_run(&mut self) {
+        self.run = StatTotals::default();
+    }
+
+    /// Saved lifetime totals, or zeros when there's no file or no storage
+    fn load() -> Self {
+        read_file(STATISTICS_PATH)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        match serde_json::to_string_pretty(self) {
+            Ok(contents) => {
+                if let Err(e) = write_file(STATISTICS_PATH, &contents) {
+                    error!("Failed to write {}: {}", STATISTICS_PATH, e);
+                }
+            }
+            Err(e) => error!("Failed to serialize statistics: {}", e),
+        }
+    }
+}
+
+/// One row of the STATS tab
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatKind {
+    CoinsCollected,
+    EnemiesDefeated,
+    DistanceTraveled,
+    TimePlayed,
+    LevelsCompleted,
+    Deaths,
+}
+
+impl StatKind {
+    pub const ALL: [StatKind; 6] = [
+        StatKind::CoinsCollected,
+        StatKind::EnemiesDefeated,
+        StatKind::DistanceTraveled,
+        StatKind::TimePlayed,
+        StatKind::LevelsCompleted,
+        StatKind::Deaths,
+    ];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            StatKind::CoinsCollected => "stats.coins_collected",
+            StatKind::EnemiesDefeated => "stats.enemies_defeated",
+            StatKind::DistanceTraveled => "stats.distance_traveled",
+            StatKind::TimePlayed => "stats.time_played",
+            StatKind::LevelsCompleted => "stats.levels_completed",
+            StatKind::Deaths => "stats.deaths",
+        }
+    }
+
+    pub fn format(self, totals: &StatTotals) -> String {
+        match self {
+            StatKind::CoinsCollected => totals.coins_collected.to_string(),
+            StatKind::EnemiesDefeated => totals.enemies_defeated.to_string(),
+            StatKind::DistanceTraveled => format!("{:.0} m", totals.distance_traveled),
+            StatKind::TimePlayed => format_duration(totals.time_played_secs),
+            StatKind::LevelsCompleted => totals.levels_completed.to_string(),
+            StatKind::Deaths => totals.deaths.to_string(),
+        }
+    }
+}
+
+/// `1:02:05` once past the hour, `2:05` below it
+pub fn format_duration(secs: f32) -> String {
+    let total = secs.max(0.0) as u32;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PlayerStatistics::load())
+            .add_systems(OnEnter(Screen::GamePlay), reset_run_statistics)
+            .add_systems(OnExit(Screen::GamePlay), persist_statistics)
+            .add_systems(
+                Update,
+                (
+                    (
+                        track_distance.after(track_player_movement),
+                        track_time_played,
+                    )
+                        .run_if(player_alive),
+                    count_statistic_events,
+                    save_statistics,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay)),
+            )
+            .add_observer(reset_run_statistics_on_restart)
+            .add_observer(persist_statistics_on_shutdown);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn reset_run_statistics(mut statistics: ResMut<PlayerStatistics>) {
+    statistics.reset_run();
+}
+
+fn reset_run_statistics_on_restart(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut statistics: ResMut<PlayerStatistics>,
+) {
+    if trigger.0 == ConfirmAction::RestartLevel {
+        statistics.reset_run();
+    }
+}
+
+/// Adds the step `track_player_movement` just measured
+fn track_distance(tracker: Res<PlayerMovementTracker>, mut statistics: ResMut<PlayerStatistics>) {
+    let step = tracker.last_step;
+    if step > 0.0 && step <= StatisticsConfig::MAX_STEP_DISTANCE {
+        statistics.record(|totals| totals.distance_traveled += step);
+    }
+}
+
+fn track_time_played(time: Res<Time>, mut statistics: ResMut<PlayerStatistics>) {
+    let delta = time.delta_secs();
+    statistics.record(|totals| totals.time_played_secs += delta);
+}
+
+fn count_statistic_events(
+    mut pickup_events: EventReader<PickupItemEvent>,
+    mut defeated_events: EventReader<EnemyDefeatedEvent>,
+    mut complete_events: EventReader<LevelCompleteEvent>,
+    mut died_events: EventReader<PlayerDied>,
+    mut statistics: ResMut<PlayerStatistics>,
+) {
+    let coins = pickup_events
+        .read()
+        .filter(|event| event.item_type == CollectibleType::Coin)
+        .count() as u32;
+    let kills = defeated_events.read().count() as u32;
+    let levels = complete_events.read().count() as u32;
+    let deaths = died_events.read().count() as u32;
+    if coins + kills + levels + deaths == 0 {
+        return;
+    }
+    statistics.record(|totals| {
+        totals.coins_collected += coins;
+        totals.enemies_defeated += kills;
+        totals.levels_completed += levels;
+        totals.deaths += deaths;
+    });
+}
+
+/// Writes the lifetime totals alongside every game save, rather than every frame
+fn save_statistics(
+    mut save_events: EventReader<SaveGameEvent>,
+    statistics: Res<PlayerStatistics>,
+    capabilities: Res<PlatformCapabilities>,
+) {
+    if save_events.read().count() > 0 && capabilities.has_storage {
+        statistics.save();
+    }
+}
+
+fn persist_statistics(statistics: Res<PlayerStatistics>, capabilities: Res<PlatformCapabilities>) {
+    if capabilities.has_storage {
+        statistics.save();
+    }
+}
+
+/// Quitting skips `OnExit`, so the totals since the last write are flushed here
+fn persist_statistics_on_shutdown(
+    _: Trigger<ShutdownSave>,
+    statistics: Res<PlayerStatistics>,
+    capabilities: Res<PlatformCapabilities>,
+) {
+    if capabilities.has_storage {
+        statistics.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_totals() -> StatTotals {
+        StatTotals {
+            coins_collected: 42,
+            enemies_defeated: 3,
+            distance_traveled: 120.5,
+            time_played_secs: 95.25,
+            levels_completed: 1,
+            deaths: 2,
+        }
+    }
+
+    #[test]
+    fn lifetime_totals_survive_a_serde_round_trip() {
+        let statistics = PlayerStatistics {
+            run: sample_totals(),
+            lifetime: sample_totals(),
+        };
+        let json = serde_json::to_string(&statistics).unwrap();
+        let restored: PlayerStatistics = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.lifetime, sample_totals());
+        // The run is never saved, so a loaded session starts fresh
+        assert_eq!(restored.run, StatTotals::default());
+    }
+
+    #[test]
+    fn missing_fields_load_as_zero() {
+        let restored: PlayerStatistics = serde_json::from_str(r#"{"lifetime":{"deaths":4}}"#).unwrap();
+        assert_eq!(restored.lifetime.deaths, 4);
+        assert_eq!(restored.lifetime.coins_collected, 0);
+    }
+
+    #[test]
+    fn recording_updates_run_and_lifetime_but_reset_only_clears_the_run() {
+        let mut statistics = PlayerStatistics::default();
+        statistics.record(|totals| totals.deaths += 1);
+        statistics.reset_run();
+        statistics.record(|totals| totals.deaths += 1);
+        assert_eq!(statistics.run.deaths, 1);
+        assert_eq!(statistics.lifetime.deaths, 2);
+    }
+
+    #[test]
+    fn durations_show_hours_only_when_needed() {
+        assert_eq!(format_duration(125.0), "2:05");
+        assert_eq!(format_duration(3725.0), "1:02:05");
+        assert_eq!(format_duration(-3.0), "0:00");
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use bevy_gltf_animation::prelude::GltfSceneRoot;
+use serde::{Deserialize, Serialize};
+
+use crate::assets::ModelAssets;
+use crate::resources::difficulty::{ActiveDifficulty, scale_u32};
+use crate::resources::platform::{PlatformCapabilities, read_file};
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::WorldSeed;
+use crate::systems::enemy_ai::{EnemyBundle, EnemyDefeatedEvent};
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// One timed wave of enemies, as listed in `waves.json`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WaveData {
+    pub enemy_count: u32,
+    /// Seconds before the wave starts, counted from the end of the previous one
+    pub start_delay: f32,
+    /// Seconds between individual spawns within the wave
+    pub spawn_interval: f32,
+    /// Whether the next wave waits for this one to be cleared
+    pub wait_for_clear: bool,
+    /// Cycled through as enemies spawn
+    pub spawn_points: Vec<[f32; 3]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WaveList {
+    pub waves: Vec<WaveData>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WavePhase {
+    #[default]
+    Waiting,
+    Spawning,
+    AwaitingClear,
+    Finished,
+}
+
+#[derive(Resource, Default)]
+pub struct WaveManager {
+    pub waves: Vec<WaveData>,
+    pub current_wave: usize,
+    pub phase: WavePhase,
+    pub timer: Timer,
+    pub spawned_in_wave: u32,
+    pub remaining_in_wave: u32,
+}
+
+impl WaveManager {
+    /// Starts over from the first wave, keeping the wave definitions
+    pub fn reset(&mut self, waves: Vec<WaveData>) {
+        let first_delay = waves.first().map_or(0.0, |wave| wave.start_delay);
+        *self = Self {
+            phase: if waves.is_empty() { WavePhase::Finished } else { WavePhase::Waiting },
+            waves,
+            timer: Timer::from_seconds(first_delay, TimerMode::Once),
+            ..default()
+        };
+    }
+
+    fn advance_to_next_wave(&mut self) {
+        self.current_wave += 1;
+        self.spawned_in_wave = 0;
+        self.remaining_in_wave = 0;
+        match self.waves.get(self.current_wave) {
+            Some(wave) => {
+                self.timer = Timer::from_seconds(wave.start_delay, TimerMode::Once);
+                self.phase = WavePhase::Waiting;
+            }
+            None => {
+                info!("All enemy waves cleared");
+                self.phase = WavePhase::Finished;
+            }
+        }
+    }
+}
+
+/// Tags enemies spawned by the wave manager with the wave they belong to
+#[derive(Component)]
+pub struct WaveEnemy {
+    pub wave: usize,
+}
+
+// ===== PLUGIN =====
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveManager>()
+            // Reset on every entry, mirroring how pregame loading resets coin streaming
+            .add_systems(OnEnter(Screen::GamePlay), reset_waves)
+            .add_systems(
+                Update,
+                (track_wave_defeats, update_waves)
+                    .chain()
+                    .run_if(in_state(Screen::GamePlay).and(crate::systems::health::player_alive)),
+            );
+    }
+}
+
+// ===== SYSTEMS =====
+
+pub fn reset_waves(
+    mut wave_manager: ResMut<WaveManager>,
+    capabilities: Res<PlatformCapabilities>,
+    difficulty: Res<ActiveDifficulty>,
+) {
+    let mut waves = if capabilities.has_filesystem {
+        load_wave_list().waves
+    } else {
+        Vec::new()
+    };
+    let multiplier = difficulty.modifiers().wave_enemy_count;
+    for wave in &mut waves {
+        wave.enemy_count = scale_u32(wave.enemy_count, multiplier);
+    }
+    wave_manager.reset(waves);
+}
+
+fn load_wave_list() -> WaveList {
+    use crate::constants::navigation::WAVES_DATA_PATH;
+
+    // Waves are optional; most levels don't have any
+    let Ok(contents) = read_file(WAVES_DATA_PATH) else {
+        return WaveList::default();
+    };
+    match serde_json::from_str::<WaveList>(&contents) {
+        Ok(wave_list) => wave_list,
+        Err(e) => {
+            error!("Failed to parse {}: {}", WAVES_DATA_PATH, e);
+            WaveList::default()
+        }
+    }
+}
+
+fn track_wave_defeats(
+    mut defeated_events: EventReader<EnemyDefeatedEvent>,
+    wave_enemies: Query<&WaveEnemy>,
+    mut wave_manager: ResMut<WaveManager>,
+) {
+    for EnemyDefeatedEvent(entity) in defeated_events.read() {
+        let Ok(wave_enemy) = wave_enemies.get(*entity) else {
+            continue;
+        };
+        if wave_enemy.wave == wave_manager.current_wave {
+            wave_manager.remaining_in_wave = wave_manager.remaining_in_wave.saturating_sub(1);
+        }
+    }
+}
+
+fn update_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<ModelAssets>,
+    difficulty: Res<ActiveDifficulty>,
+    world_seed: Res<WorldSeed>,
+    mut wave_manager: ResMut<WaveManager>,
+) {
+    let Some(wave) = wave_manager.waves.get(wave_manager.current_wave).cloned() else {
+        return;
+    };
+
+    match wave_manager.phase {
+        WavePhase::Waiting => {
+            if wave_manager.timer.tick(time.delta()).finished() {
+                info!("Starting enemy wave {}", wave_manager.current_wave + 1);
+                wave_manager.phase = WavePhase::Spawning;
+                // Fire the first spawn straight away
+                wave_manager.timer = Timer::from_seconds(0.0, TimerMode::Once);
+            }
+        }
+        WavePhase::Spawning => {
+            if !wave_manager.timer.tick(time.delta()).finished() {
+                return;
+            }
+
+            if !wave.spawn_points.is_empty() {
+                let point = wave.spawn_points[wave_manager.spawned_in_wave as usize % wave.spawn_points.len()];
+                // Wave and slot identify the enemy the same way on every run with this seed
+                let spawn_index = ((wave_manager.current_wave as u64) << 32) | wave_manager.spawned_in_wave as u64;
+                let enemy = EnemyBundle::default()
+                    .with_difficulty(&difficulty.modifiers())
+                    .with_seed(&world_seed, spawn_index);
+                spawn_wave_enemy(&mut commands, &assets, enemy, Vec3::from_array(point), wave_manager.current_wave);
+                wave_manager.remaining_in_wave += 1;
+            } else {
+                warn!("Wave {} has no spawn points", wave_manager.current_wave + 1);
+            }
+            wave_manager.spawned_in_wave += 1;
+            wave_manager.timer = Timer::from_seconds(wave.spawn_interval, TimerMode::Once);
+
+            if wave_manager.spawned_in_wave >= wave.enemy_count {
+                if wave.wait_for_clear {
+                    wave_manager.phase = WavePhase::AwaitingClear;
+                } else {
+                    wave_manager.advance_to_next_wave();
+                }
+            }
+        }
+        WavePhase::AwaitingClear => {
+            if wave_manager.remaining_in_wave == 0 {
+                wave_manager.advance_to_next_wave();
+            }
+        }
+        WavePhase::Finished => {}
+    }
+}
+
+fn spawn_wave_enemy(
+    commands: &mut Commands,
+    assets: &ModelAssets,
+    enemy: EnemyBundle,
+    position: Vec3,
+    wave: usize,
+) {
+    commands.spawn((
+        Name::new(format!("Wave {} Enemy", wave + 1)),
+        GltfSceneRoot::new(assets.enemy.clone()),
+        Transform {
+            translation: position,
+            scale: Vec3::splat(4.0),
+            ..default()
+        },
+        enemy,
+        WaveEnemy { wave },
+        PlayingScene,
+    ));
+}
@@ -0,0 +1,56 @@
+//! Headless apps for driving systems in tests. `MinimalPlugins` brings time and
+//! the schedules but no window, renderer or asset loading, so a test inserts the
+//! resources, events and entities the systems under test read, then calls
+//! `app.update()`. Systems that depend on real contacts (sensors, ground casts)
+//! use [`headless_physics_app`] instead, which also runs the avian simulation.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+/// An app with `MinimalPlugins` only; add the systems under test to it
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app
+}
+
+/// `headless_app` plus avian physics, advancing a fixed 100ms per update so
+/// several physics steps run every frame. Colliders built from meshes or scenes
+/// need the asset and scene plugins even though nothing is rendered.
+pub fn headless_physics_app() -> App {
+    let mut app = headless_app();
+    app.add_plugins((
+        TransformPlugin,
+        AssetPlugin::default(),
+        bevy::scene::ScenePlugin,
+        PhysicsPlugins::default(),
+    ))
+    .init_asset::<Mesh>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+    app
+}
+
+/// Events of type `E` written since the last two updates
+pub fn event_count<E: Event>(app: &App) -> usize {
+    app.world().resource::<Events<E>>().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn physics_app_steps_the_simulation() {
+        let mut app = headless_physics_app();
+        let body = app
+            .world_mut()
+            .spawn((RigidBody::Dynamic, Collider::sphere(0.5), Transform::from_xyz(0.0, 10.0, 0.0)))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().get::<Transform>(body).unwrap().translation.y < 10.0);
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+
+use crate::assets::{FontAssets, UiAssets};
+use crate::constants::hud::CoinCounterConfig;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::{CollectibleProgressTracker, CollectibleType};
+use crate::systems::dojo::PickupItemEvent;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+/// Coin icon the flying coins head for
+#[derive(Component)]
+struct CoinCounterIcon;
+
+#[derive(Component)]
+struct CoinCounterText;
+
+/// Pooled coin sprite; while `flight` is set it's travelling from a pickup to the counter
+#[derive(Component, Default)]
+struct FlyingCoin {
+    flight: Option<CoinFlight>,
+}
+
+struct CoinFlight {
+    /// Screen position of the pickup, in UI pixels
+    start: Vec2,
+    timer: Timer,
+}
+
+// ===== PLUGIN =====
+
+pub struct CoinCounterPlugin;
+
+impl Plugin for CoinCounterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                launch_flying_coins,
+                animate_flying_coins,
+                update_coin_counter,
+            )
+                .chain()
+                .run_if(in_state(Screen::GamePlay)),
+        );
+    }
+}
+
+// ===== SPAWNING =====
+
+/// Spawns the coin total centered under the compass, plus the pool of flying coins
+pub fn spawn_coin_counter(
+    commands: &mut Commands,
+    font_assets: &Res<FontAssets>,
+    ui_assets: &Res<UiAssets>,
+    hud_marker: impl Component + Clone,
+) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(CoinCounterConfig::TOP),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        Name::new("Coin Counter"),
+        hud_marker.clone(),
+        children![
+            (
+                Node {
+                    width: Val::Px(CoinCounterConfig::ICON_SIZE),
+                    height: Val::Px(CoinCounterConfig::ICON_SIZE),
+                    ..default()
+                },
+                ImageNode::new(ui_assets.coin.clone()),
+                CoinCounterIcon,
+            ),
+            (
+                Text::new("0"),
+                TextFont {
+                    font: font_assets.rajdhani_bold.clone(),
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Color::ELYSIUM_GOLD),
+                CoinCounterText,
+            ),
+        ],
+    ));
+
+    for _ in 0..CoinCounterConfig::POOL_SIZE {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(CoinCounterConfig::FLYING_COIN_SIZE),
+                height: Val::Px(CoinCounterConfig::FLYING_COIN_SIZE),
+                display: Display::None,
+                ..default()
+            },
+            ImageNode::new(ui_assets.coin.clone()),
+            GlobalZIndex(50), // Above the HUD panels
+            Pickable::IGNORE,
+            FlyingCoin::default(),
+            hud_marker.clone(),
+        ));
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Sends a pooled coin from each coin pickup's screen position; pickups off screen or
+/// with the pool used up just count straight away
+fn launch_flying_coins(
+    mut pickup_events: EventReader<PickupItemEvent>,
+    camera_query: Query<(&Camera, &GlobalTransform), (With<Camera3d>, With<PlayingScene>)>,
+    ui_scale: Res<UiScale>,
+    mut coin_query: Query<(&mut FlyingCoin, &mut Node)>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        pickup_events.clear();
+        return;
+    };
+
+    for event in pickup_events.read() {
+        if event.item_type != CollectibleType::Coin {
+            continue;
+        }
+        let Ok(viewport_position) = camera.world_to_viewport(camera_transform, event.position)
+        else {
+            continue;
+        };
+        let Some((mut coin, mut node)) = coin_query
+            .iter_mut()
+            .find(|(coin, _)| coin.flight.is_none())
+        else {
+            continue;
+        };
+        coin.flight = Some(CoinFlight {
+            start: viewport_position / ui_scale.0,
+            timer: Timer::from_seconds(CoinCounterConfig::FLIGHT_SECS, TimerMode::Once),
+        });
+        node.display = Display::Flex;
+    }
+}
+
+/// Eases each flying coin toward the counter icon and frees it on arrival
+fn animate_flying_coins(
+    time: Res<Time>,
+    icon_query: Query<(&GlobalTransform, &ComputedNode), With<CoinCounterIcon>>,
+    mut coin_query: Query<(&mut FlyingCoin, &mut Node)>,
+) {
+    let Ok((icon_transform, icon_node)) = icon_query.single() else {
+        return;
+    };
+    // UI transforms are in physical pixels; node offsets are in UI pixels
+    let target = icon_transform.translation().truncate() * icon_node.inverse_scale_factor();
+
+    for (mut coin, mut node) in &mut coin_query {
+        let Some(flight) = coin.flight.as_mut() else {
+            continue;
+        };
+        if flight.timer.tick(time.delta()).finished() {
+            coin.flight = None;
+            node.display = Display::None;
+            continue;
+        }
+        let t = flight.timer.fraction();
+        // Ease in, so the coin pops off the pickup and then speeds into the counter
+        let position = flight.start.lerp(target, t * t);
+        let half_size = CoinCounterConfig::FLYING_COIN_SIZE / 2.0;
+        node.left = Val::Px(position.x - half_size);
+        node.top = Val::Px(position.y - half_size);
+    }
+}
+
+/// Shows the coin total minus the coins still in flight, so each one counts when it lands
+fn update_coin_counter(
+    progress: Res<CollectibleProgressTracker>,
+    coin_query: Query<&FlyingCoin>,
+    mut text_query: Query<&mut Text, With<CoinCounterText>>,
+) {
+    let in_flight = coin_query
+        .iter()
+        .filter(|coin| coin.flight.is_some())
+        .count() as u32;
+    let displayed = progress.coins.saturating_sub(in_flight).to_string();
+    for mut text in &mut text_query {
+        if text.0 != displayed {
+            text.0 = displayed.clone();
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::constants::hud::CompassConfig;
+use crate::resources::hud::HudSettings;
+use crate::screens::Screen;
+use crate::systems::character_controller::CharacterController;
+use crate::systems::enemy_ai::Enemy;
+use crate::systems::objectives::ObjectiveManager;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+#[derive(Component)]
+struct Compass;
+
+/// Cardinal letter pinned to a world bearing, in degrees clockwise from north (-Z)
+#[derive(Component)]
+struct CompassLabel {
+    bearing: f32,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum CompassMarker {
+    /// Nth incomplete location objective
+    Objective(usize),
+    NearestEnemy,
+}
+
+// ===== PLUGIN =====
+
+pub struct CompassPlugin;
+
+impl Plugin for CompassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (apply_compass_visibility, update_compass).run_if(in_state(Screen::GamePlay)),
+        );
+    }
+}
+
+// ===== SPAWNING =====
+
+/// Spawns the compass strip centered at the top of the screen
+pub fn spawn_compass(
+    commands: &mut Commands,
+    font_assets: &Res<FontAssets>,
+    hud_marker: impl Component + Clone,
+) {
+    let font = font_assets.rajdhani_bold.clone();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(CompassConfig::TOP),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-CompassConfig::WIDTH / 2.0)),
+                width: Val::Px(CompassConfig::WIDTH),
+                height: Val::Px(CompassConfig::HEIGHT),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(Color::DARK_GLASS),
+            BorderRadius::all(Val::Px(8.0)),
+            Pickable::IGNORE,
+            Name::new("Compass"),
+            Compass,
+            hud_marker,
+        ))
+        .with_children(|compass| {
+            // Center notch marking where the player is facing
+            compass.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(CompassConfig::WIDTH / 2.0 - 1.0),
+                    width: Val::Px(2.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::WHITE.with_alpha(0.6)),
+            ));
+
+            for (letter, bearing) in [("N", 0.0), ("E", 90.0), ("S", 180.0), ("W", 270.0)] {
+                compass.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(CompassConfig::LABEL_WIDTH),
+                        height: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    Visibility::Hidden,
+                    CompassLabel { bearing },
+                    children![(
+                        Text::new(letter),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(if letter == "N" {
+                            Color::ELYSIUM_GOLD
+                        } else {
+                            Color::WHITE
+                        }),
+                    )],
+                ));
+            }
+
+            let markers = (0..CompassConfig::MAX_OBJECTIVE_MARKERS)
+                .map(|index| (CompassMarker::Objective(index), Color::ELYSIUM_GOLD))
+                .chain([(CompassMarker::NearestEnemy, Color::DANGER_RED)]);
+            for (marker, color) in markers {
+                compass.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(3.0),
+                        width: Val::Px(CompassConfig::MARKER_SIZE),
+                        height: Val::Px(CompassConfig::MARKER_SIZE),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    BorderRadius::MAX,
+                    Visibility::Hidden,
+                    marker,
+                ));
+            }
+        });
+}
+
+// ===== SYSTEMS =====
+
+fn apply_compass_visibility(
+    settings: Res<HudSettings>,
+    mut compass_query: Query<(&mut Visibility, Ref<Compass>)>,
+) {
+    for (mut visibility, compass) in compass_query.iter_mut() {
+        if settings.is_changed() || compass.is_added() {
+            *visibility = if settings.compass_visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+/// Bearing of a horizontal direction in degrees clockwise from north, where north is -Z
+fn bearing_of(direction: Vec3) -> f32 {
+    direction.x.atan2(-direction.z).to_degrees().rem_euclid(360.0)
+}
+
+/// Horizontal offset from the strip's left edge for `bearing`, or `None` when it's out of view
+fn strip_offset(bearing: f32, heading: f32) -> Option<f32> {
+    let relative = (bearing - heading + 180.0).rem_euclid(360.0) - 180.0;
+    let half_visible = CompassConfig::VISIBLE_DEGREES / 2.0;
+    if relative.abs() > half_visible {
+        return None;
+    }
+    Some(CompassConfig::WIDTH / 2.0 * (1.0 + relative / half_visible))
+}
+
+/// Scrolls the cardinal letters with the player's yaw and places the objective and enemy markers
+fn update_compass(
+    settings: Res<HudSettings>,
+    objective_manager: Res<ObjectiveManager>,
+    player_query: Query<&Transform, (With<CharacterController>, Without<Enemy>)>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    mut label_query: Query<(&CompassLabel, &mut Node, &mut Visibility), Without<CompassMarker>>,
+    mut marker_query: Query<(&CompassMarker, &mut Node, &mut Visibility), Without<CompassLabel>>,
+) {
+    if !settings.compass_visible {
+        return;
+    }
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+    let heading = bearing_of(*player_transform.forward());
+
+    for (label, mut node, mut visibility) in label_query.iter_mut() {
+        place(
+            strip_offset(label.bearing, heading),
+            CompassConfig::LABEL_WIDTH,
+            &mut node,
+            &mut visibility,
+        );
+    }
+
+    let objective_targets: Vec<Vec3> = objective_manager
+        .objectives
+        .iter()
+        .filter(|objective| objective_manager.is_trackable(objective))
+        .filter_map(|objective| objective.target_position.map(Vec3::from_array))
+        .take(CompassConfig::MAX_OBJECTIVE_MARKERS)
+        .collect();
+    let nearest_enemy = enemy_query
+        .iter()
+        .map(|transform| transform.translation)
+        .min_by(|a, b| {
+            a.distance_squared(player_pos)
+                .total_cmp(&b.distance_squared(player_pos))
+        });
+
+    for (marker, mut node, mut visibility) in marker_query.iter_mut() {
+        let target = match *marker {
+            CompassMarker::Objective(index) => objective_targets.get(index).copied(),
+            CompassMarker::NearestEnemy => nearest_enemy,
+        };
+        let offset = target
+            .map(|target| target - player_pos)
+            .filter(|direction| direction.xz() != Vec2::ZERO)
+            .and_then(|direction| strip_offset(bearing_of(direction), heading));
+        place(offset, CompassConfig::MARKER_SIZE, &mut node, &mut visibility);
+    }
+}
+
+/// Centers a strip element on `offset`, hiding it when there's nothing to show
+fn place(offset: Option<f32>, width: f32, node: &mut Node, visibility: &mut Visibility) {
+    let Some(offset) = offset else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    let left = Val::Px(offset - width / 2.0);
+    if node.left != left {
+        node.left = left;
+    }
+    visibility.set_if_neq(Visibility::Inherited);
+}
@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::systems::dojo::DojoSystemState;
+use crate::systems::dojo::chain_state::ChainState;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Whether the corner badge is shown; toggled with F8
+#[derive(Resource)]
+pub struct ConnectionStatusSettings {
+    pub visible: bool,
+}
+
+impl Default for ConnectionStatusSettings {
+    fn default() -> Self {
+        Self { visible: true }
+    }
+}
+
+/// Same three states `log_dojo_status` reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    Connected,
+    Partial,
+    Disconnected,
+}
+
+impl ConnectionStatus {
+    pub fn from_state(dojo_state: &DojoSystemState, chain_state: &ChainState) -> Self {
+        match (dojo_state.torii_connected, dojo_state.account_connected) {
+            // Reads failing means the account connection isn't actually usable
+            (true, true) if chain_state.consecutive_errors == 0 => ConnectionStatus::Connected,
+            (false, false) => ConnectionStatus::Disconnected,
+            _ => ConnectionStatus::Partial,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ConnectionStatus::Connected => "Chain: Connected",
+            ConnectionStatus::Partial => "Chain: Partial",
+            ConnectionStatus::Disconnected => "Chain: Offline",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            ConnectionStatus::Connected => Color::SUCCESS_GREEN,
+            ConnectionStatus::Partial => Color::WARNING_ORANGE,
+            ConnectionStatus::Disconnected => Color::DANGER_RED,
+        }
+    }
+}
+
+#[derive(Component)]
+struct ConnectionStatusBadge;
+
+#[derive(Component)]
+struct ConnectionStatusDot;
+
+#[derive(Component)]
+struct ConnectionStatusText;
+
+// ===== PLUGIN =====
+
+pub struct ConnectionStatusPlugin;
+
+impl Plugin for ConnectionStatusPlugin {
+    fn build(&self, app: &mut App) {
+        // Added outside `dojo::plugin`, so with Dojo disabled the default
+        // (disconnected) state is what the badge shows
+        app.init_resource::<ConnectionStatusSettings>()
+            .init_resource::<DojoSystemState>()
+            .init_resource::<ChainState>()
+            .add_systems(
+            Update,
+            (
+                spawn_connection_status_badge,
+                update_connection_status_badge,
+            )
+                .chain(),
+        );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Spawns the badge once fonts are loaded; it stays up across every screen
+fn spawn_connection_status_badge(
+    mut commands: Commands,
+    font_assets: Option<Res<FontAssets>>,
+    badge_query: Query<(), With<ConnectionStatusBadge>>,
+) {
+    let Some(font_assets) = font_assets else {
+        return;
+    };
+    if !badge_query.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(8.0),
+            bottom: Val::Px(8.0),
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+            column_gap: Val::Px(8.0),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::DARK_GLASS),
+        BorderRadius::all(Val::Px(6.0)),
+        GlobalZIndex(150),
+        Pickable::IGNORE,
+        Name::new("Connection Status Badge"),
+        ConnectionStatusBadge,
+        children![
+            (
+                Node {
+                    width: Val::Px(10.0),
+                    height: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(ConnectionStatus::Disconnected.color()),
+                BorderRadius::MAX,
+                ConnectionStatusDot,
+            ),
+            (
+                Text::new(ConnectionStatus::Disconnected.label()),
+                TextFont {
+                    font: font_assets.rajdhani_medium.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE.with_alpha(0.8)),
+                ConnectionStatusText,
+            ),
+        ],
+    ));
+}
+
+fn update_connection_status_badge(
+    dojo_state: Res<DojoSystemState>,
+    chain_state: Res<ChainState>,
+    settings: Res<ConnectionStatusSettings>,
+    added_badge: Query<(), Added<ConnectionStatusBadge>>,
+    mut badge_query: Query<&mut Visibility, With<ConnectionStatusBadge>>,
+    mut dot_query: Query<&mut BackgroundColor, With<ConnectionStatusDot>>,
+    mut text_query: Query<&mut Text, With<ConnectionStatusText>>,
+) {
+    let changed = dojo_state.is_changed() || chain_state.is_changed() || settings.is_changed();
+    if !changed && added_badge.is_empty() {
+        return;
+    }
+
+    let visibility = if settings.visible {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut badge_visibility in &mut badge_query {
+        badge_visibility.set_if_neq(visibility);
+    }
+
+    let status = ConnectionStatus::from_state(&dojo_state, &chain_state);
+    for mut color in &mut dot_query {
+        color.0 = status.color();
+    }
+    for mut text in &mut text_query {
+        text.0 = status.label().to_string();
+    }
+}
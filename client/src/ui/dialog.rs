@@ -7,12 +7,24 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct Dialog;
 
-#[derive(Component)]
-pub struct DialogProximity {
-    pub target_position: Vec3,
-    pub proximity_threshold: f32,
+/// Something the player can interact with once they're within `range` of it
+#[derive(Component, Clone)]
+pub struct Interactable {
+    /// Prompt shown while in range, e.g. "Press E to Open"
+    pub action: String,
+    pub range: f32,
+}
+
+/// The shared dialog that shows the action of the nearest interactable in range
+#[derive(Component, Default)]
+pub struct InteractionPrompt {
+    /// Current opacity, eased toward 1 while an interactable is in range and 0 otherwise
+    pub fade: f32,
 }
 
+/// How quickly the interaction prompt fades in and out, in opacity per second
+const PROMPT_FADE_SPEED: f32 = 4.0;
+
 #[derive(Resource, Clone)]
 pub struct DialogConfig {
     pub text: String,
@@ -52,18 +64,7 @@ pub fn spawn_dialog(
     windows: Query<&Window>,
     config: DialogConfig,
     scene_marker: impl Component + Clone,
-) {
-    spawn_dialog_with_proximity(commands, font_assets, windows, config, scene_marker, None);
-}
-
-pub fn spawn_dialog_with_proximity(
-    commands: &mut Commands,
-    font_assets: &Res<FontAssets>,
-    windows: Query<&Window>,
-    config: DialogConfig,
-    scene_marker: impl Component + Clone,
-    proximity: Option<DialogProximity>,
-) {
+) -> Entity {
     let window = windows.single().expect("No primary window");
     let window_height = window.height();
     let _window_width = window.width();
@@ -127,11 +128,6 @@ pub fn spawn_dialog_with_proximity(
         Visibility::Hidden, // Start hidden
     ));
 
-    // Add proximity component if provided
-    if let Some(proximity) = proximity {
-        entity_commands.insert(proximity);
-    }
-
     entity_commands.with_children(|parent| {
         parent.spawn(label_widget(
             responsive_font_size,
@@ -139,14 +135,42 @@ pub fn spawn_dialog_with_proximity(
             config.text.clone(),
         ));
     });
+
+    entity_commands.id()
+}
+
+/// Spawns the bottom-center prompt that follows whichever interactable is nearby
+pub fn spawn_interaction_prompt(
+    commands: &mut Commands,
+    font_assets: &Res<FontAssets>,
+    windows: Query<&Window>,
+    scene_marker: impl Component + Clone,
+) -> Entity {
+    let prompt = spawn_dialog(
+        commands,
+        font_assets,
+        windows,
+        DialogConfig {
+            text: "Press E to Interact".to_string(),
+            position: DialogPosition::BottomCenter { bottom_margin: 4.0 },
+            ..Default::default()
+        },
+        scene_marker,
+    );
+    commands.entity(prompt).insert(InteractionPrompt::default());
+    prompt
 }
 
-pub fn animate_dialog(time: Res<Time>, mut query: Query<&mut BackgroundColor, With<Dialog>>) {
+pub fn animate_dialog(
+    time: Res<Time>,
+    mut query: Query<(&mut BackgroundColor, Option<&InteractionPrompt>), With<Dialog>>,
+) {
     let t = (time.elapsed_secs().sin() * 0.5 + 0.5) * 0.5 + 0.5;
-    for mut bg in &mut query {
+    for (mut bg, prompt) in &mut query {
         let base_alpha = 0.4;
         let pulse_alpha = 0.3;
-        let new_alpha = base_alpha + pulse_alpha * t;
+        let fade = prompt.map_or(1.0, |prompt| prompt.fade);
+        let new_alpha = (base_alpha + pulse_alpha * t) * fade;
 
         // Create a new color with the same RGB values but animated alpha
         let new_color = Color::srgba(0.1, 0.1, 0.2, new_alpha);
@@ -154,29 +178,56 @@ pub fn animate_dialog(time: Res<Time>, mut query: Query<&mut BackgroundColor, Wi
     }
 }
 
-pub fn check_dialog_proximity(
+/// Shows the prompt for the nearest interactable in range, fading it out when there is none
+pub fn update_interaction_prompt(
     player_query: Query<
         &Transform,
         With<crate::systems::character_controller::CharacterController>,
     >,
-    mut dialog_query: Query<(&mut Visibility, Option<&DialogProximity>), With<Dialog>>,
+    interactable_query: Query<(&GlobalTransform, &Interactable)>,
+    mut prompt_query: Query<(Entity, &mut InteractionPrompt, &mut Visibility), With<Dialog>>,
+    children_query: Query<&Children>,
+    mut text_query: Query<(&mut Text, &mut TextColor)>,
+    time: Res<Time>,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
     };
+    let player_pos = player_transform.translation;
+
+    let nearest = interactable_query
+        .iter()
+        .map(|(transform, interactable)| (transform.translation().distance(player_pos), interactable))
+        .filter(|(distance, interactable)| *distance <= interactable.range)
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, interactable)| interactable);
+
+    for (entity, mut prompt, mut visibility) in prompt_query.iter_mut() {
+        let target = if nearest.is_some() { 1.0 } else { 0.0 };
+        let step = PROMPT_FADE_SPEED * time.delta_secs();
+        prompt.fade = if prompt.fade < target {
+            (prompt.fade + step).min(target)
+        } else {
+            (prompt.fade - step).max(target)
+        };
 
-    for (mut visibility, proximity) in dialog_query.iter_mut() {
-        if let Some(proximity) = proximity {
-            // Check distance to target position
-            let distance = player_transform.translation.distance(proximity.target_position);
-            if distance <= proximity.proximity_threshold {
-                *visibility = Visibility::Visible;
-            } else {
-                *visibility = Visibility::Hidden;
+        let shown = prompt.fade > 0.0;
+        let new_visibility = if shown { Visibility::Visible } else { Visibility::Hidden };
+        if *visibility != new_visibility {
+            *visibility = new_visibility;
+        }
+
+        // The text lives somewhere under the label widget; find it without relying on layout
+        for descendant in children_query.iter_descendants(entity) {
+            if let Ok((mut text, mut color)) = text_query.get_mut(descendant) {
+                // Keep the last action while fading out so the text doesn't blank early
+                if let Some(interactable) = nearest {
+                    if text.0 != interactable.action {
+                        text.0 = interactable.action.clone();
+                    }
+                }
+                color.0 = color.0.with_alpha(prompt.fade);
             }
-        } else {
-            // For dialogs without proximity, keep them hidden
-            *visibility = Visibility::Hidden;
         }
     }
 }
@@ -189,6 +240,6 @@ pub struct DialogPlugin;
 
 impl Plugin for DialogPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (animate_dialog, check_dialog_proximity));
+        app.add_systems(Update, (animate_dialog, update_interaction_prompt));
     }
 }
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::collectibles::CollectibleProgressTracker;
+use crate::systems::health::GameOverState;
+use crate::systems::level_data::LevelData;
+use crate::ui::modal::{ConfirmAction, ConfirmDialogAccepted};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS =====
+
+#[derive(Component)]
+struct GameOverScreen;
+
+/// Each button answers with the same action the confirm dialogs use,
+/// so Retry runs the regular restart and Main Menu the regular exit
+#[derive(Component, Clone, Copy)]
+struct GameOverButton(ConfirmAction);
+
+// ===== PLUGIN =====
+
+pub struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            spawn_game_over_screen
+                .run_if(in_state(Screen::GamePlay).and(resource_changed::<GameOverState>)),
+        )
+        .add_observer(despawn_game_over_screen);
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn spawn_game_over_screen(
+    mut commands: Commands,
+    state: Res<GameOverState>,
+    font_assets: Res<FontAssets>,
+    progress: Res<CollectibleProgressTracker>,
+    level: Res<LevelData>,
+    existing: Query<(), With<GameOverScreen>>,
+) {
+    if !matches!(*state, GameOverState::Shown) || !existing.is_empty() {
+        return;
+    }
+
+    let font = font_assets.rajdhani_bold.clone();
+    let summary = format!(
+        "Level reached: {}\nCoins collected: {}",
+        level.number, progress.coins
+    );
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            GlobalZIndex(200), // Above the HUD and the objectives modal
+            Name::new("Game Over Screen"),
+            GameOverScreen,
+            PlayingScene,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(720.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(32.0),
+                        padding: UiRect::all(Val::Px(48.0)),
+                        border: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::DARK_GLASS),
+                    BorderColor(Color::DANGER_RED.with_alpha(0.8)),
+                    BorderRadius::all(Val::Px(24.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("YOU DIED"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 64.0,
+                            ..default()
+                        },
+                        TextColor(Color::DANGER_RED),
+                    ));
+                    panel.spawn((
+                        Text::new(summary),
+                        TextFont {
+                            font: font_assets.rajdhani_medium.clone(),
+                            font_size: 30.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                    ));
+
+                    panel
+                        .spawn(Node {
+                            column_gap: Val::Px(32.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            for (action, label) in [
+                                (ConfirmAction::RestartLevel, "RETRY"),
+                                (ConfirmAction::ReturnToMainMenu, "MAIN MENU"),
+                            ] {
+                                buttons
+                                    .spawn((
+                                        Node {
+                                            width: Val::Px(220.0),
+                                            height: Val::Px(60.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            border: UiRect::all(Val::Px(2.0)),
+                                            ..default()
+                                        },
+                                        Button,
+                                        BackgroundColor(Color::DARKER_GLASS),
+                                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                                        BorderRadius::all(Val::Px(8.0)),
+                                        GameOverButton(action),
+                                        children![(
+                                            Text::new(label),
+                                            TextFont {
+                                                font: font.clone(),
+                                                font_size: 28.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::ELYSIUM_GOLD),
+                                            Pickable::IGNORE,
+                                        )],
+                                    ))
+                                    .observe(game_over_button_clicked);
+                            }
+                        });
+                });
+        });
+}
+
+fn game_over_button_clicked(
+    trigger: Trigger<Pointer<Click>>,
+    button_query: Query<&GameOverButton>,
+    mut commands: Commands,
+) {
+    if let Ok(button) = button_query.get(trigger.target()) {
+        commands.trigger(ConfirmDialogAccepted(button.0));
+    }
+}
+
+fn despawn_game_over_screen(
+    _: Trigger<ConfirmDialogAccepted>,
+    mut commands: Commands,
+    screen_query: Query<Entity, With<GameOverScreen>>,
+) {
+    for entity in &screen_query {
+        commands.entity(entity).despawn();
+    }
+}
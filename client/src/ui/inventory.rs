@@ -2,7 +2,12 @@ use bevy::prelude::*;
 
 use crate::assets::FontAssets;
 use crate::assets::UiAssets;
+use crate::constants::inventory::{ConsumableConfig, INVENTORY_SLOT_COUNT, InventoryConfig};
+use crate::resources::audio::{PlaySfxEvent, SfxType};
+use crate::systems::character_controller::{CharacterController, Stamina};
 use crate::systems::collectibles::{CollectibleType, NextItemToAdd};
+use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::ui::toast::{Toast, spawn_toast};
 
 // Inventory UI marker
 #[derive(Component)]
@@ -19,8 +24,46 @@ pub struct InventoryItem {
     pub count: usize,
 }
 
+/// An item stack as recorded at a checkpoint, with the slot it sat in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InventoryItemSnapshot {
+    pub slot: usize,
+    pub item_type: CollectibleType,
+    pub count: usize,
+}
+
+/// Items waiting to be put back by `restore_inventory`
+#[derive(Resource)]
+pub struct InventoryRestore(pub Vec<InventoryItemSnapshot>);
+
+/// Count badge text, pointing back at the `InventoryItem` it displays
 #[derive(Component)]
-pub struct CountText;
+pub struct CountText {
+    pub item: Entity,
+}
+
+/// Sent when a pickup is refused because its stack is capped or every slot holds another item
+#[derive(Event)]
+pub struct InventoryFullEvent {
+    pub item_type: CollectibleType,
+}
+
+/// Request to use one unit of whatever sits in the slot with this index
+#[derive(Event)]
+pub struct InventoryUseEvent {
+    pub slot: usize,
+}
+
+/// Item currently being dragged between slots, with the ghost image following the cursor
+#[derive(Resource)]
+pub struct DraggedItem {
+    pub item: Entity,
+    pub from_slot: Entity,
+    pub ghost: Entity,
+}
+
+/// Size of the ghost image shown while dragging an item
+const DRAG_GHOST_SIZE: f32 = 96.0;
 
 #[derive(Resource)]
 pub struct InventoryVisibilityState {
@@ -45,6 +88,7 @@ pub fn adjust_inventory_for_dialogs(
     mut inventory_query: Query<&mut Node, (With<InventoryUI>, Without<crate::ui::dialog::Dialog>)>,
     mut visibility_state: ResMut<InventoryVisibilityState>,
     windows: Query<&Window>,
+    ui_scale: Res<UiScale>,
 ) {
     // Check if any dialog is visible
     let dialog_visible = dialog_query
@@ -53,8 +97,8 @@ pub fn adjust_inventory_for_dialogs(
 
     if let Ok(mut node) = inventory_query.single_mut() {
         if dialog_visible && !visibility_state.shifted_up {
-            // Determine position based on screen height
-            let screen_height = windows.single().expect("No window found").height();
+            // Determine position based on screen height, in UI pixels so `UiScale` is accounted for
+            let screen_height = windows.single().expect("No window found").height() / ui_scale.0;
             let inventory_padding = if screen_height > 1080.0 {
                 200.0 // Fullscreen mode - higher position
             } else {
@@ -73,7 +117,6 @@ pub fn adjust_inventory_for_dialogs(
 }
 
 pub fn spawn_inventory_ui<T: Component + Default>(commands: &mut Commands) {
-    use crate::ui::styles::ElysiumDescentColorPalette;
     commands
         .spawn((
             Node {
@@ -98,7 +141,7 @@ pub fn spawn_inventory_ui<T: Component + Default>(commands: &mut Commands) {
             T::default(),
         ))
         .with_children(|parent| {
-            for i in 0..6 {
+            for i in 0..INVENTORY_SLOT_COUNT {
                 parent.spawn((
                     Node {
                         width: Val::Px(120.0),
@@ -115,6 +158,11 @@ pub fn spawn_inventory_ui<T: Component + Default>(commands: &mut Commands) {
                     BorderRadius::all(Val::Px(12.0)),
                     InventorySlot { index: i },
                 ))
+                .observe(start_item_drag)
+                .observe(move_dragged_item)
+                .observe(drop_item_on_slot)
+                .observe(end_item_drag)
+                .observe(use_clicked_slot)
                 .with_children(|slot| {
                     // Always add a filler node to ensure consistent sizing
                     slot.spawn((
@@ -146,12 +194,29 @@ pub fn toggle_inventory_visibility(
     state.visible = true;
 }
 
+/// Whether picking up `item_type` fits: its stack isn't capped yet, or it needs a
+/// new slot and one is free. A new item type never evicts an existing one, so
+/// with all slots taken the pickup is refused and stays in the world.
+pub fn inventory_accepts<'a>(
+    items: impl IntoIterator<Item = &'a InventoryItem>,
+    item_type: CollectibleType,
+) -> bool {
+    let mut used_slots = 0;
+    for item in items {
+        if item.item_type == item_type {
+            return item.count < InventoryConfig::max_stack(item_type);
+        }
+        used_slots += 1;
+    }
+    used_slots < INVENTORY_SLOT_COUNT
+}
+
 pub fn add_item_to_inventory(
     mut commands: Commands,
     mut slot_query: Query<(Entity, &InventorySlot)>,
     children_query: Query<&Children>,
     mut item_query: Query<&mut InventoryItem>,
-    mut text_query: Query<&mut Text>,
+    mut count_text_query: Query<(&CountText, &mut Text)>,
     font_assets: Res<FontAssets>,
     ui_assets: Res<UiAssets>,
     collectible_type: Option<Res<NextItemToAdd>>,
@@ -174,20 +239,20 @@ pub fn add_item_to_inventory(
             for child in children.iter() {
                 if let Ok(mut item) = item_query.get_mut(child) {
                     if item.item_type == collectible_type.0 {
-                        // Found matching item → increase count
+                        // Found matching item → increase count, up to its stack limit
+                        if item.count >= InventoryConfig::max_stack(item.item_type) {
+                            commands.remove_resource::<NextItemToAdd>();
+                            return;
+                        }
                         item.count += 1;
 
-                        // Now do some iterations to update the text count
-                        if let Ok(grandchildren) = children_query.get(child) {
-                            if let Ok(grand_grandchildren) = children_query.get(grandchildren[1]) {
-                                if let Some(&leaf) = grand_grandchildren.first() {
-                                    if let Ok(mut text) = text_query.get_mut(leaf) {
-                                        text.clear();
-                                        text.push_str(&item.count.to_string());
-                                    }
-                                }
+                        // Update the badge that belongs to this item
+                        for (count_text, mut text) in count_text_query.iter_mut() {
+                            if count_text.item == child {
+                                text.clear();
+                                text.push_str(&item.count.to_string());
                             }
-                        };
+                        }
 
                         commands.remove_resource::<NextItemToAdd>();
                         return;
@@ -209,74 +274,525 @@ pub fn add_item_to_inventory(
             });
         
         if !has_item {
-            commands.entity(slot_entity).with_children(|parent| {
-                parent
-                    .spawn((
-                        Node {
-                            width: Val::Percent(100.0),
-                            height: Val::Percent(100.0),
-                            align_items: AlignItems::Center,
-                            justify_content: JustifyContent::Center,
-                            ..default()
-                        },
-                        ZIndex(-1),
-                        InventoryItem {
-                            item_type: collectible_type.0,
-                            count: 1,
-                        },
-                    ))
-                    .with_children(|item_parent| {
-                        // spawn the image (larger, centered)
-                        item_parent.spawn((
-                            Node {
-                                width: Val::Px(133.0),
-                                height: Val::Px(133.0),
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::Center,
-                                ..default()
-                            },
-                            ImageNode {
-                                image: ui_assets.coin.clone(),
-                                ..default()
-                            },
-                            ZIndex(1),
-                        ));
-
-                        // Spawn count text (perfect circle badge, bottom right of image)
-                        item_parent
-                            .spawn((
-                                Node {
-                                    position_type: PositionType::Absolute,
-                                    width: Val::Px(33.0),  // Slightly smaller for better proportion
-                                    height: Val::Px(33.0), // Same as width for circle
-                                    right: Val::Px(-20.0),  // Positioned relative to image edge
-                                    bottom: Val::Px(13.0), // Positioned relative to image edge
-                                    align_items: AlignItems::Center,
-                                    justify_content: JustifyContent::Center,
-                                    border: UiRect::all(Val::Px(1.0)), // Add border for definition
-                                    ..default()
-                                },
-                                BorderRadius::all(Val::Px(16.5)), // Half of width/height for perfect circle
-                                BorderColor(Color::srgba(0.0, 0.0, 0.0, 0.4)), // Slightly darker border
-                                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.98)), // More opaque
-                                ZIndex(2),
-                            ))
-                            .with_children(|text_parent| {
-                                text_parent.spawn((
-                                    TextFont {
-                                        font_size: 19.0, // Adjusted for smaller badge
-                                        font: font_assets.rajdhani_extra_bold.clone(),
-                                        ..default()
-                                    },
-                                    Text::new("1"),
-                                    TextColor(Color::srgb(0.1, 0.1, 0.1)), // Dark gray instead of pure black
-                                ));
-                            });
-                    });
-            });
+            spawn_inventory_item(
+                &mut commands,
+                slot_entity,
+                collectible_type.0,
+                1,
+                &font_assets,
+                &ui_assets,
+            );
 
             commands.remove_resource::<NextItemToAdd>();
             return;
         }
     }
+
+    // Every slot is taken by another item type
+    commands.remove_resource::<NextItemToAdd>();
+}
+
+/// Spawns an item stack with its icon and count badge into an empty `slot`
+fn spawn_inventory_item(
+    commands: &mut Commands,
+    slot: Entity,
+    item_type: CollectibleType,
+    count: usize,
+    font_assets: &FontAssets,
+    ui_assets: &UiAssets,
+) {
+    commands.entity(slot).with_children(|parent| {
+        let mut item_commands = parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(-1),
+            InventoryItem {
+                item_type,
+                count,
+            },
+        ));
+        let item_entity = item_commands.id();
+        item_commands.with_children(|item_parent| {
+            // spawn the image (larger, centered)
+            item_parent.spawn((
+                Node {
+                    width: Val::Px(133.0),
+                    height: Val::Px(133.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ImageNode {
+                    image: ui_assets.coin.clone(),
+                    ..default()
+                },
+                ZIndex(1),
+            ));
+
+            // Spawn count text (perfect circle badge, bottom right of image)
+            item_parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(33.0),  // Slightly smaller for better proportion
+                        height: Val::Px(33.0), // Same as width for circle
+                        right: Val::Px(-20.0),  // Positioned relative to image edge
+                        bottom: Val::Px(13.0), // Positioned relative to image edge
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        border: UiRect::all(Val::Px(1.0)), // Add border for definition
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Px(16.5)), // Half of width/height for perfect circle
+                    BorderColor(Color::srgba(0.0, 0.0, 0.0, 0.4)), // Slightly darker border
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.98)), // More opaque
+                    ZIndex(2),
+                ))
+                .with_children(|text_parent| {
+                    text_parent.spawn((
+                        TextFont {
+                            font_size: 19.0, // Adjusted for smaller badge
+                            font: font_assets.rajdhani_extra_bold.clone(),
+                            ..default()
+                        },
+                        Text::new(count.to_string()),
+                        TextColor(Color::srgb(0.1, 0.1, 0.1)), // Dark gray instead of pure black
+                        CountText { item: item_entity },
+                    ));
+                });
+        });
+    });
+}
+
+/// Puts recorded items back into their slots; the restart that inserts this has
+/// already despawned whatever the player was carrying
+pub fn restore_inventory(
+    mut commands: Commands,
+    restore: Res<InventoryRestore>,
+    slot_query: Query<(Entity, &InventorySlot)>,
+    font_assets: Res<FontAssets>,
+    ui_assets: Res<UiAssets>,
+) {
+    for item in &restore.0 {
+        let Some((slot_entity, _)) = slot_query.iter().find(|(_, slot)| slot.index == item.slot)
+        else {
+            continue;
+        };
+        spawn_inventory_item(
+            &mut commands,
+            slot_entity,
+            item.item_type,
+            item.count,
+            &font_assets,
+            &ui_assets,
+        );
+    }
+    commands.remove_resource::<InventoryRestore>();
+}
+
+/// Shows a short "Inventory Full" toast when a pickup is refused
+pub fn show_inventory_full_toast(
+    mut commands: Commands,
+    mut events: EventReader<InventoryFullEvent>,
+    toast_query: Query<Entity, With<Toast>>,
+    font_assets: Res<FontAssets>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+    debug!("Inventory full, left {:?} in the world", event.item_type);
+
+    spawn_toast(
+        &mut commands,
+        &font_assets,
+        &toast_query,
+        "Inventory Full",
+        Color::DANGER_RED,
+    );
+}
+
+// ===== DRAG AND DROP =====
+
+/// Picks up the item in the pressed slot and spawns a ghost image under the cursor
+fn start_item_drag(
+    trigger: Trigger<Pointer<DragStart>>,
+    mut commands: Commands,
+    children_query: Query<&Children>,
+    mut item_query: Query<&mut Visibility, With<InventoryItem>>,
+    image_query: Query<&ImageNode>,
+    ui_scale: Res<UiScale>,
+) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+
+    let slot = trigger.target();
+    let Some(item) = children_query
+        .get(slot)
+        .ok()
+        .and_then(|children| children.iter().find(|child| item_query.contains(*child)))
+    else {
+        return; // Nothing to drag out of an empty slot
+    };
+
+    let image = children_query
+        .iter_descendants(item)
+        .find_map(|entity| image_query.get(entity).ok())
+        .map(|image| image.image.clone())
+        .unwrap_or_default();
+
+    // Pointer positions are logical pixels, `Val::Px` is scaled by `UiScale`
+    let position = trigger.pointer_location.position / ui_scale.0;
+    let ghost = commands
+        .spawn((
+            Name::new("Dragged Item"),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(DRAG_GHOST_SIZE),
+                height: Val::Px(DRAG_GHOST_SIZE),
+                left: Val::Px(position.x - DRAG_GHOST_SIZE / 2.0),
+                top: Val::Px(position.y - DRAG_GHOST_SIZE / 2.0),
+                ..default()
+            },
+            ImageNode {
+                image,
+                color: Color::WHITE.with_alpha(0.8),
+                ..default()
+            },
+            GlobalZIndex(100),
+            Pickable::IGNORE, // Let slots under the cursor receive the drop
+        ))
+        .id();
+
+    if let Ok(mut visibility) = item_query.get_mut(item) {
+        *visibility = Visibility::Hidden;
+    }
+    commands.insert_resource(DraggedItem {
+        item,
+        from_slot: slot,
+        ghost,
+    });
+}
+
+fn move_dragged_item(
+    trigger: Trigger<Pointer<Drag>>,
+    dragged: Option<Res<DraggedItem>>,
+    mut node_query: Query<&mut Node>,
+    ui_scale: Res<UiScale>,
+) {
+    let Some(dragged) = dragged else {
+        return;
+    };
+
+    if let Ok(mut node) = node_query.get_mut(dragged.ghost) {
+        let position = trigger.pointer_location.position / ui_scale.0;
+        node.left = Val::Px(position.x - DRAG_GHOST_SIZE / 2.0);
+        node.top = Val::Px(position.y - DRAG_GHOST_SIZE / 2.0);
+    }
+}
+
+/// Moves the dragged item into the slot it was released over, swapping with whatever was there
+fn drop_item_on_slot(
+    trigger: Trigger<Pointer<DragDrop>>,
+    mut commands: Commands,
+    dragged: Option<Res<DraggedItem>>,
+    slot_query: Query<(), With<InventorySlot>>,
+    children_query: Query<&Children>,
+    item_query: Query<(), With<InventoryItem>>,
+) {
+    let Some(dragged) = dragged else {
+        return;
+    };
+
+    let target_slot = trigger.target();
+    if target_slot == dragged.from_slot || !slot_query.contains(target_slot) {
+        return;
+    }
+
+    let target_item = children_query
+        .get(target_slot)
+        .ok()
+        .and_then(|children| children.iter().find(|child| item_query.contains(*child)));
+
+    commands.entity(target_slot).add_child(dragged.item);
+    if let Some(target_item) = target_item {
+        commands.entity(dragged.from_slot).add_child(target_item);
+    }
+}
+
+/// Clears the drag; an item released outside any slot simply stays where it was
+fn end_item_drag(
+    _: Trigger<Pointer<DragEnd>>,
+    mut commands: Commands,
+    dragged: Option<Res<DraggedItem>>,
+    mut item_query: Query<&mut Visibility, With<InventoryItem>>,
+) {
+    let Some(dragged) = dragged else {
+        return;
+    };
+
+    if let Ok(mut visibility) = item_query.get_mut(dragged.item) {
+        *visibility = Visibility::Inherited;
+    }
+    commands.entity(dragged.ghost).despawn();
+    commands.remove_resource::<DraggedItem>();
+}
+
+/// Drops a drag in progress when leaving gameplay, so the ghost doesn't outlive the inventory
+pub fn cancel_item_drag(mut commands: Commands, dragged: Option<Res<DraggedItem>>) {
+    if let Some(dragged) = dragged {
+        commands.entity(dragged.ghost).despawn();
+        commands.remove_resource::<DraggedItem>();
+    }
+}
+
+// ===== CONSUMABLES =====
+
+const SLOT_HOTKEYS: [KeyCode; INVENTORY_SLOT_COUNT] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+];
+
+/// Number keys 1-6 use the matching inventory slot
+pub fn use_inventory_slot_hotkeys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut use_events: EventWriter<InventoryUseEvent>,
+) {
+    for (slot, key) in SLOT_HOTKEYS.iter().enumerate() {
+        if keyboard.just_pressed(*key) {
+            use_events.write(InventoryUseEvent { slot });
+        }
+    }
+}
+
+/// Right-clicking a slot uses it; left button is reserved for dragging
+fn use_clicked_slot(
+    trigger: Trigger<Pointer<Click>>,
+    slot_query: Query<&InventorySlot>,
+    mut use_events: EventWriter<InventoryUseEvent>,
+) {
+    if trigger.button != PointerButton::Secondary {
+        return;
+    }
+    if let Ok(slot) = slot_query.get(trigger.target()) {
+        use_events.write(InventoryUseEvent { slot: slot.index });
+    }
+}
+
+/// Consumes one unit from the requested slot and applies its effect to the player
+pub fn handle_inventory_use(
+    mut commands: Commands,
+    mut use_events: EventReader<InventoryUseEvent>,
+    slot_query: Query<(Entity, &InventorySlot)>,
+    children_query: Query<&Children>,
+    mut item_query: Query<&mut InventoryItem>,
+    mut count_text_query: Query<(&CountText, &mut Text)>,
+    mut player_query: Query<(&mut crate::game::Health, &mut Stamina), With<CharacterController>>,
+    mut sfx_events: EventWriter<PlaySfxEvent>,
+) {
+    for event in use_events.read() {
+        let Some((slot_entity, _)) = slot_query.iter().find(|(_, slot)| slot.index == event.slot) else {
+            continue;
+        };
+        let Some(item_entity) = children_query
+            .get(slot_entity)
+            .ok()
+            .and_then(|children| children.iter().find(|child| item_query.contains(*child)))
+        else {
+            continue; // Empty slot
+        };
+        let Ok(mut item) = item_query.get_mut(item_entity) else {
+            continue;
+        };
+        if !item.item_type.is_consumable() {
+            continue;
+        }
+        let Ok((mut health, mut stamina)) = player_query.single_mut() else {
+            continue;
+        };
+
+        match item.item_type {
+            CollectibleType::HealthPotion => health.heal(ConsumableConfig::HEALTH_POTION_HEAL),
+            CollectibleType::SurvivalKit => health.heal(ConsumableConfig::SURVIVAL_KIT_HEAL),
+            CollectibleType::PowerCrystal => stamina.regenerate(ConsumableConfig::POWER_CRYSTAL_STAMINA),
+            CollectibleType::Coin | CollectibleType::Book => continue,
+        }
+        sfx_events.write(PlaySfxEvent {
+            sfx_type: SfxType::ItemUse,
+        });
+
+        item.count = item.count.saturating_sub(1);
+        if item.count == 0 {
+            // Frees the slot for the next pickup
+            commands.entity(item_entity).despawn();
+        } else {
+            for (count_text, mut text) in count_text_query.iter_mut() {
+                if count_text.item == item_entity {
+                    text.clear();
+                    text.push_str(&item.count.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::headless_app;
+
+    /// The six empty slots with the inventory systems, and a player to use items on
+    fn inventory_app() -> App {
+        let mut app = headless_app();
+        app.init_resource::<FontAssets>()
+            .init_resource::<UiAssets>()
+            .init_resource::<InventoryVisibilityState>()
+            .add_event::<InventoryUseEvent>()
+            .add_event::<PlaySfxEvent>()
+            .add_systems(Update, (add_item_to_inventory, handle_inventory_use).chain());
+        for index in 0..INVENTORY_SLOT_COUNT {
+            app.world_mut().spawn(InventorySlot { index });
+        }
+        app.world_mut()
+            .spawn((CharacterController, crate::game::Health::new(100), Stamina::default()));
+        app
+    }
+
+    fn pick_up(app: &mut App, item_type: CollectibleType) {
+        app.insert_resource(NextItemToAdd(item_type));
+        app.update();
+    }
+
+    fn items(app: &mut App) -> Vec<(CollectibleType, usize)> {
+        let world = app.world_mut();
+        world
+            .query::<&InventoryItem>()
+            .iter(world)
+            .map(|item| (item.item_type, item.count))
+            .collect()
+    }
+
+    fn badge_text(app: &mut App) -> Vec<String> {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Text, With<CountText>>()
+            .iter(world)
+            .map(|text| text.0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn picking_up_a_held_item_increments_its_count_and_badge() {
+        let mut app = inventory_app();
+        pick_up(&mut app, CollectibleType::HealthPotion);
+        assert_eq!(items(&mut app), vec![(CollectibleType::HealthPotion, 1)]);
+        assert_eq!(badge_text(&mut app), vec!["1".to_string()]);
+
+        pick_up(&mut app, CollectibleType::HealthPotion);
+        assert_eq!(items(&mut app), vec![(CollectibleType::HealthPotion, 2)]);
+        assert_eq!(badge_text(&mut app), vec!["2".to_string()]);
+    }
+
+    #[test]
+    fn a_new_item_type_takes_its_own_slot() {
+        let mut app = inventory_app();
+        pick_up(&mut app, CollectibleType::HealthPotion);
+        pick_up(&mut app, CollectibleType::Book);
+        let held = items(&mut app);
+        assert_eq!(held.len(), 2);
+        assert!(held.contains(&(CollectibleType::HealthPotion, 1)));
+        assert!(held.contains(&(CollectibleType::Book, 1)));
+    }
+
+    fn stack(item_type: CollectibleType, count: usize) -> InventoryItem {
+        InventoryItem { item_type, count }
+    }
+
+    #[test]
+    fn stacks_accept_pickups_up_to_the_cap() {
+        let cap = InventoryConfig::max_stack(CollectibleType::HealthPotion);
+        assert!(inventory_accepts(&[stack(CollectibleType::HealthPotion, cap - 1)], CollectibleType::HealthPotion));
+        assert!(!inventory_accepts(&[stack(CollectibleType::HealthPotion, cap)], CollectibleType::HealthPotion));
+    }
+
+    #[test]
+    fn new_item_type_is_refused_when_every_slot_is_taken() {
+        let others = [
+            CollectibleType::Coin,
+            CollectibleType::SurvivalKit,
+            CollectibleType::Book,
+            CollectibleType::PowerCrystal,
+        ];
+        let full: Vec<InventoryItem> = others
+            .iter()
+            .cycle()
+            .take(INVENTORY_SLOT_COUNT)
+            .map(|item_type| stack(*item_type, 1))
+            .collect();
+        assert!(!inventory_accepts(&full, CollectibleType::HealthPotion));
+        // One free slot is enough
+        assert!(inventory_accepts(&full[1..], CollectibleType::HealthPotion));
+        // A type already held still stacks in a full inventory
+        assert!(inventory_accepts(&full, CollectibleType::Book));
+    }
+
+    #[test]
+    fn pickups_past_the_cap_leave_the_stack_unchanged() {
+        let mut app = inventory_app();
+        let cap = InventoryConfig::max_stack(CollectibleType::Book);
+        for _ in 0..cap + 2 {
+            pick_up(&mut app, CollectibleType::Book);
+        }
+        assert_eq!(items(&mut app), vec![(CollectibleType::Book, cap)]);
+        assert_eq!(badge_text(&mut app), vec![cap.to_string()]);
+    }
+
+    fn use_slot(app: &mut App, slot: usize) {
+        app.world_mut().send_event(InventoryUseEvent { slot });
+        app.update();
+    }
+
+    #[test]
+    fn using_a_consumable_decrements_it_until_it_is_removed() {
+        let mut app = inventory_app();
+        pick_up(&mut app, CollectibleType::HealthPotion);
+        pick_up(&mut app, CollectibleType::HealthPotion);
+        let world = app.world_mut();
+        world
+            .query_filtered::<&mut crate::game::Health, With<CharacterController>>()
+            .single_mut(world)
+            .unwrap()
+            .current = 10;
+
+        use_slot(&mut app, 0);
+        assert_eq!(items(&mut app), vec![(CollectibleType::HealthPotion, 1)]);
+        assert_eq!(badge_text(&mut app), vec!["1".to_string()]);
+        let world = app.world_mut();
+        let health = world
+            .query_filtered::<&crate::game::Health, With<CharacterController>>()
+            .single(world)
+            .unwrap()
+            .current;
+        assert_eq!(health, 10 + ConsumableConfig::HEALTH_POTION_HEAL);
+
+        use_slot(&mut app, 0);
+        assert!(items(&mut app).is_empty());
+    }
+
+    #[test]
+    fn non_consumables_are_not_used_up() {
+        let mut app = inventory_app();
+        pick_up(&mut app, CollectibleType::Book);
+        use_slot(&mut app, 0);
+        assert_eq!(items(&mut app), vec![(CollectibleType::Book, 1)]);
+    }
 }
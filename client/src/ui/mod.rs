@@ -1,5 +1,12 @@
+pub mod coin_counter;
+pub mod compass;
+pub mod connection_status;
 pub mod dialog;
+pub mod game_over;
 pub mod inventory;
 pub mod modal;
+pub mod movement_tuning;
 pub mod styles;
+pub mod toast;
+pub mod touch_controls;
 pub mod widgets;
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 use crate::assets::{FontAssets, UiAssets};
-use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::resources::locale::Locale;
+use crate::ui::styles::{ElysiumDescentColorPalette, PaletteBorder, PaletteRole, UiPalette};
 use crate::systems::objectives::ObjectiveManager;
+use crate::systems::achievements::{AchievementId, Achievements};
+use crate::systems::statistics::{PlayerStatistics, StatKind};
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 
 // ===== MODAL COMPONENTS =====
@@ -17,9 +20,7 @@ pub struct ModalContent;
 
 #[derive(Component)]
 pub struct NavigationTab {
-    #[allow(dead_code)]
     pub tab_name: String,
-    #[allow(dead_code)]
     pub is_active: bool,
 }
 
@@ -38,10 +39,37 @@ pub struct QuestReward;
 #[derive(Component)]
 pub struct QuestEntriesContainer;
 
+/// Content shown under a tab; only the visible tab's panel is displayed
+#[derive(Component)]
+pub struct TabPanel {
+    pub tab_name: &'static str,
+}
+
+/// Tabs that have a panel; the rest are still placeholders and can't be selected
+const PANEL_TABS: [&str; 3] = ["QUESTS", "STATS", "ACHIEVEMENTS"];
+
+/// One value in the STATS tab, either for the current run or the lifetime total
+#[derive(Component)]
+pub struct StatValueText {
+    pub stat: StatKind,
+    pub lifetime: bool,
+}
+
+/// "x / y UNLOCKED" at the top of the ACHIEVEMENTS tab
+#[derive(Component)]
+pub struct AchievementsSummaryText;
+
+/// Progress toward one achievement in the ACHIEVEMENTS tab
+#[derive(Component)]
+pub struct AchievementProgressText(pub AchievementId);
+
+/// An achievement's title, gold once it's unlocked
+#[derive(Component)]
+pub struct AchievementTitleText(pub AchievementId);
+
 #[derive(Resource)]
 pub struct ModalState {
     pub visible: bool,
-    #[allow(dead_code)]
     pub active_tab: String,
 }
 
@@ -56,7 +84,7 @@ impl Default for ModalState {
 
 // ===== MODAL SYSTEMS =====
 
-pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAssets>, ui_assets: &Res<UiAssets>) {
+pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAssets>, ui_assets: &Res<UiAssets>, locale: &Locale) {
     commands
         .spawn((
             Node {
@@ -80,6 +108,9 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                 Node {
                     width: Val::Px(1350.0), // Scaled up by 1.5x from 900
                     height: Val::Px(900.0), // Scaled up by 1.5x from 600
+                    // On top of window scaling, so a raised UI scale still can't push it off screen
+                    max_width: Val::Percent(95.0),
+                    max_height: Val::Percent(90.0),
                     flex_direction: FlexDirection::Column,
                     justify_content: JustifyContent::FlexStart,
                     align_items: AlignItems::Center,
@@ -173,7 +204,15 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                 ));
                 
                 // Navigation tabs - more prominent
-                let tabs = ["INVENTORY", "QUESTS", "CONTROLLER", "SETTINGS", "STATS"];
+                // Tabs are identified by their English name; only the label is translated
+                let tabs = [
+                    ("INVENTORY", "modal.tab.inventory"),
+                    ("QUESTS", "modal.tab.quests"),
+                    ("CONTROLLER", "modal.tab.controller"),
+                    ("SETTINGS", "modal.tab.settings"),
+                    ("STATS", "modal.tab.stats"),
+                    ("ACHIEVEMENTS", "modal.tab.achievements"),
+                ];
                 modal.spawn((
                     Node {
                         width: Val::Percent(100.0),
@@ -187,9 +226,9 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                 ))
                 .with_children(|tabs_parent| {
                     // Spawn each tab
-                    for (_i, tab_name) in tabs.iter().enumerate() {
-                        let is_active = *tab_name == "QUESTS";
-                        tabs_parent.spawn((
+                    for (tab_name, label_key) in tabs {
+                        let is_active = tab_name == "QUESTS";
+                        let mut tab_entity = tabs_parent.spawn((
                             Node {
                                 padding: UiRect::all(Val::Px(18.0)), // Scaled up by 1.5x from 12
                                 border: if is_active { UiRect::bottom(Val::Px(3.0)) } else { UiRect::all(Val::Px(0.0)) }, // Scaled up by 1.5x from 2
@@ -201,22 +240,27 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                                 Color::NONE 
                             }),
                             BorderColor(Color::ELYSIUM_GOLD),
+                            PaletteBorder(PaletteRole::Accent),
                             NavigationTab {
                                 tab_name: tab_name.to_string(),
                                 is_active,
                             },
-                        ))
-                        .with_children(|tab| {
+                        ));
+                        tab_entity.with_children(|tab| {
                             tab.spawn((
-                                Text::new(*tab_name),
+                                Text::new(locale.t(label_key)),
                                 TextFont {
                                     font: font_assets.rajdhani_medium.clone(),
                                     font_size: 27.0, // Scaled up by 1.5x from 18
                                     ..default()
                                 },
                                 TextColor(if is_active { Color::ELYSIUM_GOLD } else { Color::WHITE.with_alpha(0.7) }),
+                                Pickable::IGNORE,
                             ));
                         });
+                        if PANEL_TABS.contains(&tab_name) {
+                            tab_entity.insert(Button).observe(select_modal_tab);
+                        }
                     }
                 });
                 
@@ -242,7 +286,7 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                             children![
                                 // Title text
                                 (
-                                    Text::new("QUESTS"),
+                                    Text::new(locale.t("modal.quests")),
                                     TextFont {
                                         font: font_assets.rajdhani_bold.clone(),
                                         font_size: 54.0, // Scaled up by 1.5x from 36
@@ -302,6 +346,7 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                     BackgroundColor(Color::srgba(0.05, 0.07, 0.11, 0.8)),
                     BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.3)),
                     BorderRadius::all(Val::Px(6.0)),
+                    TabPanel { tab_name: "QUESTS" },
                     children![
                         // Quest entries container - scrollable
                         (
@@ -333,10 +378,309 @@ pub fn spawn_objectives_modal(commands: &mut Commands, font_assets: &Res<FontAss
                         )
                     ]
                 ));
+
+                spawn_stats_panel(modal, font_assets, locale);
+                spawn_achievements_panel(modal, font_assets);
+
+                // Restart level button, pinned to the bottom of the panel
+                modal
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            bottom: Val::Px(18.0),
+                            padding: UiRect::axes(Val::Px(30.0), Val::Px(12.0)),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        Button,
+                        Name::new("Restart Level Button"),
+                        BackgroundColor(Color::DARK_GLASS),
+                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                        BorderRadius::all(Val::Px(8.0)),
+                        children![(
+                            Text::new(locale.t("modal.restart_level")),
+                            TextFont {
+                                font: font_assets.rajdhani_bold.clone(),
+                                font_size: 27.0,
+                                ..default()
+                            },
+                            TextColor(Color::ELYSIUM_GOLD),
+                            Pickable::IGNORE,
+                        )],
+                    ))
+                    .observe(restart_level_clicked);
             });
         });
 }
 
+/// STATS tab: one row per statistic, with the current run beside the lifetime total
+fn spawn_stats_panel(modal: &mut ChildSpawnerCommands, font_assets: &FontAssets, locale: &Locale) {
+    let font = font_assets.rajdhani_medium.clone();
+    let cell = |width: f32| Node {
+        width: Val::Px(width),
+        ..default()
+    };
+
+    modal
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(525.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(30.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.07, 0.11, 0.8)),
+            BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.3)),
+            BorderRadius::all(Val::Px(6.0)),
+            TabPanel { tab_name: "STATS" },
+        ))
+        .with_children(|panel| {
+            // Column headers
+            panel.spawn(Node::default()).with_children(|row| {
+                row.spawn(cell(480.0));
+                for key in ["stats.this_run", "stats.lifetime"] {
+                    row.spawn((
+                        cell(300.0),
+                        Text::new(locale.t(key)),
+                        TextFont {
+                            font: font_assets.rajdhani_bold.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::ELYSIUM_GOLD),
+                    ));
+                }
+            });
+
+            for stat in StatKind::ALL {
+                panel
+                    .spawn((
+                        Node {
+                            padding: UiRect::vertical(Val::Px(9.0)),
+                            border: UiRect::bottom(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.15)),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            cell(480.0),
+                            Text::new(locale.t(stat.label_key())),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 27.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                        for lifetime in [false, true] {
+                            row.spawn((
+                                cell(300.0),
+                                Text::new(""),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 27.0,
+                                    ..default()
+                                },
+                                TextColor(if lifetime { Color::WHITE.with_alpha(0.7) } else { Color::WHITE }),
+                                StatValueText { stat, lifetime },
+                            ));
+                        }
+                    });
+            }
+        });
+}
+
+/// ACHIEVEMENTS tab: every achievement with its description and progress
+fn spawn_achievements_panel(modal: &mut ChildSpawnerCommands, font_assets: &FontAssets) {
+    let font = font_assets.rajdhani_medium.clone();
+
+    modal
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(525.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(30.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.07, 0.11, 0.8)),
+            BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.3)),
+            BorderRadius::all(Val::Px(6.0)),
+            TabPanel { tab_name: "ACHIEVEMENTS" },
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font_assets.rajdhani_bold.clone(),
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::ELYSIUM_GOLD),
+                AchievementsSummaryText,
+            ));
+
+            for id in AchievementId::ALL {
+                panel
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::vertical(Val::Px(9.0)),
+                            border: UiRect::bottom(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.15)),
+                    ))
+                    .with_children(|row| {
+                        row.spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        })
+                        .with_children(|text| {
+                            text.spawn((
+                                Text::new(id.title()),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 27.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                AchievementTitleText(id),
+                            ));
+                            text.spawn((
+                                Text::new(id.description()),
+                                TextFont {
+                                    font: font.clone(),
+                                    font_size: 21.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE.with_alpha(0.7)),
+                            ));
+                        });
+                        row.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 27.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            AchievementProgressText(id),
+                        ));
+                    });
+            }
+        });
+}
+
+fn select_modal_tab(
+    trigger: Trigger<Pointer<Click>>,
+    tab_query: Query<&NavigationTab>,
+    mut modal_state: ResMut<ModalState>,
+) {
+    let Ok(tab) = tab_query.get(trigger.target()) else { return; };
+    if modal_state.active_tab != tab.tab_name {
+        modal_state.active_tab = tab.tab_name.clone();
+    }
+}
+
+/// Highlights the active tab and shows its panel
+pub fn update_modal_tabs(
+    modal_state: Res<ModalState>,
+    palette: Res<UiPalette>,
+    mut tab_query: Query<(&mut NavigationTab, &mut Node, &mut BackgroundColor, &Children)>,
+    mut label_query: Query<&mut TextColor>,
+    mut panel_query: Query<(&TabPanel, &mut Node), Without<NavigationTab>>,
+) {
+    if !modal_state.is_changed() && !palette.is_changed() {
+        return;
+    }
+
+    for (mut tab, mut node, mut background, children) in &mut tab_query {
+        tab.is_active = tab.tab_name == modal_state.active_tab;
+        node.border = if tab.is_active { UiRect::bottom(Val::Px(3.0)) } else { UiRect::all(Val::Px(0.0)) };
+        background.0 = if tab.is_active { Color::srgba(0.15, 0.17, 0.21, 0.8) } else { Color::NONE };
+        for child in children.iter() {
+            if let Ok(mut color) = label_query.get_mut(child) {
+                color.0 = if tab.is_active { palette.accent } else { Color::WHITE.with_alpha(0.7) };
+            }
+        }
+    }
+
+    for (panel, mut node) in &mut panel_query {
+        node.display = if panel.tab_name == modal_state.active_tab { Display::Flex } else { Display::None };
+    }
+}
+
+/// Refreshes the STATS tab while it's on screen; time played changes every frame
+pub fn update_stats_panel(
+    modal_state: Res<ModalState>,
+    statistics: Option<Res<PlayerStatistics>>,
+    mut value_query: Query<(&StatValueText, &mut Text)>,
+) {
+    let Some(statistics) = statistics else { return; };
+    if !modal_state.visible || modal_state.active_tab != "STATS" {
+        return;
+    }
+    for (value, mut text) in &mut value_query {
+        let totals = if value.lifetime { &statistics.lifetime } else { &statistics.run };
+        let formatted = value.stat.format(totals);
+        if text.0 != formatted {
+            text.0 = formatted;
+        }
+    }
+}
+
+/// Refreshes the ACHIEVEMENTS tab while it's on screen
+pub fn update_achievements_panel(
+    modal_state: Res<ModalState>,
+    achievements: Option<Res<Achievements>>,
+    locale: Res<Locale>,
+    mut summary_query: Query<&mut Text, (With<AchievementsSummaryText>, Without<AchievementProgressText>)>,
+    mut progress_query: Query<(&AchievementProgressText, &mut Text), Without<AchievementsSummaryText>>,
+    mut title_query: Query<(&AchievementTitleText, &mut TextColor)>,
+) {
+    let Some(achievements) = achievements else { return; };
+    if !modal_state.visible || modal_state.active_tab != "ACHIEVEMENTS" {
+        return;
+    }
+
+    let summary = format!(
+        "{} / {} {}",
+        achievements.unlocked().count(),
+        AchievementId::ALL.len(),
+        locale.t("achievements.unlocked"),
+    );
+    for mut text in &mut summary_query {
+        if text.0 != summary {
+            text.0 = summary.clone();
+        }
+    }
+    for (AchievementProgressText(id), mut text) in &mut progress_query {
+        let formatted = if achievements.is_unlocked(*id) {
+            locale.t("achievements.unlocked")
+        } else {
+            format!("{} / {}", achievements.progress(*id), id.target())
+        };
+        if text.0 != formatted {
+            text.0 = formatted;
+        }
+    }
+    for (AchievementTitleText(id), mut color) in &mut title_query {
+        color.0 = if achievements.is_unlocked(*id) { Color::ELYSIUM_GOLD } else { Color::WHITE };
+    }
+}
+
 pub fn update_quest_list(
     mut commands: Commands,
     _objective_manager: Res<ObjectiveManager>,
@@ -344,6 +688,7 @@ pub fn update_quest_list(
     ui_assets: Option<Res<UiAssets>>,
     quest_container_query: Query<Entity, With<QuestEntriesContainer>>,
     existing_quests: Query<Entity, With<QuestEntry>>,
+    locale: Res<Locale>,
 ) {
     let Some(font_assets) = font_assets else { return; };
     let Some(ui_assets) = ui_assets else { return; };
@@ -357,16 +702,16 @@ pub fn update_quest_list(
 
     // Create 10 quests total - use existing objectives plus additional ones
     let quest_titles = [
-        "COLLECT HEALTH POTIONS",
-        "FIND SURVIVAL KITS", 
-        "GATHER ANCIENT BOOKS",
-        "COLLECT GOLDEN COINS",
-        "EXPLORE ANCIENT RUINS",
-        "DEFEAT DARK CREATURES",
-        "RETRIEVE LOST ARTIFACTS",
-        "MASTER THE ELEMENTS",
-        "UNLOCK HIDDEN PASSAGES",
-        "RESTORE THE TEMPLE"
+        "quest.collect_health_potions",
+        "quest.find_survival_kits",
+        "quest.gather_ancient_books",
+        "quest.collect_golden_coins",
+        "quest.explore_ancient_ruins",
+        "quest.defeat_dark_creatures",
+        "quest.retrieve_lost_artifacts",
+        "quest.master_the_elements",
+        "quest.unlock_hidden_passages",
+        "quest.restore_the_temple"
     ];
     
     let quest_descriptions = [
@@ -386,15 +731,22 @@ pub fn update_quest_list(
     for i in 0..10 {
         let quest_objective = crate::systems::objectives::Objective {
             id: i,
-            title: quest_titles[i].to_string(),
+            title: locale.t(quest_titles[i]),
             description: quest_descriptions[i].to_string(),
             item_type: crate::systems::collectibles::CollectibleType::Coin,
             required_count: ((i + 1) * 2) as u32,
             current_count: if i < 2 { ((i + 1) * 2) as u32 } else { 0 }, // First 2 are completed
             completed: i < 2,
+            target_position: None,
+            completion_radius: None,
+            initial_distance: None,
+            required: true,
+            time_limit: None,
+            failed: false,
+            prerequisites: Vec::new(),
         };
         
-        let quest_entity = spawn_quest_entry(&mut commands, &quest_objective, &font_assets, &ui_assets, i);
+        let quest_entity = spawn_quest_entry(&mut commands, &quest_objective, &font_assets, &ui_assets, &locale, i);
         commands.entity(quest_container_entity).add_child(quest_entity);
     }
 }
@@ -404,6 +756,7 @@ fn spawn_quest_entry(
     objective: &crate::systems::objectives::Objective,
     font_assets: &Res<FontAssets>,
     ui_assets: &Res<UiAssets>,
+    locale: &Locale,
     index: usize,
 ) -> Entity {
     let is_active = index < 2; // First two quests are active (lighter background)
@@ -496,7 +849,7 @@ fn spawn_quest_entry(
                 children![
                     // Reward label
                     (
-                        Text::new("REWARD"),
+                        Text::new(locale.t("modal.reward")),
                         TextFont {
                             font: font_assets.rajdhani_medium.clone(),
                             font_size: 18.0, // Scaled up by 1.5x from 12
@@ -535,7 +888,7 @@ fn spawn_quest_entry(
                             ),
                             // Reward amount
                             (
-                                Text::new(format!("{} Gold", (objective.id + 1) * 250)),
+                                Text::new(format!("{} {}", (objective.id + 1) * 250, locale.t("modal.gold"))),
                                 TextFont {
                                     font: font_assets.rajdhani_medium.clone(),
                                     font_size: 24.0, // Scaled up by 1.5x from 16
@@ -559,12 +912,7 @@ pub fn toggle_modal_visibility(
     // Handle Escape key - close modal if open, otherwise let the global handler deal with it
     if keyboard.just_pressed(KeyCode::Escape) {
         if modal_state.visible {
-            // Close the modal
-            modal_state.visible = false;
-            
-            for mut visibility in &mut background_query {
-                *visibility = Visibility::Hidden;
-            }
+            close_modal(&mut modal_state, &mut background_query);
         }
         // If modal is not visible, let the global ESC handler (ReturnToMainMenu) take over
     }
@@ -616,6 +964,246 @@ pub fn despawn_modal(mut commands: Commands, query: Query<Entity, With<ModalBack
     }
 }
 
+// ===== CONFIRM DIALOG =====
+
+/// What happens when the player answers Yes to a confirm dialog
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfirmAction {
+    ReturnToMainMenu,
+    RestartLevel,
+    QuitGame,
+}
+
+/// Triggered when a confirm dialog is answered Yes; whoever owns the action observes it
+#[derive(Event, Debug)]
+pub struct ConfirmDialogAccepted(pub ConfirmAction);
+
+/// The open confirm dialog, if any. `opened_frame` keeps the key press that opened
+/// the dialog from also dismissing it on the same frame.
+#[derive(Resource, Default)]
+pub struct ConfirmDialogState {
+    pub pending: Option<ConfirmAction>,
+    pub opened_frame: u32,
+}
+
+impl ConfirmDialogState {
+    pub fn is_open(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+#[derive(Component)]
+pub struct ConfirmDialog;
+
+#[derive(Component, Clone, Copy)]
+enum ConfirmChoice {
+    Yes,
+    No,
+}
+
+/// Opens a Yes/No dialog over everything else; `action` runs only on Yes
+pub fn spawn_confirm_dialog(
+    commands: &mut Commands,
+    font_assets: &Res<FontAssets>,
+    state: &mut ConfirmDialogState,
+    frame: u32,
+    message: &str,
+    action: ConfirmAction,
+) {
+    if state.is_open() {
+        return;
+    }
+    state.pending = Some(action);
+    state.opened_frame = frame;
+
+    let font = font_assets.rajdhani_bold.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            GlobalZIndex(200), // Above the objectives modal and the HUD
+            Name::new("Confirm Dialog"),
+            ConfirmDialog,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(720.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(36.0),
+                        padding: UiRect::all(Val::Px(48.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.10, 0.14, 0.95)),
+                    BorderColor(Color::ELYSIUM_GOLD),
+                    BorderRadius::all(Val::Px(12.0)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(message),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                    ));
+
+                    panel
+                        .spawn(Node {
+                            column_gap: Val::Px(32.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            for (choice, label) in [(ConfirmChoice::Yes, "YES"), (ConfirmChoice::No, "NO")] {
+                                buttons
+                                    .spawn((
+                                        Node {
+                                            width: Val::Px(180.0),
+                                            height: Val::Px(60.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            border: UiRect::all(Val::Px(2.0)),
+                                            ..default()
+                                        },
+                                        Button,
+                                        BackgroundColor(Color::DARK_GLASS),
+                                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                                        BorderRadius::all(Val::Px(8.0)),
+                                        choice,
+                                        children![(
+                                            Text::new(label),
+                                            TextFont {
+                                                font: font.clone(),
+                                                font_size: 28.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::ELYSIUM_GOLD),
+                                            Pickable::IGNORE,
+                                        )],
+                                    ))
+                                    .observe(confirm_choice_clicked);
+                            }
+                        });
+                });
+        });
+}
+
+fn confirm_choice_clicked(
+    trigger: Trigger<Pointer<Click>>,
+    choice_query: Query<&ConfirmChoice>,
+    mut commands: Commands,
+    mut state: ResMut<ConfirmDialogState>,
+    dialog_query: Query<Entity, With<ConfirmDialog>>,
+) {
+    if let Ok(choice) = choice_query.get(trigger.target()) {
+        resolve_confirm_dialog(*choice, &mut commands, &mut state, &dialog_query);
+    }
+}
+
+/// Enter answers Yes and Escape answers No, once the opening frame has passed
+fn confirm_dialog_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    frame: Res<bevy::diagnostic::FrameCount>,
+    mut commands: Commands,
+    mut state: ResMut<ConfirmDialogState>,
+    dialog_query: Query<Entity, With<ConfirmDialog>>,
+) {
+    if !state.is_open() || frame.0 == state.opened_frame {
+        return;
+    }
+    let choice = if keyboard.just_pressed(KeyCode::Enter) {
+        ConfirmChoice::Yes
+    } else if keyboard.just_pressed(KeyCode::Escape) {
+        ConfirmChoice::No
+    } else {
+        return;
+    };
+    resolve_confirm_dialog(choice, &mut commands, &mut state, &dialog_query);
+}
+
+fn resolve_confirm_dialog(
+    choice: ConfirmChoice,
+    commands: &mut Commands,
+    state: &mut ConfirmDialogState,
+    dialog_query: &Query<Entity, With<ConfirmDialog>>,
+) {
+    let Some(action) = state.pending.take() else {
+        return;
+    };
+    for entity in dialog_query {
+        commands.entity(entity).despawn();
+    }
+
+    if let ConfirmChoice::Yes = choice {
+        commands.trigger(ConfirmDialogAccepted(action));
+    }
+}
+
+fn return_to_main_menu_confirmed(
+    trigger: Trigger<ConfirmDialogAccepted>,
+    mut next_state: ResMut<NextState<crate::screens::Screen>>,
+) {
+    if trigger.0 == ConfirmAction::ReturnToMainMenu {
+        next_state.set(crate::screens::Screen::MainMenu);
+    }
+}
+
+/// "RESTART LEVEL" in the modal; asks first since all level progress is lost
+fn restart_level_clicked(
+    _: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    mut state: ResMut<ConfirmDialogState>,
+    frame: Res<bevy::diagnostic::FrameCount>,
+) {
+    spawn_confirm_dialog(
+        &mut commands,
+        &font_assets,
+        &mut state,
+        frame.0,
+        "Restart the level?\nAll progress in this level will be lost.",
+        ConfirmAction::RestartLevel,
+    );
+}
+
+/// Hides the modal, e.g. once a restart has been confirmed from it
+pub fn close_modal(
+    modal_state: &mut ModalState,
+    background_query: &mut Query<&mut Visibility, With<ModalBackground>>,
+) {
+    modal_state.visible = false;
+    for mut visibility in background_query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+/// Closes any open confirm dialog without running its action, e.g. when the screen changes
+impl crate::screens::SceneState for ConfirmDialogState {
+    fn reset_on_teardown(&mut self) {
+        self.pending = None;
+    }
+}
+
+impl crate::screens::SceneState for ModalState {
+    fn reset_on_teardown(&mut self) {
+        self.visible = false;
+        // The modal is rebuilt on the quests tab
+        self.active_tab = "QUESTS".to_string();
+    }
+}
+
 // ===== MODAL PLUGIN =====
 
 pub struct ModalPlugin;
@@ -623,11 +1211,17 @@ pub struct ModalPlugin;
 impl Plugin for ModalPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ModalState>()
+            .init_resource::<ConfirmDialogState>()
+            .add_observer(return_to_main_menu_confirmed)
             .add_systems(Update, (
                 toggle_modal_visibility,
                 handle_view_more_click,
                 update_quest_list,
+                update_modal_tabs,
+                update_stats_panel,
+                update_achievements_panel,
                 update_scroll_position,
+                confirm_dialog_keyboard,
             ));
     }
 } 
\ No newline at end of file
@@ -0,0 +1,369 @@
+//! Debug panel for tuning character movement live. Toggled with F6; every slider
+//! writes straight into [`MovementTuning`], which the controller systems read.
+
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::screens::Screen;
+use crate::screens::gameplay::PlayingScene;
+use crate::systems::character_controller::MovementTuning;
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Whether the tuning panel is open; closed by default
+#[derive(Resource, Default)]
+pub struct MovementTuningPanelSettings {
+    pub visible: bool,
+}
+
+/// One tunable value of [`MovementTuning`] and the range its slider covers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TuningParam {
+    MaxSpeed,
+    MaxRunSpeed,
+    RotationSpeed,
+    Acceleration,
+    Deceleration,
+    AirResistance,
+    JumpImpulse,
+    CoyoteTime,
+    JumpCutMultiplier,
+    GravityScale,
+}
+
+impl TuningParam {
+    const ALL: [TuningParam; 10] = [
+        TuningParam::MaxSpeed,
+        TuningParam::MaxRunSpeed,
+        TuningParam::RotationSpeed,
+        TuningParam::Acceleration,
+        TuningParam::Deceleration,
+        TuningParam::AirResistance,
+        TuningParam::JumpImpulse,
+        TuningParam::CoyoteTime,
+        TuningParam::JumpCutMultiplier,
+        TuningParam::GravityScale,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            TuningParam::MaxSpeed => "WALK SPEED",
+            TuningParam::MaxRunSpeed => "RUN SPEED",
+            TuningParam::RotationSpeed => "TURN SPEED",
+            TuningParam::Acceleration => "ACCELERATION",
+            TuningParam::Deceleration => "DECELERATION",
+            TuningParam::AirResistance => "AIR RESISTANCE",
+            TuningParam::JumpImpulse => "JUMP IMPULSE",
+            TuningParam::CoyoteTime => "COYOTE TIME",
+            TuningParam::JumpCutMultiplier => "JUMP CUT",
+            TuningParam::GravityScale => "GRAVITY",
+        }
+    }
+
+    fn range(self) -> (f32, f32) {
+        match self {
+            TuningParam::MaxSpeed => (1.0, 15.0),
+            TuningParam::MaxRunSpeed => (5.0, 30.0),
+            TuningParam::RotationSpeed => (1.0, 15.0),
+            TuningParam::Acceleration => (5.0, 100.0),
+            TuningParam::Deceleration => (5.0, 100.0),
+            TuningParam::AirResistance => (0.8, 1.0),
+            TuningParam::JumpImpulse => (2.0, 20.0),
+            TuningParam::CoyoteTime => (0.0, 0.5),
+            TuningParam::JumpCutMultiplier => (0.1, 1.0),
+            TuningParam::GravityScale => (0.25, 3.0),
+        }
+    }
+
+    fn get(self, tuning: &MovementTuning) -> f32 {
+        match self {
+            TuningParam::MaxSpeed => tuning.max_speed,
+            TuningParam::MaxRunSpeed => tuning.max_run_speed,
+            TuningParam::RotationSpeed => tuning.rotation_speed,
+            TuningParam::Acceleration => tuning.acceleration,
+            TuningParam::Deceleration => tuning.deceleration,
+            TuningParam::AirResistance => tuning.air_resistance,
+            TuningParam::JumpImpulse => tuning.jump_impulse,
+            TuningParam::CoyoteTime => tuning.coyote_time,
+            TuningParam::JumpCutMultiplier => tuning.jump_cut_multiplier,
+            TuningParam::GravityScale => tuning.gravity_scale,
+        }
+    }
+
+    fn set(self, tuning: &mut MovementTuning, value: f32) {
+        let (min, max) = self.range();
+        let value = value.clamp(min, max);
+        match self {
+            TuningParam::MaxSpeed => tuning.max_speed = value,
+            TuningParam::MaxRunSpeed => tuning.max_run_speed = value,
+            TuningParam::RotationSpeed => tuning.rotation_speed = value,
+            TuningParam::Acceleration => tuning.acceleration = value,
+            TuningParam::Deceleration => tuning.deceleration = value,
+            TuningParam::AirResistance => tuning.air_resistance = value,
+            TuningParam::JumpImpulse => tuning.jump_impulse = value,
+            TuningParam::CoyoteTime => tuning.coyote_time = value,
+            TuningParam::JumpCutMultiplier => tuning.jump_cut_multiplier = value,
+            TuningParam::GravityScale => tuning.gravity_scale = value,
+        }
+    }
+
+    /// Where the value sits in its range, from 0 to 1
+    fn fraction(self, tuning: &MovementTuning) -> f32 {
+        let (min, max) = self.range();
+        ((self.get(tuning) - min) / (max - min)).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Component)]
+struct MovementTuningPanel;
+
+/// Clickable, draggable track of a tuning slider
+#[derive(Component)]
+struct TuningSlider(TuningParam);
+
+#[derive(Component)]
+struct TuningSliderFill(TuningParam);
+
+#[derive(Component)]
+struct TuningValueText(TuningParam);
+
+// ===== PLUGIN =====
+
+pub struct MovementTuningPanelPlugin;
+
+impl Plugin for MovementTuningPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MovementTuningPanelSettings>().add_systems(
+            Update,
+            (toggle_movement_tuning_panel, update_tuning_widgets)
+                .chain()
+                .run_if(in_state(Screen::GamePlay)),
+        );
+    }
+}
+
+// ===== SYSTEMS =====
+
+/// Spawns or despawns the panel to match the settings, also after re-entering gameplay
+fn toggle_movement_tuning_panel(
+    mut commands: Commands,
+    settings: Res<MovementTuningPanelSettings>,
+    font_assets: Res<FontAssets>,
+    tuning: Res<MovementTuning>,
+    panel_query: Query<Entity, With<MovementTuningPanel>>,
+) {
+    match (settings.visible, panel_query.iter().next()) {
+        (true, None) => spawn_movement_tuning_panel(&mut commands, &font_assets, &tuning),
+        (false, Some(panel)) => commands.entity(panel).despawn(),
+        _ => {}
+    }
+}
+
+fn spawn_movement_tuning_panel(
+    commands: &mut Commands,
+    font_assets: &FontAssets,
+    tuning: &MovementTuning,
+) {
+    let font = font_assets.rajdhani_medium.clone();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(32.0),
+                top: Val::Px(220.0),
+                width: Val::Px(380.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(14.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::DARK_GLASS),
+            BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+            BorderRadius::all(Val::Px(12.0)),
+            GlobalZIndex(120),
+            Name::new("Movement Tuning Panel"),
+            MovementTuningPanel,
+            PlayingScene,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new("MOVEMENT TUNING (F6)"),
+                TextFont {
+                    font: font_assets.rajdhani_bold.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::ELYSIUM_GOLD),
+            ));
+
+            for param in TuningParam::ALL {
+                spawn_tuning_row(panel, font.clone(), param, tuning);
+            }
+
+            panel
+                .spawn((
+                    Node {
+                        align_self: AlignSelf::FlexEnd,
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                        margin: UiRect::top(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::LIGHT_GLASS),
+                    BorderRadius::all(Val::Px(6.0)),
+                    Name::new("Reset Movement Tuning"),
+                    children![(
+                        Text::new("RESET"),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        Pickable::IGNORE,
+                    )],
+                ))
+                .observe(reset_movement_tuning);
+        });
+}
+
+fn spawn_tuning_row(
+    panel: &mut ChildSpawnerCommands,
+    font: Handle<Font>,
+    param: TuningParam,
+    tuning: &MovementTuning,
+) {
+    let text_font = TextFont {
+        font,
+        font_size: 16.0,
+        ..default()
+    };
+
+    panel
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(22.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(120.0),
+                    ..default()
+                },
+                Text::new(param.label()),
+                text_font.clone(),
+                TextColor(Color::WHITE.with_alpha(0.8)),
+            ));
+
+            row.spawn((
+                Node {
+                    flex_grow: 1.0,
+                    height: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(Color::DARKER_GLASS),
+                BorderRadius::MAX,
+                TuningSlider(param),
+                children![(
+                    Node {
+                        width: Val::Percent(param.fraction(tuning) * 100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::ELYSIUM_GOLD),
+                    BorderRadius::MAX,
+                    Pickable::IGNORE,
+                    TuningSliderFill(param),
+                )],
+            ))
+            .observe(tuning_slider_pressed)
+            .observe(tuning_slider_dragged);
+
+            row.spawn((
+                Node {
+                    width: Val::Px(48.0),
+                    justify_content: JustifyContent::FlexEnd,
+                    ..default()
+                },
+                Text::new(tuning_value_label(param.get(tuning))),
+                text_font,
+                TextColor(Color::ELYSIUM_GOLD),
+                TuningValueText(param),
+            ));
+        });
+}
+
+fn tuning_value_label(value: f32) -> String {
+    format!("{:.2}", value)
+}
+
+fn tuning_slider_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    slider_query: Query<(&TuningSlider, &ComputedNode, &GlobalTransform)>,
+    tuning: ResMut<MovementTuning>,
+    ui_scale: Res<UiScale>,
+) {
+    let pointer = trigger.pointer_location.position / ui_scale.0;
+    set_tuning_from_pointer(trigger.target(), pointer, slider_query, tuning);
+}
+
+fn tuning_slider_dragged(
+    trigger: Trigger<Pointer<Drag>>,
+    slider_query: Query<(&TuningSlider, &ComputedNode, &GlobalTransform)>,
+    tuning: ResMut<MovementTuning>,
+    ui_scale: Res<UiScale>,
+) {
+    let pointer = trigger.pointer_location.position / ui_scale.0;
+    set_tuning_from_pointer(trigger.target(), pointer, slider_query, tuning);
+}
+
+fn set_tuning_from_pointer(
+    slider: Entity,
+    pointer: Vec2,
+    slider_query: Query<(&TuningSlider, &ComputedNode, &GlobalTransform)>,
+    mut tuning: ResMut<MovementTuning>,
+) {
+    let Ok((slider, node, transform)) = slider_query.get(slider) else {
+        return;
+    };
+
+    // Node layout is in physical pixels; `pointer` is already in UI pixels
+    let width = node.size().x * node.inverse_scale_factor();
+    if width <= 0.0 {
+        return;
+    }
+    let left = transform.translation().x * node.inverse_scale_factor() - width / 2.0;
+    let fraction = ((pointer.x - left) / width).clamp(0.0, 1.0);
+    let (min, max) = slider.0.range();
+    let value = min + (max - min) * fraction;
+
+    if slider.0.get(&tuning) != value {
+        slider.0.set(&mut tuning, value);
+    }
+}
+
+fn reset_movement_tuning(_: Trigger<Pointer<Click>>, mut tuning: ResMut<MovementTuning>) {
+    *tuning = MovementTuning::default();
+}
+
+/// Keeps slider fills and value texts in step with `MovementTuning`
+fn update_tuning_widgets(
+    tuning: Res<MovementTuning>,
+    mut fill_query: Query<(&TuningSliderFill, &mut Node)>,
+    mut text_query: Query<(&TuningValueText, &mut Text)>,
+) {
+    if !tuning.is_changed() {
+        return;
+    }
+    for (fill, mut node) in fill_query.iter_mut() {
+        node.width = Val::Percent(fill.0.fraction(&tuning) * 100.0);
+    }
+    for (value_text, mut text) in text_query.iter_mut() {
+        text.0 = tuning_value_label(value_text.0.get(&tuning));
+    }
+}
@@ -65,3 +65,149 @@ impl ElysiumDescentColorPalette for Color {
     const ELYSIUM_DESCENT_YELLOW: Color = Self::ELYSIUM_GOLD;
     const ELYSIUM_DESCENT_BLUE: Color = Self::ELYSIUM_BLUE;
 }
+
+// ===== SWAPPABLE PALETTE =====
+
+/// The colors that carry meaning (done vs failed, health vs xp, which tab is active), kept
+/// apart from the fixed brand colors so they can be swapped for colorblind-friendly sets
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct UiPalette {
+    pub accent: Color,
+    pub success: Color,
+    pub danger: Color,
+    pub health: Color,
+    pub health_dark: Color,
+    pub xp: Color,
+    pub xp_dark: Color,
+}
+
+impl UiPalette {
+    pub const DEFAULT: Self = Self {
+        accent: Color::ELYSIUM_GOLD,
+        success: Color::SUCCESS_GREEN,
+        danger: Color::DANGER_RED,
+        health: Color::HEALTH_GREEN,
+        health_dark: Color::HEALTH_GREEN_DARK,
+        xp: Color::XP_PURPLE,
+        xp_dark: Color::XP_PURPLE_DARK,
+    };
+
+    // The sets below are built from the Okabe-Ito colors, which stay distinct under each deficiency
+
+    /// Red-weak: green and red move onto the blue/orange axis
+    pub const PROTANOPIA: Self = Self {
+        accent: Color::ELYSIUM_GOLD,
+        success: Color::srgba(0.337, 0.706, 0.914, 1.0), // Sky blue
+        danger: Color::srgba(0.835, 0.369, 0.0, 1.0), // Vermillion
+        health: Color::srgba(0.337, 0.706, 0.914, 1.0),
+        health_dark: Color::srgba(0.0, 0.447, 0.698, 1.0), // Blue
+        xp: Color::srgba(0.941, 0.894, 0.259, 1.0), // Yellow
+        xp_dark: Color::srgba(0.659, 0.626, 0.181, 1.0),
+    };
+
+    /// Green-weak: like protanopia, but with a brighter danger color and purple kept for xp
+    pub const DEUTERANOPIA: Self = Self {
+        accent: Color::ELYSIUM_GOLD,
+        success: Color::srgba(0.0, 0.447, 0.698, 1.0), // Blue
+        danger: Color::srgba(0.902, 0.624, 0.0, 1.0), // Orange
+        health: Color::srgba(0.337, 0.706, 0.914, 1.0), // Sky blue
+        health_dark: Color::srgba(0.0, 0.447, 0.698, 1.0),
+        xp: Color::srgba(0.8, 0.475, 0.655, 1.0), // Reddish purple
+        xp_dark: Color::srgba(0.56, 0.333, 0.459, 1.0),
+    };
+
+    /// Blue-weak: blue/green and yellow/violet collapse, so lean on red against teal
+    pub const TRITANOPIA: Self = Self {
+        accent: Color::srgba(0.902, 0.624, 0.0, 1.0), // Orange
+        success: Color::srgba(0.0, 0.620, 0.451, 1.0), // Bluish green
+        danger: Color::srgba(0.835, 0.369, 0.0, 1.0), // Vermillion
+        health: Color::srgba(0.0, 0.620, 0.451, 1.0),
+        health_dark: Color::srgba(0.0, 0.434, 0.316, 1.0),
+        xp: Color::srgba(0.8, 0.475, 0.655, 1.0), // Reddish purple
+        xp_dark: Color::srgba(0.56, 0.333, 0.459, 1.0),
+    };
+
+    pub fn color(&self, role: PaletteRole) -> Color {
+        match role {
+            PaletteRole::Accent => self.accent,
+            PaletteRole::Success => self.success,
+            PaletteRole::Danger => self.danger,
+            PaletteRole::Health => self.health,
+            PaletteRole::HealthDark => self.health_dark,
+            PaletteRole::Xp => self.xp,
+            PaletteRole::XpDark => self.xp_dark,
+        }
+    }
+
+    /// `role`'s color at `current`'s alpha, so translucent borders stay translucent
+    fn tint(&self, role: PaletteRole, current: Color) -> Color {
+        self.color(role).with_alpha(current.alpha())
+    }
+}
+
+impl Default for UiPalette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteRole {
+    Accent,
+    Success,
+    Danger,
+    Health,
+    HealthDark,
+    Xp,
+    XpDark,
+}
+
+/// Repaints the node's `BackgroundColor` from the palette, on spawn and whenever it changes
+#[derive(Component, Clone, Copy)]
+pub struct PaletteBackground(pub PaletteRole);
+
+/// Same as `PaletteBackground`, for `BorderColor`
+#[derive(Component, Clone, Copy)]
+pub struct PaletteBorder(pub PaletteRole);
+
+/// Same as `PaletteBackground`, for `TextColor`
+#[derive(Component, Clone, Copy)]
+pub struct PaletteText(pub PaletteRole);
+
+pub struct PalettePlugin;
+
+impl Plugin for PalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiPalette>().add_systems(
+            PostUpdate,
+            (paint_backgrounds, paint_borders, paint_text),
+        );
+    }
+}
+
+fn paint_backgrounds(
+    palette: Res<UiPalette>,
+    mut query: Query<(Ref<PaletteBackground>, &mut BackgroundColor)>,
+) {
+    for (role, mut color) in &mut query {
+        if palette.is_changed() || role.is_added() {
+            color.0 = palette.tint(role.0, color.0);
+        }
+    }
+}
+
+fn paint_borders(palette: Res<UiPalette>, mut query: Query<(Ref<PaletteBorder>, &mut BorderColor)>) {
+    for (role, mut color) in &mut query {
+        if palette.is_changed() || role.is_added() {
+            color.0 = palette.tint(role.0, color.0);
+        }
+    }
+}
+
+fn paint_text(palette: Res<UiPalette>, mut query: Query<(Ref<PaletteText>, &mut TextColor)>) {
+    for (role, mut color) in &mut query {
+        if palette.is_changed() || role.is_added() {
+            color.0 = palette.tint(role.0, color.0);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::constants::hud::TOAST_SECS;
+use crate::resources::audio::{PlaySfxEvent, SfxType};
+use crate::screens::gameplay::PlayingScene;
+
+// ===== COMPONENTS =====
+
+/// Short on-screen notification, e.g. a submitted score or an unlocked achievement
+#[derive(Component)]
+pub struct Toast {
+    pub timer: Timer,
+}
+
+// ===== PLUGIN =====
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (play_toast_sfx, update_toasts));
+    }
+}
+
+/// Shows `message` at the top of the screen, replacing any toast already up
+pub fn spawn_toast(
+    commands: &mut Commands,
+    font_assets: &FontAssets,
+    existing_toasts: &Query<Entity, With<Toast>>,
+    message: impl Into<String>,
+    color: Color,
+) {
+    for entity in existing_toasts {
+        commands.entity(entity).despawn();
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(24.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+        Name::new("Toast"),
+        Toast {
+            timer: Timer::from_seconds(TOAST_SECS, TimerMode::Once),
+        },
+        PlayingScene,
+        children![(
+            Text::new(message),
+            TextFont {
+                font: font_assets.rajdhani_bold.clone(),
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(color),
+        )],
+    ));
+}
+
+// ===== SYSTEMS =====
+
+/// Chimes on the UI channel whenever a toast appears
+fn play_toast_sfx(toast_query: Query<(), Added<Toast>>, mut sfx_events: EventWriter<PlaySfxEvent>) {
+    if !toast_query.is_empty() {
+        sfx_events.write(PlaySfxEvent { sfx_type: SfxType::UiNotify });
+    }
+}
+
+fn update_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut Toast)>,
+    time: Res<Time<Real>>,
+) {
+    for (entity, mut toast) in &mut toast_query {
+        if toast.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
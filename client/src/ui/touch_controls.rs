@@ -0,0 +1,326 @@
+use bevy::input::touch::TouchInput;
+use bevy::prelude::*;
+
+use crate::assets::FontAssets;
+use crate::constants::touch_controls::TouchControlsConfig;
+use crate::resources::platform::PlatformCapabilities;
+use crate::systems::character_controller::{LastInputDirection, MovementAction};
+use crate::ui::styles::ElysiumDescentColorPalette;
+
+// ===== COMPONENTS & RESOURCES =====
+
+/// Current state of the on-screen joystick, read alongside the keyboard as movement input
+#[derive(Resource, Default)]
+pub struct VirtualJoystick {
+    /// Knob offset from the joystick center in logical pixels, screen space (y down)
+    pub offset: Vec2,
+    /// Whether a touch is currently holding the joystick
+    pub held: bool,
+}
+
+impl VirtualJoystick {
+    pub fn is_active(&self) -> bool {
+        self.held && self.offset != Vec2::ZERO
+    }
+
+    /// Normalized move direction with +y forward, matching the keyboard `Move` binding
+    pub fn direction(&self) -> Vec2 {
+        Vec2::new(self.offset.x, -self.offset.y) / TouchControlsConfig::JOYSTICK_RADIUS
+    }
+
+    fn release(&mut self) {
+        self.offset = Vec2::ZERO;
+        self.held = false;
+    }
+}
+
+/// Root of every touch control; hidden until the platform reports touch input
+#[derive(Component)]
+pub struct TouchControl;
+
+#[derive(Component)]
+pub struct JoystickBase;
+
+#[derive(Component)]
+pub struct JoystickKnob;
+
+/// An on-screen button that sends a movement action when tapped
+#[derive(Component, Clone, Copy)]
+pub enum TouchButton {
+    Jump,
+    FightMove1,
+    FightMove2,
+}
+
+impl TouchButton {
+    fn label(self) -> &'static str {
+        match self {
+            TouchButton::Jump => "JUMP",
+            TouchButton::FightMove1 => "ATK 1",
+            TouchButton::FightMove2 => "ATK 2",
+        }
+    }
+
+    fn action(self) -> MovementAction {
+        match self {
+            TouchButton::Jump => MovementAction::Jump,
+            TouchButton::FightMove1 => MovementAction::FightMove1,
+            TouchButton::FightMove2 => MovementAction::FightMove2,
+        }
+    }
+}
+
+// ===== PLUGIN =====
+
+pub struct TouchControlsPlugin;
+
+impl Plugin for TouchControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VirtualJoystick>().add_systems(
+            Update,
+            (
+                detect_touch_input,
+                update_touch_controls_visibility,
+                update_joystick_knob,
+                send_joystick_movement,
+            ),
+        );
+    }
+}
+
+// ===== SPAWNING =====
+
+/// Spawns the joystick (bottom left) and action buttons (bottom right), hidden until touch is detected
+pub fn spawn_touch_controls(
+    commands: &mut Commands,
+    font_assets: &Res<FontAssets>,
+    hud_marker: impl Component + Clone,
+) {
+    let base_size = TouchControlsConfig::JOYSTICK_BASE_SIZE;
+    let knob_size = TouchControlsConfig::JOYSTICK_KNOB_SIZE;
+    let knob_rest = (base_size - knob_size) / 2.0;
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(TouchControlsConfig::SCREEN_MARGIN),
+                bottom: Val::Px(TouchControlsConfig::SCREEN_MARGIN),
+                width: Val::Px(base_size),
+                height: Val::Px(base_size),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(Color::DARK_GLASS),
+            BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+            BorderRadius::MAX,
+            Visibility::Hidden,
+            Name::new("Touch Joystick"),
+            TouchControl,
+            JoystickBase,
+            hud_marker.clone(),
+            children![(
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(knob_rest),
+                    top: Val::Px(knob_rest),
+                    width: Val::Px(knob_size),
+                    height: Val::Px(knob_size),
+                    ..default()
+                },
+                BackgroundColor(Color::ELYSIUM_GOLD.with_alpha(0.8)),
+                BorderRadius::MAX,
+                Pickable::IGNORE,
+                JoystickKnob,
+            )],
+        ))
+        .observe(joystick_pressed)
+        .observe(joystick_dragged)
+        .observe(joystick_released)
+        .observe(joystick_drag_ended);
+
+    let font = font_assets.rajdhani_bold.clone();
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(TouchControlsConfig::SCREEN_MARGIN),
+                bottom: Val::Px(TouchControlsConfig::SCREEN_MARGIN),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::FlexEnd,
+                column_gap: Val::Px(TouchControlsConfig::BUTTON_SPACING),
+                ..default()
+            },
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            Name::new("Touch Buttons"),
+            TouchControl,
+            hud_marker,
+        ))
+        .with_children(|parent| {
+            for button in [TouchButton::FightMove2, TouchButton::FightMove1, TouchButton::Jump] {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(TouchControlsConfig::BUTTON_SIZE),
+                            height: Val::Px(TouchControlsConfig::BUTTON_SIZE),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::DARK_GLASS),
+                        BorderColor(Color::ELYSIUM_GOLD.with_alpha(0.6)),
+                        BorderRadius::MAX,
+                        button,
+                        children![(
+                            Text::new(button.label()),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            Pickable::IGNORE,
+                        )],
+                    ))
+                    .observe(touch_button_pressed);
+            }
+        });
+}
+
+// ===== OBSERVERS =====
+// Only touch pointers drive these, so a mouse clicking through the HUD is never mistaken for a touch
+
+fn joystick_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    base_query: Query<(&ComputedNode, &GlobalTransform), With<JoystickBase>>,
+    mut joystick: ResMut<VirtualJoystick>,
+    ui_scale: Res<UiScale>,
+) {
+    if !trigger.pointer_id.is_touch() {
+        return;
+    }
+    joystick.held = true;
+    set_joystick_from_pointer(
+        trigger.target(),
+        trigger.pointer_location.position / ui_scale.0,
+        base_query,
+        &mut joystick,
+    );
+}
+
+fn joystick_dragged(
+    trigger: Trigger<Pointer<Drag>>,
+    base_query: Query<(&ComputedNode, &GlobalTransform), With<JoystickBase>>,
+    mut joystick: ResMut<VirtualJoystick>,
+    ui_scale: Res<UiScale>,
+) {
+    if !trigger.pointer_id.is_touch() {
+        return;
+    }
+    set_joystick_from_pointer(
+        trigger.target(),
+        trigger.pointer_location.position / ui_scale.0,
+        base_query,
+        &mut joystick,
+    );
+}
+
+fn joystick_released(trigger: Trigger<Pointer<Released>>, mut joystick: ResMut<VirtualJoystick>) {
+    if trigger.pointer_id.is_touch() {
+        joystick.release();
+    }
+}
+
+fn joystick_drag_ended(trigger: Trigger<Pointer<DragEnd>>, mut joystick: ResMut<VirtualJoystick>) {
+    if trigger.pointer_id.is_touch() {
+        joystick.release();
+    }
+}
+
+fn set_joystick_from_pointer(
+    base: Entity,
+    pointer: Vec2,
+    base_query: Query<(&ComputedNode, &GlobalTransform), With<JoystickBase>>,
+    joystick: &mut VirtualJoystick,
+) {
+    let Ok((node, transform)) = base_query.get(base) else {
+        return;
+    };
+
+    // Node layout is in physical pixels; `pointer` is already in UI pixels
+    let center = transform.translation().truncate() * node.inverse_scale_factor();
+    joystick.offset = (pointer - center).clamp_length_max(TouchControlsConfig::JOYSTICK_RADIUS);
+}
+
+fn touch_button_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    button_query: Query<&TouchButton>,
+    mut movement_events: EventWriter<MovementAction>,
+) {
+    if !trigger.pointer_id.is_touch() {
+        return;
+    }
+    if let Ok(button) = button_query.get(trigger.target()) {
+        movement_events.write(button.action());
+    }
+}
+
+// ===== SYSTEMS =====
+
+fn detect_touch_input(
+    mut touch_events: EventReader<TouchInput>,
+    mut capabilities: ResMut<PlatformCapabilities>,
+) {
+    if touch_events.read().next().is_some() && !capabilities.has_touch {
+        info!("Touch input detected, enabling touch controls");
+        capabilities.has_touch = true;
+    }
+}
+
+fn update_touch_controls_visibility(
+    capabilities: Res<PlatformCapabilities>,
+    mut controls: Query<&mut Visibility, With<TouchControl>>,
+) {
+    let target = if capabilities.has_touch {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in &mut controls {
+        visibility.set_if_neq(target);
+    }
+}
+
+fn update_joystick_knob(
+    joystick: Res<VirtualJoystick>,
+    mut knob_query: Query<&mut Node, With<JoystickKnob>>,
+) {
+    if !joystick.is_changed() {
+        return;
+    }
+    let knob_rest =
+        (TouchControlsConfig::JOYSTICK_BASE_SIZE - TouchControlsConfig::JOYSTICK_KNOB_SIZE) / 2.0;
+    for mut node in &mut knob_query {
+        node.left = Val::Px(knob_rest + joystick.offset.x);
+        node.top = Val::Px(knob_rest + joystick.offset.y);
+    }
+}
+
+/// Feeds the joystick into the same `MovementAction::Move` events the keyboard binding sends
+fn send_joystick_movement(
+    joystick: Res<VirtualJoystick>,
+    mut movement_events: EventWriter<MovementAction>,
+    mut last_input: ResMut<LastInputDirection>,
+) {
+    if !joystick.is_active() {
+        return;
+    }
+    let direction = joystick.direction();
+    movement_events.write(MovementAction::Move(avian3d::math::Vector2::new(
+        direction.x,
+        direction.y,
+    )));
+    last_input.0 = direction;
+}
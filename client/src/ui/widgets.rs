@@ -1,4 +1,8 @@
-use crate::ui::styles::ElysiumDescentColorPalette;
+use crate::audio::AudioSettings;
+use crate::constants::hud::ProgressBarConfig;
+use crate::ui::styles::{
+    ElysiumDescentColorPalette, PaletteBackground, PaletteBorder, PaletteRole, PaletteText,
+};
 use bevy::ecs::relationship::{RelatedSpawnerCommands, Relationship};
 use bevy::ecs::system::IntoObserverSystem;
 use bevy::prelude::*;
@@ -30,10 +34,65 @@ pub fn label_widget(
     )
 }
 
+/// Which `AudioSettings` volume a settings row controls
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VolumeChannel {
+    Master,
+    Music,
+    Sfx,
+}
+
+impl VolumeChannel {
+    pub fn get(self, settings: &AudioSettings) -> f64 {
+        match self {
+            VolumeChannel::Master => settings.master_volume,
+            VolumeChannel::Music => settings.music_volume,
+            VolumeChannel::Sfx => settings.sfx_volume,
+        }
+    }
+
+    pub fn set(self, settings: &mut AudioSettings, value: f64) {
+        let value = value.clamp(0.0, 1.0);
+        match self {
+            VolumeChannel::Master => settings.master_volume = value,
+            VolumeChannel::Music => settings.music_volume = value,
+            VolumeChannel::Sfx => settings.sfx_volume = value,
+        }
+    }
+}
+
+/// The 0-10 number shown next to a volume row
+pub fn volume_label(value: f64) -> String {
+    ((value * 10.0).floor() as i32).to_string()
+}
+
+/// Label of the mute-all button, flipped between "MUTE ALL" and "UNMUTE ALL"
+#[derive(Component)]
+pub struct MuteToggleLabel;
+
+pub fn mute_label(muted: bool) -> &'static str {
+    if muted { "UNMUTE ALL" } else { "MUTE ALL" }
+}
+
+/// Text showing a channel's current volume
+#[derive(Component)]
+pub struct VolumeDisplay(pub VolumeChannel);
+
+/// Clickable, draggable track of a volume slider
+#[derive(Component)]
+pub struct VolumeSlider(pub VolumeChannel);
+
+#[derive(Component)]
+pub struct VolumeSliderFill(pub VolumeChannel);
+
+#[derive(Component)]
+pub struct VolumeSliderHandle(pub VolumeChannel);
+
 fn volume_display_widget(
     window_height: f32,
     font: Handle<Font>,
     text: impl Into<String> + Clone,
+    channel: VolumeChannel,
 ) -> impl Bundle {
     (
         Node {
@@ -56,10 +115,270 @@ fn volume_display_widget(
                 ..default()
             },
             TextColor::WHITE,
+            VolumeDisplay(channel),
         )],
     )
 }
 
+/// Track with a fill and a round handle; press or drag anywhere on it to set the volume
+fn volume_slider_widget(window_height: f32, channel: VolumeChannel, value: f64) -> impl Bundle {
+    let track_height = (window_height * 0.012).max(8.0);
+    let handle_size = track_height * 2.5;
+    let percent = (value.clamp(0.0, 1.0) * 100.0) as f32;
+
+    (
+        Node {
+            width: Val::Percent(25.0),
+            height: Val::Px(track_height),
+            align_self: AlignSelf::Center,
+            margin: UiRect::left(Val::Percent(2.0)),
+            ..default()
+        },
+        Name::new("Volume slider"),
+        BackgroundColor(Color::DARKER_GLASS),
+        BorderColor(Color::ELYSIUM_DESCENT_BLUE),
+        BorderRadius::MAX,
+        VolumeSlider(channel),
+        children![
+            (
+                Node {
+                    width: Val::Percent(percent),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::ELYSIUM_DESCENT_RED),
+                BorderRadius::MAX,
+                Pickable::IGNORE,
+                VolumeSliderFill(channel),
+            ),
+            (
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(percent),
+                    top: Val::Px((track_height - handle_size) / 2.0),
+                    width: Val::Px(handle_size),
+                    height: Val::Px(handle_size),
+                    margin: UiRect::left(Val::Px(-handle_size / 2.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::WHITE),
+                BorderColor(Color::ELYSIUM_DESCENT_RED),
+                BorderRadius::MAX,
+                VolumeSliderHandle(channel),
+            ),
+        ],
+    )
+}
+
+fn slider_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    slider_query: Query<(&VolumeSlider, &ComputedNode, &GlobalTransform)>,
+    settings: ResMut<AudioSettings>,
+    ui_scale: Res<UiScale>,
+) {
+    let pointer = trigger.pointer_location.position / ui_scale.0;
+    set_volume_from_pointer(trigger.target(), pointer, slider_query, settings);
+}
+
+fn slider_dragged(
+    trigger: Trigger<Pointer<Drag>>,
+    slider_query: Query<(&VolumeSlider, &ComputedNode, &GlobalTransform)>,
+    settings: ResMut<AudioSettings>,
+    ui_scale: Res<UiScale>,
+) {
+    let pointer = trigger.pointer_location.position / ui_scale.0;
+    set_volume_from_pointer(trigger.target(), pointer, slider_query, settings);
+}
+
+fn set_volume_from_pointer(
+    slider: Entity,
+    pointer: Vec2,
+    slider_query: Query<(&VolumeSlider, &ComputedNode, &GlobalTransform)>,
+    mut settings: ResMut<AudioSettings>,
+) {
+    let Ok((slider, node, transform)) = slider_query.get(slider) else {
+        return;
+    };
+
+    // Node layout is in physical pixels; `pointer` is already in UI pixels
+    let width = node.size().x * node.inverse_scale_factor();
+    if width <= 0.0 {
+        return;
+    }
+    let left = transform.translation().x * node.inverse_scale_factor() - width / 2.0;
+    let value = ((pointer.x - left) / width).clamp(0.0, 1.0) as f64;
+
+    if slider.0.get(&settings) != value {
+        slider.0.set(&mut settings, value);
+    }
+}
+
+/// Keeps slider fills, handles and number displays in step with `AudioSettings`
+pub(crate) fn update_volume_widgets(
+    settings: Res<AudioSettings>,
+    mut fill_query: Query<(&VolumeSliderFill, &mut Node), Without<VolumeSliderHandle>>,
+    mut handle_query: Query<(&VolumeSliderHandle, &mut Node), Without<VolumeSliderFill>>,
+    mut display_query: Query<(&VolumeDisplay, &mut Text), Without<MuteToggleLabel>>,
+    mut mute_label_query: Query<&mut Text, With<MuteToggleLabel>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (fill, mut node) in fill_query.iter_mut() {
+        node.width = Val::Percent((fill.0.get(&settings) * 100.0) as f32);
+    }
+    for (handle, mut node) in handle_query.iter_mut() {
+        node.left = Val::Percent((handle.0.get(&settings) * 100.0) as f32);
+    }
+    for (display, mut text) in display_query.iter_mut() {
+        text.0 = volume_label(display.0.get(&settings));
+    }
+    for mut text in mute_label_query.iter_mut() {
+        text.0 = mute_label(settings.muted).to_string();
+    }
+}
+
+/// Settings row with a single wide button, e.g. "MUTE ALL". `label_marker` goes on
+/// the button text so a system can rewrite it when the setting changes.
+pub(crate) fn toggle_button_widget<R, E, B, M, I>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    text: impl Into<String> + Clone,
+    label_marker: impl Component,
+    top: f32,
+    toggle_system: I,
+) where
+    E: Event,
+    B: Bundle,
+    R: Relationship,
+    I: IntoObserverSystem<E, B, M>,
+{
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(15.0),
+                top: Val::Percent(top),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Name::new("Toggle row"),
+            Pickable::IGNORE,
+        ))
+        .with_children(|content| {
+            content
+                .spawn((
+                    Node {
+                        width: Val::Percent(20.0),
+                        height: Val::Percent(60.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    Button,
+                    Name::new(text.clone().into()),
+                    BackgroundColor(Color::ELYSIUM_DESCENT_RED),
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    children![(
+                        Text::new(text.into()),
+                        TextFont {
+                            font_size: window_height * 0.03,
+                            font,
+                            ..default()
+                        },
+                        TextColor(Color::BLACK),
+                        Pickable::IGNORE,
+                        label_marker,
+                    )],
+                ))
+                .observe(toggle_system);
+        });
+}
+
+/// Settings row laid out like `volume_widget` without the slider: a title, then the
+/// value between "-" and "+" buttons. `value_marker` goes on the value text so a
+/// system can rewrite it when the setting changes.
+pub(crate) fn stepper_widget<R, E, B, M, IL, IR>(
+    parent: &mut RelatedSpawnerCommands<'_, R>,
+    window_height: f32,
+    font: Handle<Font>,
+    text: impl Into<String> + Clone,
+    value: impl Into<String>,
+    value_marker: impl Component,
+    top: f32,
+    lower_system: IL,
+    raise_system: IR,
+) where
+    E: Event,
+    B: Bundle,
+    R: Relationship,
+    IL: IntoObserverSystem<E, B, M>,
+    IR: IntoObserverSystem<E, B, M>,
+{
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(40.0),
+                top: Val::Percent(top),
+                ..default()
+            },
+            Name::new("Stepper settings row"),
+            Pickable::IGNORE,
+        ))
+        .with_children(|content| {
+            content.spawn(label_widget(window_height * 0.04, font.clone(), text));
+
+            content
+                .spawn(button_widget(window_height, font.clone(), "-"))
+                .observe(lower_system);
+
+            content.spawn((Node {
+                margin: UiRect::all(Val::Percent(0.5)),
+                ..default()
+            },));
+
+            content.spawn((
+                Node {
+                    width: Val::Percent(12.0),
+                    height: Val::Percent(20.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(Color::ELYSIUM_DESCENT_BLUE),
+                Pickable::IGNORE,
+                BorderRadius::MAX,
+                children![(
+                    Text::new(value.into()),
+                    TextFont {
+                        font_size: window_height * 0.03,
+                        font: font.clone(),
+                        ..default()
+                    },
+                    TextColor::WHITE,
+                    value_marker,
+                )],
+            ));
+
+            content.spawn((Node {
+                margin: UiRect::all(Val::Percent(0.5)),
+                ..default()
+            },));
+
+            content
+                .spawn(button_widget(window_height, font.clone(), "+"))
+                .observe(raise_system);
+        });
+}
+
 fn button_widget(
     window_height: f32,
     font: Handle<Font>,
@@ -96,7 +415,8 @@ pub(crate) fn volume_widget<R, E, B, M, IL, IR>(
     window_height: f32,
     font: Handle<Font>,
     text: impl Into<String> + Clone,
-    volume_value: impl Into<String> + Clone,
+    channel: VolumeChannel,
+    volume: f64,
     top: f32,
     lower_volume_system: IL,
     raise_volume_system: IR,
@@ -135,7 +455,8 @@ where
             content.spawn((volume_display_widget(
                 window_height,
                 font.clone(),
-                volume_value,
+                volume_label(volume),
+                channel,
             ),));
 
             content.spawn((Node {
@@ -146,9 +467,249 @@ where
             content
                 .spawn(button_widget(window_height, font.clone(), "+"))
                 .observe(raise_volume_system);
+
+            content
+                .spawn(volume_slider_widget(window_height, channel, volume))
+                .observe(slider_pressed)
+                .observe(slider_dragged);
         });
 }
 
+/// Border around every progress bar track; the fill sits inside it
+const PROGRESS_BAR_BORDER: f32 = 1.5;
+
+/// Track, border and fill colors of a `progress_bar_widget`. A `chip` color adds
+/// a lagging layer behind the fill that shows how much was just lost.
+#[derive(Clone, Copy, Debug)]
+pub struct ProgressBarColors {
+    pub track: Color,
+    pub border: Color,
+    pub fill: Color,
+    pub chip: Option<Color>,
+}
+
+/// Track of a progress bar. Setting `progress` (0.0 to 1.0) moves the fill child
+/// toward it through `update_progress_bars`, at `smoothing` per second (0 jumps).
+#[derive(Component, Debug)]
+pub struct ProgressBar {
+    pub progress: f32,
+    pub smoothing: f32,
+    displayed: f32,
+    last_target: f32,
+    chip: Option<ChipLayer>,
+    width: f32,
+}
+
+/// Where the lagging chip layer is, and how long it waits before draining
+#[derive(Clone, Copy, Debug)]
+struct ChipLayer {
+    value: f32,
+    hold_secs: f32,
+}
+
+impl ProgressBar {
+    fn new(progress: f32, width: f32, chip: bool) -> Self {
+        let progress = clamp_progress(progress);
+        Self {
+            progress,
+            smoothing: ProgressBarConfig::SMOOTHING,
+            displayed: progress,
+            last_target: progress,
+            chip: chip.then_some(ChipLayer {
+                value: progress,
+                hold_secs: 0.0,
+            }),
+            width,
+        }
+    }
+
+    /// Whether the fill (and chip) have caught up with `progress`
+    pub fn is_settled(&self) -> bool {
+        let target = clamp_progress(self.progress);
+        self.displayed == target && self.chip.is_none_or(|chip| chip.value == target)
+    }
+
+    /// Moves the fill toward `progress` by `delta_secs`. A drop restarts the chip's
+    /// hold, after which it drains down to the fill; gains carry it up with the fill.
+    pub fn step(&mut self, delta_secs: f32) {
+        let target = clamp_progress(self.progress);
+        let dropped = target < self.last_target;
+        self.last_target = target;
+        self.displayed = smooth_toward(self.displayed, target, self.smoothing, delta_secs);
+
+        if let Some(chip) = self.chip.as_mut() {
+            if dropped {
+                chip.hold_secs = ProgressBarConfig::CHIP_HOLD_SECS;
+            }
+            if chip.value <= self.displayed {
+                chip.value = self.displayed;
+            } else if chip.hold_secs > 0.0 {
+                chip.hold_secs -= delta_secs;
+            } else {
+                chip.value = smooth_toward(
+                    chip.value,
+                    self.displayed,
+                    ProgressBarConfig::CHIP_SMOOTHING,
+                    delta_secs,
+                );
+            }
+        }
+    }
+}
+
+/// The filled part of a `ProgressBar`
+#[derive(Component)]
+pub struct ProgressBarFill;
+
+/// The lagging layer behind a `ProgressBar`'s fill
+#[derive(Component)]
+pub struct ProgressBarChip;
+
+fn clamp_progress(progress: f32) -> f32 {
+    // max/min rather than clamp, so NaN (e.g. from a 0 maximum) reads as empty
+    progress.max(0.0).min(1.0)
+}
+
+/// Exponential approach from `current` to `target` at `smoothing` per second,
+/// snapping once close enough that the bar would stop visibly moving
+pub fn smooth_toward(current: f32, target: f32, smoothing: f32, delta_secs: f32) -> f32 {
+    if smoothing <= 0.0 || (target - current).abs() <= ProgressBarConfig::SNAP_EPSILON {
+        return target;
+    }
+    current + (target - current) * (1.0 - (-smoothing * delta_secs).exp())
+}
+
+/// Fill width for `progress` of a bar `width` pixels wide, border included.
+/// Out of range or NaN progress is clamped.
+pub fn progress_fill_width(width: f32, progress: f32) -> f32 {
+    (width - 2.0 * PROGRESS_BAR_BORDER).max(0.0) * clamp_progress(progress)
+}
+
+/// Rounded progress bar `width` by `height` pixels, filled to `progress`
+pub fn progress_bar_widget(
+    width: f32,
+    height: f32,
+    progress: f32,
+    colors: ProgressBarColors,
+) -> impl Bundle {
+    progress_bar(width, height, progress, colors, ())
+}
+
+/// `progress_bar_widget` with its border and fill recolored by the palette,
+/// for bars whose colors carry meaning (health, xp)
+pub fn palette_progress_bar_widget(
+    width: f32,
+    height: f32,
+    progress: f32,
+    colors: ProgressBarColors,
+    border_role: PaletteRole,
+    fill_role: PaletteRole,
+) -> impl Bundle {
+    (
+        progress_bar(width, height, progress, colors, PaletteBackground(fill_role)),
+        PaletteBorder(border_role),
+    )
+}
+
+fn progress_bar(
+    width: f32,
+    height: f32,
+    progress: f32,
+    colors: ProgressBarColors,
+    fill_extra: impl Bundle,
+) -> impl Bundle {
+    let fill_height = (height - 2.0 * PROGRESS_BAR_BORDER).max(0.0);
+    let fill_width = progress_fill_width(width, progress);
+    (
+        Node {
+            width: Val::Px(width),
+            height: Val::Px(height),
+            border: UiRect::all(Val::Px(PROGRESS_BAR_BORDER)),
+            ..default()
+        },
+        BackgroundColor(colors.track),
+        BorderColor(colors.border),
+        BorderRadius::all(Val::Px(height / 2.0)),
+        ProgressBar::new(progress, width, colors.chip.is_some()),
+        children![
+            // Spawned first so the fill draws over it; bars without a chip color lay it out as nothing
+            (
+                Node {
+                    display: if colors.chip.is_some() { Display::Flex } else { Display::None },
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Px(fill_width),
+                    height: Val::Px(fill_height),
+                    ..default()
+                },
+                BackgroundColor(colors.chip.unwrap_or(Color::NONE)),
+                BorderRadius::all(Val::Px(fill_height / 2.0)),
+                ProgressBarChip,
+            ),
+            (
+                Node {
+                    width: Val::Px(fill_width),
+                    height: Val::Px(fill_height),
+                    ..default()
+                },
+                BackgroundColor(colors.fill),
+                BorderRadius::all(Val::Px(fill_height / 2.0)),
+                ProgressBarFill,
+                fill_extra,
+            ),
+        ],
+    )
+}
+
+pub struct ProgressBarPlugin;
+
+impl Plugin for ProgressBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_progress_bars.before(bevy::ui::UiSystem::Layout));
+    }
+}
+
+/// Animates unsettled bars; settled ones are skipped without touching change detection
+fn update_progress_bars(
+    time: Res<Time<Real>>,
+    mut bar_query: Query<(&mut ProgressBar, &Children)>,
+    mut fill_query: Query<&mut Node, (With<ProgressBarFill>, Without<ProgressBarChip>)>,
+    mut chip_query: Query<&mut Node, (With<ProgressBarChip>, Without<ProgressBarFill>)>,
+) {
+    for (mut bar, children) in &mut bar_query {
+        if bar.is_settled() {
+            continue;
+        }
+        bar.step(time.delta_secs());
+
+        let fill_width = Val::Px(progress_fill_width(bar.width, bar.displayed));
+        let chip_width = bar
+            .chip
+            .map(|chip| Val::Px(progress_fill_width(bar.width, chip.value)));
+        for child in children.iter() {
+            if let Ok(mut node) = fill_query.get_mut(child) {
+                node.width = fill_width;
+            } else if let (Ok(mut node), Some(chip_width)) =
+                (chip_query.get_mut(child), chip_width)
+            {
+                node.width = chip_width;
+            }
+        }
+    }
+}
+
+/// The `ProgressBar`s in the player HUD, for systems that keep them current
+#[derive(Component)]
+pub struct HudHealthBar;
+
+/// The "current/max" label above `HudHealthBar`
+#[derive(Component)]
+pub struct HudHealthText;
+
+#[derive(Component)]
+pub struct HudXpBar;
+
 pub enum HudPosition {
     Left,
     Right,
@@ -300,6 +861,7 @@ pub fn player_hud_widget(
                                             ..default()
                                         },
                                         TextColor(Color::HEALTH_GREEN),
+                                        PaletteText(PaletteRole::Health),
                                     ),
                                     (
                                         Text::new(format!("{}/{}", health.0, health.1)),
@@ -309,32 +871,26 @@ pub fn player_hud_widget(
                                             ..default()
                                         },
                                         TextColor::WHITE,
+                                        HudHealthText,
                                     )
                                 ]
                             ),
                             // Health Bar
                             (
-                                Node {
-                                    width: Val::Px(420.0),
-                                    height: Val::Px(21.0),
-                                    border: UiRect::all(Val::Px(1.5)),
-                                    ..default()
-                                },
-                                BackgroundColor(Color::DARKER_GLASS),
-                                BorderColor(Color::HEALTH_GREEN_DARK.with_alpha(0.6)),
-                                BorderRadius::all(Val::Px(10.5)),
-                                children![
-                                    (
-                                        Node {
-                                            width: Val::Px(417.0 * health_percent),
-                                            height: Val::Px(18.0),
-                                            margin: UiRect::all(Val::Px(1.5)),
-                                            ..default()
-                                        },
-                                        BackgroundColor(Color::HEALTH_GREEN),
-                                        BorderRadius::all(Val::Px(9.0)),
-                                    )
-                                ]
+                                palette_progress_bar_widget(
+                                    420.0,
+                                    21.0,
+                                    health_percent,
+                                    ProgressBarColors {
+                                        track: Color::DARKER_GLASS,
+                                        border: Color::HEALTH_GREEN_DARK.with_alpha(0.6),
+                                        fill: Color::HEALTH_GREEN,
+                                        chip: Some(Color::DANGER_RED.with_alpha(0.8)),
+                                    },
+                                    PaletteRole::HealthDark,
+                                    PaletteRole::Health,
+                                ),
+                                HudHealthBar,
                             )
                         ]
                     ),
@@ -367,6 +923,7 @@ pub fn player_hud_widget(
                                             ..default()
                                         },
                                         TextColor(Color::XP_PURPLE),
+                                        PaletteText(PaletteRole::Xp),
                                     ),
                                     (
                                         Text::new(format!("{}/{}", xp.0, xp.1)),
@@ -381,27 +938,20 @@ pub fn player_hud_widget(
                             ),
                             // XP Bar
                             (
-                                Node {
-                                    width: Val::Px(420.0),
-                                    height: Val::Px(21.0),
-                                    border: UiRect::all(Val::Px(1.5)),
-                                    ..default()
-                                },
-                                BackgroundColor(Color::DARKER_GLASS),
-                                BorderColor(Color::XP_PURPLE_DARK.with_alpha(0.6)),
-                                BorderRadius::all(Val::Px(10.5)),
-                                children![
-                                    (
-                                        Node {
-                                            width: Val::Px(417.0 * xp_percent),
-                                            height: Val::Px(18.0),
-                                            margin: UiRect::all(Val::Px(1.5)),
-                                            ..default()
-                                        },
-                                        BackgroundColor(Color::XP_PURPLE),
-                                        BorderRadius::all(Val::Px(9.0)),
-                                    )
-                                ]
+                                palette_progress_bar_widget(
+                                    420.0,
+                                    21.0,
+                                    xp_percent,
+                                    ProgressBarColors {
+                                        track: Color::DARKER_GLASS,
+                                        border: Color::XP_PURPLE_DARK.with_alpha(0.6),
+                                        fill: Color::XP_PURPLE,
+                                        chip: None,
+                                    },
+                                    PaletteRole::XpDark,
+                                    PaletteRole::Xp,
+                                ),
+                                HudXpBar,
                             )
                         ]
                     )
@@ -410,3 +960,90 @@ pub fn player_hud_widget(
         ],
     )
 }
+
+/// Marker for the fill node of the stamina bar, resized by the character controller
+#[derive(Component)]
+pub struct StaminaBarFill;
+
+/// Slim stamina bar that sits just under the left player HUD
+pub fn stamina_bar_widget(font: Handle<Font>) -> impl Bundle {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(194.0),
+            top: Val::Px(220.0),
+            width: Val::Px(420.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        Name::new("Stamina Bar"),
+        children![
+            (
+                Text::new("STAMINA"),
+                TextFont {
+                    font,
+                    font_size: 13.5,
+                    ..default()
+                },
+                TextColor(Color::ENERGY_BLUE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(3.0)),
+                    ..default()
+                },
+            ),
+            (
+                Node {
+                    width: Val::Px(420.0),
+                    height: Val::Px(15.0),
+                    border: UiRect::all(Val::Px(1.5)),
+                    ..default()
+                },
+                BackgroundColor(Color::DARKER_GLASS),
+                BorderColor(Color::ENERGY_BLUE_DARK.with_alpha(0.6)),
+                BorderRadius::all(Val::Px(7.5)),
+                children![(
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::ENERGY_BLUE),
+                    BorderRadius::all(Val::Px(6.0)),
+                    StaminaBarFill,
+                )]
+            )
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_width_is_the_percent_of_the_inner_track() {
+        let inner = 200.0 - 2.0 * PROGRESS_BAR_BORDER;
+        assert_eq!(progress_fill_width(200.0, 0.0), 0.0);
+        assert_eq!(progress_fill_width(200.0, 0.5), inner * 0.5);
+        assert_eq!(progress_fill_width(200.0, 1.0), inner);
+    }
+
+    #[test]
+    fn out_of_range_progress_is_clamped() {
+        assert_eq!(progress_fill_width(200.0, 1.5), progress_fill_width(200.0, 1.0));
+        assert_eq!(progress_fill_width(200.0, -0.5), 0.0);
+        assert_eq!(progress_fill_width(200.0, f32::NAN), 0.0);
+        // A bar narrower than its border has no room for a fill
+        assert_eq!(progress_fill_width(2.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn smoothing_approaches_the_target_without_overshooting() {
+        assert_eq!(smooth_toward(0.2, 0.8, 0.0, 0.016), 0.8);
+        let step = smooth_toward(0.2, 0.8, 8.0, 0.016);
+        assert!(step > 0.2 && step < 0.8);
+        // Close enough snaps straight to the target
+        let target = 0.8 + ProgressBarConfig::SNAP_EPSILON * 0.5;
+        assert_eq!(smooth_toward(0.8, target, 8.0, 0.016), target);
+    }
+}